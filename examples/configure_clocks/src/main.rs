@@ -8,7 +8,13 @@ async fn main() {
         .init();
 
     info!("loading static config!");
-    let config = Arc::new(xrfclk::load_config_from_file());
+    let config = match xrfclk::load_config_from_file() {
+        Ok(config) => Arc::new(config),
+        Err(e) => {
+            error!("cannot load config: {e}");
+            return;
+        }
+    };
 
     let lmk_freq = 50025;
     let lmx_freq = 10240;
@@ -0,0 +1,675 @@
+use clap::{Parser, Subcommand};
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::Level;
+use xrfclk::describe::describe_registers;
+use xrfclk::discovery::{enumerate_clock_chips_in_blocking, restore_driver_blocking};
+use xrfclk::error::{XRFClkError, XRFClkErrorKind};
+use xrfclk::lock::LockReport;
+use xrfclk::readback::RegisterDump;
+use xrfclk::schedule::{ProgramFailures, ProgramReport};
+use xrfclk::{
+    load_config_with_overrides, open_devices_blocking, set_ref_clks_and_wait_for_lock_in_blocking,
+    set_ref_clks_in_blocking, Chip, Config, DeviceIdentity, DeviceSetting, Frequency, LMKDevice,
+    LMXDevice, Schedule, SystemRoot,
+};
+
+/// Inspect and program the clock chips of RFSoC boards.
+#[derive(Debug, Parser)]
+#[command(name = "xrfclk-cli", version)]
+struct Cli {
+    /// Print JSON instead of text, for scripting
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Config file layered over the embedded profiles, JSON or TOML. Can be
+    /// given more than once
+    #[arg(long = "config", global = true, value_name = "FILE")]
+    configs: Vec<PathBuf>,
+
+    /// Directory `sys` and `dev` are looked up in
+    #[arg(long, global = true, default_value = "/", value_name = "DIR")]
+    root: PathBuf,
+
+    /// Log debug messages to stderr
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List the clock chips of the system without touching their drivers
+    List,
+    /// List the available frequencies per chip
+    Profiles {
+        /// Only the profiles of this chip, e.g. lmx2594
+        #[arg(long)]
+        chip: Option<Chip>,
+    },
+    /// Show what a profile generates: PLLs, VCO, outputs and SYSREF
+    Describe {
+        chip: Chip,
+        /// The profile, in units of 10 kHz
+        frequency: u64,
+        /// Frequency at OSCin in Hz, by default the reference of the profile
+        #[arg(long, value_name = "HZ")]
+        reference: Option<u64>,
+    },
+    /// Program every LMK and LMX with a profile, frequencies in units of 10 kHz
+    Set {
+        #[arg(long)]
+        lmk: u64,
+        #[arg(long)]
+        lmx: u64,
+        /// Wait up to this many milliseconds for the chips to lock
+        #[arg(long, value_name = "MS")]
+        lock_timeout: Option<u64>,
+    },
+    /// Read back the registers of the clock chips, their drivers are put
+    /// back afterwards
+    Dump {
+        /// Only this device, e.g. spi1.2 or lmx2594@2
+        #[arg(long)]
+        device: Option<DeviceIdentity>,
+    },
+    /// Write a TICS Pro register export or .tcs setup file into a device.
+    /// The chip stays bound to spidev after a successful load, a failed one
+    /// puts its driver back
+    Load {
+        file: PathBuf,
+        /// The device to program, e.g. spi1.2 or lmx2594@2
+        #[arg(long)]
+        device: DeviceIdentity,
+    },
+    /// Show the lock state of the clock chips, their drivers are put back
+    /// afterwards
+    Status {
+        /// Only this device, e.g. spi1.2 or lmx2594@2
+        #[arg(long)]
+        device: Option<DeviceIdentity>,
+    },
+}
+
+/// What a command prints, as JSON or as lines of text.
+struct Output {
+    json: Value,
+    lines: Vec<String>,
+    success: bool,
+}
+
+impl Output {
+    fn from(json: Value, lines: Vec<String>) -> Self {
+        Self {
+            json,
+            lines,
+            success: true,
+        }
+    }
+
+    fn print(&self, as_json: bool) {
+        if as_json {
+            println!("{:#}", self.json);
+        } else {
+            for line in &self.lines {
+                println!("{line}");
+            }
+        }
+    }
+}
+
+fn load_config(paths: &[PathBuf]) -> Result<Arc<Config>, XRFClkError> {
+    let paths: Vec<&Path> = paths.iter().map(PathBuf::as_path).collect();
+    Ok(Arc::new(load_config_with_overrides(&paths)?))
+}
+
+fn hex(word: u32) -> String {
+    format!("0x{word:06X}")
+}
+
+fn list(root: &SystemRoot) -> Result<Output, XRFClkError> {
+    let descriptors = enumerate_clock_chips_in_blocking(root)?;
+
+    let json = descriptors
+        .iter()
+        .map(|descriptor| {
+            json!({
+                "name": descriptor.name,
+                "chip": descriptor.chip.to_string(),
+                "bus": descriptor.bus,
+                "chip_select": descriptor.chip_select,
+                "driver": descriptor.driver,
+                "num_bytes": descriptor.num_bytes,
+                "identities": descriptor
+                    .identities
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+
+    let lines = descriptors
+        .iter()
+        .map(|descriptor| {
+            format!(
+                "{} {} driver: {}",
+                descriptor.name,
+                descriptor.chip,
+                descriptor.driver.as_deref().unwrap_or("none")
+            )
+        })
+        .collect();
+
+    Ok(Output::from(json, lines))
+}
+
+fn profiles(config: &Config, chip: Option<Chip>) -> Output {
+    let mut chips: Vec<Chip> = match chip {
+        Some(chip) => vec![chip],
+        None => config.chips().collect(),
+    };
+    chips.sort_by_key(|chip| chip.to_string());
+
+    let mut json = serde_json::Map::new();
+    let mut lines = Vec::new();
+
+    for chip in chips {
+        let profiles: Vec<Value> = config
+            .profiles(chip)
+            .map(|profile| {
+                json!({
+                    "frequency": profile.frequency.as_10khz(),
+                    "mhz": profile.frequency.mhz(),
+                    "reference_hz": profile.reference.map(|reference| reference.hz()),
+                    "description": profile.description,
+                    "board": profile.board,
+                })
+            })
+            .collect();
+
+        lines.push(format!("{chip}:"));
+        lines.extend(
+            config
+                .profiles(chip)
+                .map(|profile| match &profile.description {
+                    Some(description) => format!(
+                        "  {} ({}) {description}",
+                        profile.frequency.as_10khz(),
+                        profile.frequency
+                    ),
+                    None => format!("  {} ({})", profile.frequency.as_10khz(), profile.frequency),
+                }),
+        );
+
+        json.insert(chip.to_string(), Value::Array(profiles));
+    }
+
+    Output::from(Value::Object(json), lines)
+}
+
+fn describe(
+    config: &Config,
+    chip: Chip,
+    frequency: u64,
+    reference: Option<u64>,
+) -> Result<Output, XRFClkError> {
+    let invalid_frequency = || {
+        XRFClkError::from(XRFClkErrorKind::InvalidFrequency)
+            .with_chip(chip)
+            .with_frequency(frequency)
+    };
+    let profile = config
+        .profile(chip, Frequency::from_10khz(frequency))
+        .ok_or_else(invalid_frequency)?;
+    let reference = reference
+        .map(Frequency::from_hz)
+        .or(profile.reference)
+        .ok_or_else(invalid_frequency)?;
+
+    let description = describe_registers(&profile.registers, reference)?;
+
+    let json = json!({
+        "chip": chip.to_string(),
+        "reference_hz": reference.hz(),
+        "vco_hz": description.vco_hz,
+        "plls": description.plls.iter().map(|pll| json!({
+            "name": pll.name,
+            "enabled": pll.enabled,
+            "r": pll.r,
+            "n": pll.n,
+            "input_hz": pll.input_hz,
+            "phase_detector_hz": pll.phase_detector_hz,
+            "output_hz": pll.output_hz,
+        })).collect::<Vec<_>>(),
+        "outputs": description.outputs.iter().map(|output| json!({
+            "name": output.name,
+            "enabled": output.enabled,
+            "source": output.source,
+            "divider": output.divider,
+            "frequency_hz": output.frequency_hz,
+            "power": output.power,
+        })).collect::<Vec<_>>(),
+        "sysref": description.sysref.as_ref().map(|sysref| json!({
+            "enabled": sysref.enabled,
+            "mode": sysref.mode,
+            "divider": sysref.divider,
+            "frequency_hz": sysref.frequency_hz,
+        })),
+    });
+    let lines = description
+        .to_string()
+        .lines()
+        .map(str::to_string)
+        .collect();
+
+    Ok(Output::from(json, lines))
+}
+
+fn program_reports(reports: &[ProgramReport]) -> Output {
+    let json = reports
+        .iter()
+        .map(|report| {
+            json!({
+                "chip": report.chip.to_string(),
+                "device": report.device,
+                "error": report.result.as_ref().err().map(ToString::to_string),
+            })
+        })
+        .collect();
+
+    Output {
+        json,
+        lines: reports.iter().map(ToString::to_string).collect(),
+        success: reports.iter().all(ProgramReport::is_ok),
+    }
+}
+
+fn lock_reports(reports: &[LockReport]) -> Output {
+    let json = reports
+        .iter()
+        .map(|report| {
+            json!({
+                "chip": report.chip.to_string(),
+                "device": report.device,
+                "locked": report.locked,
+            })
+        })
+        .collect();
+
+    Output {
+        json,
+        lines: reports
+            .iter()
+            .map(|report| lock_line(report.chip, &report.device, report.locked, "timed out"))
+            .collect(),
+        success: reports.iter().all(|report| report.locked != Some(false)),
+    }
+}
+
+fn set(
+    config: Arc<Config>,
+    root: &SystemRoot,
+    lmk_freq: u64,
+    lmx_freq: u64,
+    lock_timeout: Option<Duration>,
+) -> Result<Output, XRFClkError> {
+    let output = match lock_timeout {
+        Some(timeout) => {
+            set_ref_clks_and_wait_for_lock_in_blocking(config, root, lmk_freq, lmx_freq, timeout)
+                .map(|reports| lock_reports(&reports))
+        }
+        None => set_ref_clks_in_blocking(config, root, lmk_freq, lmx_freq).map(|()| {
+            Output::from(
+                json!({ "lmk": lmk_freq, "lmx": lmx_freq }),
+                vec![format!(
+                    "every lmk set to {lmk_freq}, every lmx to {lmx_freq}"
+                )],
+            )
+        }),
+    };
+
+    match output {
+        Err(e) => match ProgramFailures::of(&e) {
+            Some(failures) => Ok(program_reports(&failures.reports)),
+            None => Err(e),
+        },
+        output => output,
+    }
+}
+
+/// Binds the clock chips selected by `device`, every chip without one, to
+/// spidev for `f` and puts their drivers back afterwards. With `keep_bound`
+/// they stay on spidev if `f` succeeds.
+fn with_devices_bound(
+    config: Arc<Config>,
+    root: &SystemRoot,
+    device: Option<&DeviceIdentity>,
+    keep_bound: bool,
+    f: impl FnOnce(&[LMKDevice], &[LMXDevice]) -> Result<Output, XRFClkError>,
+) -> Result<Output, XRFClkError> {
+    let descriptors: Vec<_> = enumerate_clock_chips_in_blocking(root)?
+        .into_iter()
+        .filter(|descriptor| selected(&descriptor.identities, device))
+        .collect();
+
+    if descriptors.is_empty() && device.is_some() {
+        return Err(XRFClkError::from(XRFClkErrorKind::DeviceNotFound));
+    }
+
+    // the devices are closed before their drivers are restored
+    let output = open_devices_blocking(config, &descriptors)
+        .and_then(|(lmk_devices, lmx_devices)| f(&lmk_devices, &lmx_devices));
+    if keep_bound && output.as_ref().is_ok_and(|output| output.success) {
+        return output;
+    }
+    let restored: Vec<_> = descriptors.iter().map(restore_driver_blocking).collect();
+
+    let output = output?;
+    restored.into_iter().collect::<Result<(), _>>()?;
+    Ok(output)
+}
+
+fn selected(identities: &[DeviceIdentity], device: Option<&DeviceIdentity>) -> bool {
+    device.is_none_or(|device| identities.contains(device))
+}
+
+fn dump_output(location: String, dump: &RegisterDump) -> (Value, Vec<String>) {
+    let registers: serde_json::Map<String, Value> = dump
+        .iter()
+        .map(|(address, word)| (address.to_string(), Value::from(word)))
+        .collect();
+
+    let mut lines = vec![format!("{} on {location}:", dump.chip())];
+    lines.extend(
+        dump.iter()
+            .map(|(address, word)| format!("  R{address}: {}", hex(word))),
+    );
+
+    (
+        json!({
+            "chip": dump.chip().to_string(),
+            "device": location,
+            "registers": registers,
+        }),
+        lines,
+    )
+}
+
+fn dump(lmk_devices: &[LMKDevice], lmx_devices: &[LMXDevice]) -> Result<Output, XRFClkError> {
+    let mut json = Vec::new();
+    let mut lines = Vec::new();
+
+    for lmk_device in lmk_devices {
+        let (device_json, device_lines) = dump_output(
+            lmk_device.location(),
+            &lmk_device.read_all_registers_blocking()?,
+        );
+        json.push(device_json);
+        lines.extend(device_lines);
+    }
+
+    for lmx_device in lmx_devices {
+        let (device_json, device_lines) = dump_output(
+            lmx_device.location(),
+            &lmx_device.read_all_registers_blocking()?,
+        );
+        json.push(device_json);
+        lines.extend(device_lines);
+    }
+
+    Ok(Output::from(Value::Array(json), lines))
+}
+
+fn load(
+    lmk_devices: &[LMKDevice],
+    lmx_devices: &[LMXDevice],
+    file: &Path,
+) -> Result<Output, XRFClkError> {
+    let mut schedule = Schedule::new();
+
+    for lmk_device in lmk_devices {
+        let registers = xrfclk::tics::load_tics_pro_file(lmk_device.chip(), file)?;
+        schedule = schedule.with_lmk(lmk_device, DeviceSetting::Registers(registers));
+    }
+
+    for lmx_device in lmx_devices {
+        let registers = xrfclk::tics::load_tics_pro_file(lmx_device.chip(), file)?;
+        schedule = schedule.with_lmx(lmx_device, DeviceSetting::Registers(registers));
+    }
+
+    Ok(program_reports(&schedule.run_blocking()))
+}
+
+fn lock_line(chip: Chip, location: &str, locked: Option<bool>, state: &str) -> String {
+    match locked {
+        Some(true) => format!("{chip} on {location}: locked"),
+        Some(false) => format!("{chip} on {location}: not locked ({state})"),
+        None => format!("{chip} on {location}: lock state unknown"),
+    }
+}
+
+/// The lock state of every device. With `timeout` every device gets that
+/// long to lock.
+fn lock_status(
+    lmk_devices: &[LMKDevice],
+    lmx_devices: &[LMXDevice],
+    timeout: Option<Duration>,
+) -> Result<Output, XRFClkError> {
+    let mut json = Vec::new();
+    let mut lines = Vec::new();
+    let mut success = true;
+
+    for lmk_device in lmk_devices {
+        if let Some(timeout) = timeout {
+            match lmk_device.wait_for_lock_blocking(timeout) {
+                Err(e) if e.kind() == XRFClkErrorKind::LockTimeout => (),
+                Err(e) if e.kind() == XRFClkErrorKind::UnsupportedOperation => (),
+                other => other?,
+            }
+        }
+
+        let (locked, state) = match lmk_device.lock_state_blocking() {
+            Ok(state) => (Some(state.is_locked()), format!("{state:?}")),
+            Err(e) if e.kind() == XRFClkErrorKind::UnsupportedOperation => {
+                (None, "unsupported".to_string())
+            }
+            Err(e) => return Err(e),
+        };
+
+        success &= locked != Some(false);
+        lines.push(lock_line(
+            lmk_device.chip(),
+            &lmk_device.location(),
+            locked,
+            &state,
+        ));
+        json.push(json!({
+            "chip": lmk_device.chip().to_string(),
+            "device": lmk_device.location(),
+            "locked": locked,
+            "state": state,
+        }));
+    }
+
+    for lmx_device in lmx_devices {
+        if let Some(timeout) = timeout {
+            match lmx_device.wait_for_lock_blocking(timeout) {
+                Err(e) if e.kind() == XRFClkErrorKind::LockTimeout => (),
+                other => other?,
+            }
+        }
+
+        let state = lmx_device.lock_state_blocking()?;
+        let locked = Some(state.is_locked());
+
+        success &= state.is_locked();
+        lines.push(lock_line(
+            lmx_device.chip(),
+            &lmx_device.location(),
+            locked,
+            &format!("{state:?}"),
+        ));
+        json.push(json!({
+            "chip": lmx_device.chip().to_string(),
+            "device": lmx_device.location(),
+            "locked": locked,
+            "state": format!("{state:?}"),
+        }));
+    }
+
+    Ok(Output {
+        json: Value::Array(json),
+        lines,
+        success,
+    })
+}
+
+fn run(cli: &Cli) -> Result<Output, XRFClkError> {
+    let root = SystemRoot::from(cli.root.clone());
+
+    match &cli.command {
+        Command::List => list(&root),
+        Command::Profiles { chip } => Ok(profiles(&*load_config(&cli.configs)?, *chip)),
+        Command::Describe {
+            chip,
+            frequency,
+            reference,
+        } => describe(&*load_config(&cli.configs)?, *chip, *frequency, *reference),
+        Command::Set {
+            lmk,
+            lmx,
+            lock_timeout,
+        } => set(
+            load_config(&cli.configs)?,
+            &root,
+            *lmk,
+            *lmx,
+            lock_timeout.map(Duration::from_millis),
+        ),
+        Command::Dump { device } => with_devices_bound(
+            load_config(&cli.configs)?,
+            &root,
+            device.as_ref(),
+            false,
+            dump,
+        ),
+        Command::Load { file, device } => with_devices_bound(
+            load_config(&cli.configs)?,
+            &root,
+            Some(device),
+            true,
+            |lmk_devices, lmx_devices| load(lmk_devices, lmx_devices, file),
+        ),
+        Command::Status { device } => with_devices_bound(
+            load_config(&cli.configs)?,
+            &root,
+            device.as_ref(),
+            false,
+            |lmk_devices, lmx_devices| lock_status(lmk_devices, lmx_devices, None),
+        ),
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    tracing_subscriber::fmt()
+        .with_max_level(if cli.verbose {
+            Level::DEBUG
+        } else {
+            Level::WARN
+        })
+        .with_writer(std::io::stderr)
+        .init();
+
+    match run(&cli) {
+        Ok(output) => {
+            output.print(cli.json);
+            if output.success {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(e) => {
+            let message = error_chain(&e);
+            if cli.json {
+                println!("{:#}", json!({ "error": message }));
+            } else {
+                eprintln!("{message}");
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The error followed by its causes.
+fn error_chain(error: &XRFClkError) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        message.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+
+    message
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn the_command_line_is_consistent() {
+        Cli::command().debug_assert();
+
+        let cli = Cli::parse_from(["xrfclk-cli", "--json", "dump", "--device", "spi1.2"]);
+        assert!(cli.json);
+        assert!(matches!(
+            cli.command,
+            Command::Dump {
+                device: Some(DeviceIdentity::Spi {
+                    bus: 1,
+                    chip_select: 2
+                })
+            }
+        ));
+        assert!(Cli::try_parse_from(["xrfclk-cli", "profiles", "--chip", "lmx9999"]).is_err());
+    }
+
+    #[test]
+    fn profiles_are_described() {
+        let config = load_config(&[]).unwrap();
+
+        let output = describe(&config, Chip::LMK04828, 50025, Some(160_000_000)).unwrap();
+        assert_eq!(output.json["vco_hz"], 3e9);
+        assert_eq!(output.json["sysref"]["frequency_hz"], 10e6);
+
+        // the embedded profiles do not know their reference
+        assert!(describe(&config, Chip::LMK04828, 50025, None).is_err());
+        assert!(describe(&config, Chip::LMK04828, 1, Some(160_000_000)).is_err());
+    }
+
+    #[test]
+    fn profiles_are_listed_per_chip() {
+        let config = load_config(&[]).unwrap();
+        let output = profiles(&config, Some(Chip::LMX2594));
+
+        let frequencies: Vec<u64> = output.json["lmx2594"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|profile| profile["frequency"].as_u64().unwrap())
+            .collect();
+        assert!(frequencies.contains(&40960));
+        assert_eq!(output.lines[0], "lmx2594:");
+        assert_eq!(output.json.as_object().unwrap().len(), 1);
+    }
+}
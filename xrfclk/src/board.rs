@@ -0,0 +1,486 @@
+use crate::config::{ClockProfile, Frequency};
+use crate::describe::describe_registers;
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::identity::DeviceSetting;
+use crate::schedule::{check_reports, Schedule};
+use crate::{find_devices, Chip, Config, LMKDevice, LMXDevice};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::debug;
+
+/// RF data converter tile, numbered like in the Vivado block design
+/// (ADC tiles 224 to 227, DAC tiles 228 to 231).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Adc(u16),
+    Dac(u16),
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Adc(tile) => write!(f, "ADC tile {tile}"),
+            Self::Dac(tile) => write!(f, "DAC tile {tile}"),
+        }
+    }
+}
+
+/// An LMX2594 of a board and the tiles its outputs are routed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LmxOutput {
+    pub name: &'static str,
+    pub tiles: &'static [Tile],
+}
+
+/// Frequencies of every clock chip of a board that work together, as
+/// profile keys in units of 10 kHz. `lmx` holds one entry per LMX2594 in
+/// the order of `Board::lmx_outputs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardProfile {
+    pub name: String,
+    pub lmk: u64,
+    pub lmx: Vec<u64>,
+}
+
+impl BoardProfile {
+    pub fn from(name: &str, lmk: u64, lmx: Vec<u64>) -> Self {
+        Self {
+            name: name.to_string(),
+            lmk,
+            lmx,
+        }
+    }
+
+    /// Checks that the profile fits `board`, that `config` holds every
+    /// register set it needs and that the LMK outputs the reference every
+    /// LMX profile was planned for, so a board is never left half
+    /// programmed or programmed with unlocked PLLs.
+    pub fn check(&self, board: Board, config: &Config) -> Result<(), XRFClkError> {
+        if self.lmx.len() != board.lmx_outputs().len() {
+            debug!(
+                "profile {} has {} lmx frequencies, {board} has {} lmx chips",
+                self.name,
+                self.lmx.len(),
+                board.lmx_outputs().len()
+            );
+            return Err(XRFClkError::from(XRFClkErrorKind::InvalidBoard));
+        }
+
+        let required = [(board.lmk(), self.lmk)]
+            .into_iter()
+            .chain(self.lmx.iter().map(|lmx| (Chip::LMX2594, *lmx)));
+
+        let mut profiles = Vec::with_capacity(self.lmx.len() + 1);
+        for (chip, frequency) in required {
+            match config.profile(chip, Frequency::from_10khz(frequency)) {
+                Some(profile) => profiles.push(profile),
+                None => {
+                    return Err(XRFClkError::from(XRFClkErrorKind::InvalidFrequency)
+                        .with_chip(chip)
+                        .with_frequency(frequency))
+                }
+            }
+        }
+
+        let lmk = profiles.remove(0);
+        let lmk_outputs_hz: Vec<f64> = describe_registers(
+            &lmk.registers,
+            lmk.reference.unwrap_or(board.lmk_reference()),
+        )?
+        .outputs
+        .into_iter()
+        .filter(|output| output.enabled)
+        .filter_map(|output| output.frequency_hz)
+        .collect();
+
+        for (lmx, frequency) in profiles.into_iter().zip(&self.lmx) {
+            let reference_hz = lmx_reference_hz(lmx)?;
+            if !lmk_outputs_hz.iter().any(|output_hz| {
+                (output_hz - reference_hz).abs() <= reference_hz * REFERENCE_TOLERANCE
+            }) {
+                debug!(
+                    "lmx profile {frequency} needs a {reference_hz} Hz reference, lmk profile {} \
+                     of {board} only outputs {lmk_outputs_hz:?} Hz",
+                    self.lmk
+                );
+                return Err(XRFClkError::from(XRFClkErrorKind::InvalidFrequency)
+                    .with_chip(Chip::LMX2594)
+                    .with_frequency(*frequency));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Profile keys only name the output frequency, e.g. 73700 runs at
+/// 737.28 MHz, so the reference they imply is close but not exact.
+const REFERENCE_TOLERANCE: f64 = 1e-3;
+
+/// The OSCin frequency `profile` was planned for, derived from its nominal
+/// frequency and dividers unless the profile names it.
+fn lmx_reference_hz(profile: &ClockProfile) -> Result<f64, XRFClkError> {
+    if let Some(reference) = profile.reference {
+        return Ok(reference.hz() as f64);
+    }
+
+    // the output frequency per Hz of reference
+    let gain = describe_registers(&profile.registers, Frequency::from_hz(1))?
+        .outputs
+        .into_iter()
+        .filter(|output| output.enabled)
+        .find_map(|output| output.frequency_hz)
+        .filter(|gain| *gain > 0.0)
+        .ok_or_else(|| {
+            debug!("lmx profile {} has no enabled output", profile.frequency);
+            XRFClkError::from(XRFClkErrorKind::InvalidFrequency)
+                .with_chip(Chip::LMX2594)
+                .with_frequency(profile.frequency.as_10khz())
+        })?;
+
+    Ok(profile.frequency.hz() as f64 / gain)
+}
+
+/// Bus and chip select of a spidev node like `/dev/spidev1.10`.
+fn spidev_address(location: &str) -> Option<(u32, u32)> {
+    let name = Path::new(location).file_name()?.to_str()?;
+    let (bus, chip_select) = name.strip_prefix("spidev")?.split_once('.')?;
+
+    Some((bus.parse().ok()?, chip_select.parse().ok()?))
+}
+
+const ZCU111_LMX: &[LmxOutput] = &[
+    LmxOutput {
+        name: "RFPLL_1",
+        tiles: &[Tile::Dac(228), Tile::Dac(229)],
+    },
+    LmxOutput {
+        name: "RFPLL_2",
+        tiles: &[Tile::Adc(224), Tile::Adc(225)],
+    },
+    LmxOutput {
+        name: "RFPLL_3",
+        tiles: &[Tile::Adc(226), Tile::Adc(227)],
+    },
+];
+
+/// The CLK104 add-on card of the ZCU208 and ZCU216.
+const CLK104_LMX: &[LmxOutput] = &[
+    LmxOutput {
+        name: "LMX_A (ADC_REFCLK)",
+        tiles: &[
+            Tile::Adc(224),
+            Tile::Adc(225),
+            Tile::Adc(226),
+            Tile::Adc(227),
+        ],
+    },
+    LmxOutput {
+        name: "LMX_B (DAC_REFCLK)",
+        tiles: &[
+            Tile::Dac(228),
+            Tile::Dac(229),
+            Tile::Dac(230),
+            Tile::Dac(231),
+        ],
+    },
+    LmxOutput {
+        name: "LMX_C (RFIN_RF)",
+        tiles: &[],
+    },
+];
+
+const RFSOC4X2_LMX: &[LmxOutput] = &[
+    LmxOutput {
+        name: "LMX_ADC",
+        tiles: &[Tile::Adc(224), Tile::Adc(226)],
+    },
+    LmxOutput {
+        name: "LMX_DAC",
+        tiles: &[Tile::Dac(228), Tile::Dac(230)],
+    },
+];
+
+/// LMX2594 profiles of the embedded config by name.
+const LMX_PROFILES: [(&str, u64); 4] = [
+    ("102.4", 10240),
+    ("204.8", 20480),
+    ("409.6", 40960),
+    ("737.28", 73700),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Board {
+    Zcu111,
+    Zcu208,
+    Zcu216,
+    Rfsoc4x2,
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Zcu111 => write!(f, "zcu111"),
+            Self::Zcu208 => write!(f, "zcu208"),
+            Self::Zcu216 => write!(f, "zcu216"),
+            Self::Rfsoc4x2 => write!(f, "rfsoc4x2"),
+        }
+    }
+}
+
+impl FromStr for Board {
+    type Err = XRFClkError;
+
+    fn from_str(s: &str) -> Result<Board, Self::Err> {
+        match s {
+            "zcu111" => Ok(Board::Zcu111),
+            "zcu208" => Ok(Board::Zcu208),
+            "zcu216" => Ok(Board::Zcu216),
+            "rfsoc4x2" => Ok(Board::Rfsoc4x2),
+            _ => Err(Self::Err::from(XRFClkErrorKind::InvalidBoard)),
+        }
+    }
+}
+
+impl Board {
+    pub const ALL: [Board; 4] = [Board::Zcu111, Board::Zcu208, Board::Zcu216, Board::Rfsoc4x2];
+
+    /// The LMK that provides the reference and SYSREF clocks.
+    pub fn lmk(&self) -> Chip {
+        match self {
+            Self::Zcu111 => Chip::LMK04208,
+            Self::Zcu208 | Self::Zcu216 | Self::Rfsoc4x2 => Chip::LMK04828,
+        }
+    }
+
+    /// The LMX2594s of the board, in the order of their spidev nodes.
+    pub fn lmx_outputs(&self) -> &'static [LmxOutput] {
+        match self {
+            Self::Zcu111 => ZCU111_LMX,
+            Self::Zcu208 | Self::Zcu216 => CLK104_LMX,
+            Self::Rfsoc4x2 => RFSOC4X2_LMX,
+        }
+    }
+
+    /// Frequency of the VCXO at the OSCin of the LMK.
+    pub fn lmk_reference(&self) -> Frequency {
+        match self {
+            Self::Zcu111 => Frequency::from_hz(122_880_000),
+            Self::Zcu208 | Self::Zcu216 | Self::Rfsoc4x2 => Frequency::from_hz(160_000_000),
+        }
+    }
+
+    /// Number of bytes per SPI word of the LMK.
+    pub fn lmk_word_bytes(&self) -> u32 {
+        match self.lmk() {
+            Chip::LMK04208 => 4,
+            _ => 3,
+        }
+    }
+
+    /// Frequency combinations the embedded config supports on this board,
+    /// the first one is the default. The LMKs output the 122.88 MHz the
+    /// LMX2594 profiles are planned for.
+    pub fn profiles(&self) -> Vec<BoardProfile> {
+        let default_lmx = match self {
+            Self::Zcu111 => 40960,
+            Self::Zcu208 | Self::Zcu216 | Self::Rfsoc4x2 => 10240,
+        };
+        let lmx_count = self.lmx_outputs().len();
+
+        LMX_PROFILES
+            .iter()
+            .filter(|(_, lmx)| *lmx == default_lmx)
+            .chain(LMX_PROFILES.iter().filter(|(_, lmx)| *lmx != default_lmx))
+            .map(|(name, lmx)| BoardProfile::from(name, 12288, vec![*lmx; lmx_count]))
+            .collect()
+    }
+
+    pub fn default_profile(&self) -> BoardProfile {
+        // every board has at least one profile
+        self.profiles().remove(0)
+    }
+
+    pub fn profile(&self, name: &str) -> Option<BoardProfile> {
+        self.profiles()
+            .into_iter()
+            .find(|profile| profile.name == name)
+    }
+}
+
+/// Programs `profile` into the clock chips of `board` through a `Schedule`.
+/// `lmx_devices` are matched to `Board::lmx_outputs` by the bus and chip
+/// select of their spidev node, e.g. `spidev1.2` before `spidev1.10`;
+/// devices behind other transports keep their order.
+pub async fn program_board(
+    config: &Config,
+    board: Board,
+    profile: &BoardProfile,
+    lmk_device: &LMKDevice,
+    lmx_devices: &[LMXDevice],
+) -> Result<(), XRFClkError> {
+    profile.check(board, config)?;
+
+    if lmk_device.chip() != board.lmk() || lmx_devices.len() != board.lmx_outputs().len() {
+        debug!(
+            "{board} expects a {} and {} lmx2594, found a {} and {} lmx2594",
+            board.lmk(),
+            board.lmx_outputs().len(),
+            lmk_device.chip(),
+            lmx_devices.len()
+        );
+        return Err(XRFClkError::from(XRFClkErrorKind::InvalidBoard));
+    }
+
+    let mut lmx_devices: Vec<&LMXDevice> = lmx_devices.iter().collect();
+    lmx_devices.sort_by_key(|device| spidev_address(&device.location()));
+
+    let mut schedule = Schedule::new().with_lmk(lmk_device, DeviceSetting::Frequency(profile.lmk));
+    for ((lmx_device, output), frequency) in lmx_devices
+        .into_iter()
+        .zip(board.lmx_outputs())
+        .zip(&profile.lmx)
+    {
+        debug!(
+            "setting {} on {} to {frequency}",
+            output.name,
+            lmx_device.location()
+        );
+        schedule =
+            schedule.with_lmx_fed_by(lmx_device, DeviceSetting::Frequency(*frequency), lmk_device);
+    }
+
+    check_reports(&schedule.run().await?)
+}
+
+/// Finds the clock chips of this system and programs `profile` of `board`
+/// into them.
+pub async fn set_board_clks(
+    config: Arc<Config>,
+    board: Board,
+    profile: &BoardProfile,
+) -> Result<(), XRFClkError> {
+    // fail before the chips are rebound to spidev
+    profile.check(board, &config)?;
+
+    let (mut lmk_devices, lmx_devices) = find_devices(config.clone()).await?;
+
+    if lmk_devices.len() != 1 {
+        debug!("{board} expects one lmk, found {}", lmk_devices.len());
+        return Err(XRFClkError::from(XRFClkErrorKind::InvalidBoard));
+    }
+
+    program_board(
+        &config,
+        board,
+        profile,
+        &lmk_devices.remove(0),
+        &lmx_devices,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::load_config_from_file;
+    use crate::transport::RecordingTransport;
+
+    #[test]
+    fn default_profiles_are_in_the_embedded_config() {
+        let config = load_config_from_file().unwrap();
+
+        for board in Board::ALL {
+            for profile in board.profiles() {
+                profile.check(board, &config).unwrap();
+            }
+            assert_eq!(board.to_string().parse::<Board>().unwrap(), board);
+        }
+
+        assert_eq!(Board::Zcu111.default_profile().lmx, vec![40960; 3]);
+        assert_eq!(Board::Zcu216.default_profile().lmk, 12288);
+        assert_eq!(Board::Rfsoc4x2.default_profile().lmx, vec![10240; 2]);
+    }
+
+    #[test]
+    fn lmx_profiles_need_their_reference_from_the_lmk() {
+        let config = load_config_from_file().unwrap();
+
+        // the LMK04828 outputs 500 MHz, the LMX profiles need 122.88 MHz
+        let error = BoardProfile::from("102.4", 50025, vec![10240; 3])
+            .check(Board::Zcu216, &config)
+            .unwrap_err();
+        assert_eq!(error.kind(), XRFClkErrorKind::InvalidFrequency);
+        assert_eq!(error.chip(), Some(Chip::LMX2594));
+
+        // unless the profile says it was planned for 500 MHz
+        let mut config = config;
+        let mut lmx = config
+            .profile(Chip::LMX2594, Frequency::from_10khz(10240))
+            .unwrap()
+            .clone();
+        lmx.reference = Some(Frequency::from_hz(500_000_000));
+        config.insert(lmx);
+        BoardProfile::from("102.4", 50025, vec![10240; 3])
+            .check(Board::Zcu216, &config)
+            .unwrap();
+    }
+
+    #[test]
+    fn spidev_nodes_sort_by_bus_and_chip_select() {
+        let mut locations = ["/dev/spidev1.10", "/dev/spidev1.2", "/dev/spidev0.3"];
+        locations.sort_by_key(|location| spidev_address(location));
+        assert_eq!(
+            locations,
+            ["/dev/spidev0.3", "/dev/spidev1.2", "/dev/spidev1.10"]
+        );
+        assert_eq!(spidev_address("recording transport"), None);
+    }
+
+    #[tokio::test]
+    async fn lmx_frequencies_follow_the_device_order() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let lmk = LMKDevice::from_transport(
+            Chip::LMK04208,
+            Box::new(RecordingTransport::new()),
+            4,
+            config.clone(),
+        );
+        let transports = [
+            RecordingTransport::new(),
+            RecordingTransport::new(),
+            RecordingTransport::new(),
+        ];
+        let lmx: Vec<LMXDevice> = transports
+            .iter()
+            .map(|transport| {
+                LMXDevice::from_transport(
+                    Chip::LMX2594,
+                    Box::new(transport.clone()),
+                    config.clone(),
+                )
+            })
+            .collect();
+
+        let frequencies = [20480, 40960, 73700];
+        let profile = BoardProfile::from("mixed", 12288, frequencies.to_vec());
+        program_board(&config, Board::Zcu111, &profile, &lmk, &lmx)
+            .await
+            .unwrap();
+
+        for (transport, frequency) in transports.iter().zip(frequencies) {
+            let r112 = config
+                .registers(Chip::LMX2594, Frequency::from_10khz(frequency))
+                .unwrap()
+                .get(112);
+            assert_eq!(transport.words().get(2).copied(), r112);
+        }
+
+        let error = program_board(&config, Board::Rfsoc4x2, &profile, &lmk, &lmx)
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), XRFClkErrorKind::InvalidBoard);
+    }
+}
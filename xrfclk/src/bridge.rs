@@ -0,0 +1,567 @@
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::transport::SpiTransport;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fs;
+use std::io::{Read, Write};
+use std::os::unix::io::AsRawFd;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::debug;
+
+/// I2C address of the SC18IS602 with all address pins pulled high, as on the
+/// ZCU111 and ZCU216.
+pub const SC18IS602_ADDRESS: u16 = 0x2F;
+/// I2C address of the TCA9548 mux in front of the bridge.
+pub const TCA9548_ADDRESS: u16 = 0x74;
+
+/// `I2C_SLAVE` from `linux/i2c-dev.h`.
+const I2C_SLAVE: u32 = 0x0703;
+
+/// Function ID that configures the SPI interface of the bridge.
+const SC18IS602_CONFIGURE_SPI: u8 = 0xF0;
+/// MSB first, CPOL = 0, CPHA = 0 and the slowest SPI clock of 58 kHz.
+const SC18IS602_SPI_MODE_0_58KHZ: u8 = 0x03;
+
+/// The bridge does not acknowledge its address while it is busy shifting
+/// out the previous word, accesses are retried this often.
+const BRIDGE_BUSY_RETRIES: usize = 10;
+const BRIDGE_BUSY_INTERVAL: Duration = Duration::from_millis(1);
+
+/// errnos of accesses that may succeed when retried: a NACK (ENXIO or
+/// EREMOTEIO depending on the I2C adapter), a busy or timed out adapter.
+const TRANSIENT_ERRNOS: [i32; 5] = [
+    libc::ENXIO,
+    libc::EREMOTEIO,
+    libc::EAGAIN,
+    libc::EIO,
+    libc::ETIMEDOUT,
+];
+
+/// Raw access to an I2C bus.
+pub trait I2cBus: Send {
+    fn write(&mut self, address: u16, bytes: &[u8]) -> Result<(), XRFClkError>;
+
+    fn read(&mut self, address: u16, buffer: &mut [u8]) -> Result<(), XRFClkError>;
+
+    /// Human readable location of the bus, used in log messages.
+    fn describe(&self) -> String;
+}
+
+/// I2C bus behind a `/dev/i2c-N` character device.
+pub struct I2cDevBus {
+    path: PathBuf,
+    file: Option<fs::File>,
+    address: Option<u16>,
+}
+
+impl I2cDevBus {
+    /// The device node is opened lazily on the first access.
+    pub fn from(path: PathBuf) -> Self {
+        Self {
+            path,
+            file: None,
+            address: None,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Opens the bus and points it at `address`.
+    fn file(&mut self, address: u16) -> Result<&mut fs::File, XRFClkError> {
+        if self.file.is_none() {
+            self.file = Some(
+                fs::OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .create(false)
+                    .open(&self.path)
+                    .map_err(|e| XRFClkError::io(e, &self.path))?,
+            );
+            self.address = None;
+        }
+
+        let file = self.file.as_mut().unwrap();
+
+        if self.address != Some(address) {
+            let result =
+                unsafe { libc::ioctl(file.as_raw_fd(), I2C_SLAVE as _, address as libc::c_ulong) };
+            if result < 0 {
+                return Err(XRFClkError::io(std::io::Error::last_os_error(), &self.path));
+            }
+            self.address = Some(address);
+        }
+
+        Ok(file)
+    }
+}
+
+impl I2cBus for I2cDevBus {
+    fn write(&mut self, address: u16, bytes: &[u8]) -> Result<(), XRFClkError> {
+        self.file(address)?
+            .write_all(bytes)
+            .map_err(|e| XRFClkError::io(e, &self.path))
+    }
+
+    fn read(&mut self, address: u16, buffer: &mut [u8]) -> Result<(), XRFClkError> {
+        self.file(address)?
+            .read_exact(buffer)
+            .map_err(|e| XRFClkError::io(e, &self.path))
+    }
+
+    fn describe(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+/// A single access on a `SimulatedI2cBus`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum I2cAccess {
+    Write { address: u16, bytes: Vec<u8> },
+    Read { address: u16, len: usize },
+}
+
+#[derive(Default)]
+struct Simulation {
+    accesses: Vec<I2cAccess>,
+    responses: VecDeque<Vec<u8>>,
+    /// errnos the next accesses fail with
+    failures: VecDeque<i32>,
+}
+
+/// In-memory I2C bus that records every access.
+///
+/// Clones share the same state, so one handle can be given to a transport
+/// while the other is kept around to inspect the bus traffic.
+#[derive(Clone, Default)]
+pub struct SimulatedI2cBus {
+    simulation: Arc<Mutex<Simulation>>,
+}
+
+impl SimulatedI2cBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All accesses that were acknowledged so far, in order.
+    pub fn accesses(&self) -> Vec<I2cAccess> {
+        self.simulation.lock().unwrap().accesses.clone()
+    }
+
+    /// Queues the bytes returned by the next read. Reads without a queued
+    /// response return zeros.
+    pub fn push_response(&self, bytes: &[u8]) {
+        self.simulation
+            .lock()
+            .unwrap()
+            .responses
+            .push_back(bytes.to_vec());
+    }
+
+    /// Makes the next `count` accesses fail like a device that does not
+    /// acknowledge its address.
+    pub fn nack(&self, count: usize) {
+        self.fail(count, libc::ENXIO);
+    }
+
+    /// Makes the next `count` accesses fail with `errno`, e.g. `EACCES`.
+    pub fn fail(&self, count: usize, errno: i32) {
+        let mut simulation = self.simulation.lock().unwrap();
+        simulation
+            .failures
+            .extend(std::iter::repeat_n(errno, count));
+    }
+
+    pub fn clear(&self) {
+        let mut simulation = self.simulation.lock().unwrap();
+        simulation.accesses.clear();
+        simulation.responses.clear();
+        simulation.failures.clear();
+    }
+}
+
+impl Simulation {
+    fn acknowledge(&mut self) -> Result<(), XRFClkError> {
+        match self.failures.pop_front() {
+            Some(errno) => Err(std::io::Error::from_raw_os_error(errno).into()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl I2cBus for SimulatedI2cBus {
+    fn write(&mut self, address: u16, bytes: &[u8]) -> Result<(), XRFClkError> {
+        let mut simulation = self.simulation.lock().unwrap();
+        simulation.acknowledge()?;
+        simulation.accesses.push(I2cAccess::Write {
+            address,
+            bytes: bytes.to_vec(),
+        });
+        Ok(())
+    }
+
+    fn read(&mut self, address: u16, buffer: &mut [u8]) -> Result<(), XRFClkError> {
+        let mut simulation = self.simulation.lock().unwrap();
+        simulation.acknowledge()?;
+        simulation.accesses.push(I2cAccess::Read {
+            address,
+            len: buffer.len(),
+        });
+
+        let response = simulation.responses.pop_front().unwrap_or_default();
+        let len = response.len().min(buffer.len());
+        buffer.fill(0);
+        buffer[..len].copy_from_slice(&response[..len]);
+
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        "simulated i2c bus".to_string()
+    }
+}
+
+/// Channel of an I2C mux that has to be selected before the bridge can be
+/// reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct I2cMux {
+    pub address: u16,
+    pub channel: u8,
+}
+
+impl I2cMux {
+    pub fn from(address: u16, channel: u8) -> Self {
+        Self { address, channel }
+    }
+}
+
+struct BusState {
+    bus: Box<dyn I2cBus>,
+    /// the mux channel selected last, `None` while unknown
+    selected: Option<I2cMux>,
+}
+
+/// An I2C bus shared by the transports of several bridges, e.g. the ones
+/// behind different channels of the same mux. It is held for a whole
+/// transaction and remembers the selected mux channel, so every transport
+/// talks to its own bridge even when they are used from several threads.
+///
+/// Clones share the same bus.
+#[derive(Clone)]
+pub struct SharedI2cBus {
+    state: Arc<Mutex<BusState>>,
+}
+
+impl SharedI2cBus {
+    pub fn from(bus: Box<dyn I2cBus>) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(BusState {
+                bus,
+                selected: None,
+            })),
+        }
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, BusState>, XRFClkError> {
+        self.state
+            .lock()
+            .map_err(|_| XRFClkError::from(XRFClkErrorKind::UnknownError))
+    }
+
+    pub fn describe(&self) -> String {
+        self.lock()
+            .map(|state| state.bus.describe())
+            .unwrap_or_default()
+    }
+}
+
+/// Whether `error` is one `with_retries` waits out.
+fn is_transient(error: &XRFClkError) -> bool {
+    error
+        .source()
+        .and_then(|source| source.downcast_ref::<std::io::Error>())
+        .and_then(std::io::Error::raw_os_error)
+        .is_some_and(|errno| TRANSIENT_ERRNOS.contains(&errno))
+}
+
+/// Retries `access` while the bridge is busy, other errors such as a
+/// missing or inaccessible bus are returned right away.
+fn with_retries<T>(
+    bus: &mut dyn I2cBus,
+    mut access: impl FnMut(&mut dyn I2cBus) -> Result<T, XRFClkError>,
+) -> Result<T, XRFClkError> {
+    let mut retries = 0;
+
+    loop {
+        match access(bus) {
+            Ok(value) => return Ok(value),
+            Err(e) if retries < BRIDGE_BUSY_RETRIES && is_transient(&e) => {
+                debug!("bridge on {} busy: {e}", bus.describe());
+                retries += 1;
+                std::thread::sleep(BRIDGE_BUSY_INTERVAL);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Transport that reaches a clock chip through an SC18IS602 I2C-to-SPI
+/// bridge, the way the Xilinx bare-metal driver does. No spidev overlay or
+/// kernel clock driver is needed, only `/dev/i2c-N`.
+pub struct Sc18is602Transport {
+    bus: SharedI2cBus,
+    address: u16,
+    /// SS0 to SS3, the chip select line of the clock chip
+    slave_select: u8,
+    mux: Option<I2cMux>,
+    /// the SPI interface of the bridge is configured on the first access
+    configured: bool,
+}
+
+impl Sc18is602Transport {
+    /// A transport that has `bus` to itself.
+    pub fn from(
+        bus: Box<dyn I2cBus>,
+        address: u16,
+        slave_select: u8,
+        mux: Option<I2cMux>,
+    ) -> Result<Self, XRFClkError> {
+        Self::from_shared(SharedI2cBus::from(bus), address, slave_select, mux)
+    }
+
+    /// A transport on a bus that other transports use as well.
+    pub fn from_shared(
+        bus: SharedI2cBus,
+        address: u16,
+        slave_select: u8,
+        mux: Option<I2cMux>,
+    ) -> Result<Self, XRFClkError> {
+        if slave_select > 3 || mux.is_some_and(|mux| mux.channel > 7) {
+            return Err(XRFClkError::from(XRFClkErrorKind::InvalidConfig));
+        }
+
+        Ok(Self {
+            bus,
+            address,
+            slave_select,
+            mux,
+            configured: false,
+        })
+    }
+
+    /// Runs `access` with the bus held and the mux channel of the bridge
+    /// selected, another transport may have switched it since the last one.
+    fn transaction<T>(
+        &mut self,
+        access: impl FnOnce(&mut dyn I2cBus) -> Result<T, XRFClkError>,
+    ) -> Result<T, XRFClkError> {
+        let bus = self.bus.clone();
+        let mut state = bus.lock()?;
+
+        if let Some(mux) = self.mux {
+            if state.selected != Some(mux) {
+                state.selected = None;
+                state.bus.write(mux.address, &[1 << mux.channel])?;
+                state.selected = Some(mux);
+            }
+        }
+
+        if !self.configured {
+            let address = self.address;
+            with_retries(state.bus.as_mut(), |bus| {
+                bus.write(
+                    address,
+                    &[SC18IS602_CONFIGURE_SPI, SC18IS602_SPI_MODE_0_58KHZ],
+                )
+            })?;
+            self.configured = true;
+        }
+
+        access(state.bus.as_mut())
+    }
+
+    /// The message that makes the bridge shift out `word`.
+    fn message(&self, word: u32, width: usize) -> Result<Vec<u8>, XRFClkError> {
+        if width == 0 || width > 4 {
+            return Err(XRFClkError::from(XRFClkErrorKind::InvalidRegister));
+        }
+
+        let mut message = vec![1 << self.slave_select];
+        message.extend_from_slice(&word.to_be_bytes()[4 - width..]);
+        Ok(message)
+    }
+}
+
+impl SpiTransport for Sc18is602Transport {
+    /// Hands the word to the bridge, which shifts it out on its own.
+    fn write_word(&mut self, word: u32, width: usize) -> Result<(), XRFClkError> {
+        let message = self.message(word, width)?;
+        let address = self.address;
+
+        self.transaction(|bus| with_retries(bus, |bus| bus.write(address, &message)))
+    }
+
+    fn transfer_word(&mut self, word: u32, width: usize) -> Result<u32, XRFClkError> {
+        let message = self.message(word, width)?;
+        let address = self.address;
+
+        self.transaction(|bus| {
+            with_retries(bus, |bus| bus.write(address, &message))?;
+
+            // the bytes clocked in during the transfer wait in the data
+            // buffer of the bridge
+            let mut received = [0u8; 4];
+            with_retries(bus, |bus| bus.read(address, &mut received[4 - width..]))?;
+
+            Ok(u32::from_be_bytes(received))
+        })
+    }
+
+    fn flush(&mut self) -> Result<(), XRFClkError> {
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "sc18is602 {:#04x} SS{} on {}",
+            self.address,
+            self.slave_select,
+            self.bus.describe()
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn transport(bus: &SimulatedI2cBus) -> Sc18is602Transport {
+        Sc18is602Transport::from(
+            Box::new(bus.clone()),
+            SC18IS602_ADDRESS,
+            1,
+            Some(I2cMux::from(TCA9548_ADDRESS, 5)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn selects_the_mux_and_frames_words() {
+        let bus = SimulatedI2cBus::new();
+        let mut transport = transport(&bus);
+
+        transport.write_word(0x700000, 3).unwrap();
+        transport.write_word(0x0002_0000, 4).unwrap();
+
+        assert_eq!(
+            bus.accesses(),
+            vec![
+                I2cAccess::Write {
+                    address: TCA9548_ADDRESS,
+                    bytes: vec![0x20],
+                },
+                I2cAccess::Write {
+                    address: SC18IS602_ADDRESS,
+                    bytes: vec![0xF0, 0x03],
+                },
+                I2cAccess::Write {
+                    address: SC18IS602_ADDRESS,
+                    bytes: vec![0x02, 0x70, 0x00, 0x00],
+                },
+                I2cAccess::Write {
+                    address: SC18IS602_ADDRESS,
+                    bytes: vec![0x02, 0x00, 0x02, 0x00, 0x00],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reselects_the_mux_for_every_transport() {
+        let bus = SimulatedI2cBus::new();
+        let shared = SharedI2cBus::from(Box::new(bus.clone()));
+        let mut lmk = Sc18is602Transport::from_shared(
+            shared.clone(),
+            SC18IS602_ADDRESS,
+            0,
+            Some(I2cMux::from(TCA9548_ADDRESS, 3)),
+        )
+        .unwrap();
+        let mut lmx = Sc18is602Transport::from_shared(
+            shared,
+            SC18IS602_ADDRESS,
+            1,
+            Some(I2cMux::from(TCA9548_ADDRESS, 5)),
+        )
+        .unwrap();
+
+        lmk.write_word(0x000000, 3).unwrap();
+        lmx.write_word(0x700000, 3).unwrap();
+        lmk.write_word(0x000100, 3).unwrap();
+
+        let mux_writes: Vec<_> = bus
+            .accesses()
+            .into_iter()
+            .filter_map(|access| match access {
+                I2cAccess::Write { address, bytes } if address == TCA9548_ADDRESS => Some(bytes),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(mux_writes, vec![vec![0x08], vec![0x20], vec![0x08]]);
+        assert_eq!(
+            bus.accesses().last(),
+            Some(&I2cAccess::Write {
+                address: SC18IS602_ADDRESS,
+                bytes: vec![0x01, 0x00, 0x01, 0x00],
+            })
+        );
+    }
+
+    #[test]
+    fn reads_the_answer_out_of_the_buffer() {
+        let bus = SimulatedI2cBus::new();
+        let mut transport = transport(&bus);
+        transport.write_word(0x000000, 3).unwrap();
+
+        // busy while shifting out the previous word and the read request
+        bus.nack(2);
+        bus.push_response(&[0xEE, 0x04, 0x00]);
+
+        assert_eq!(transport.transfer_word(0xEE0000, 3).unwrap(), 0xEE0400);
+        assert_eq!(
+            bus.accesses().last(),
+            Some(&I2cAccess::Read {
+                address: SC18IS602_ADDRESS,
+                len: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn gives_up_on_a_dead_bridge() {
+        let bus = SimulatedI2cBus::new();
+        let mut transport =
+            Sc18is602Transport::from(Box::new(bus.clone()), SC18IS602_ADDRESS, 0, None).unwrap();
+
+        bus.nack(BRIDGE_BUSY_RETRIES + 1);
+        assert!(transport.write_word(0x000000, 3).is_err());
+        assert!(Sc18is602Transport::from(Box::new(bus), SC18IS602_ADDRESS, 4, None).is_err());
+    }
+
+    #[test]
+    fn permanent_errors_are_not_retried() {
+        let bus = SimulatedI2cBus::new();
+        let mut transport =
+            Sc18is602Transport::from(Box::new(bus.clone()), SC18IS602_ADDRESS, 0, None).unwrap();
+
+        // every attempt takes one failure off the bus
+        bus.fail(2, libc::EACCES);
+        let error = transport.write_word(0x000000, 3).unwrap_err();
+        assert_eq!(error.kind(), XRFClkErrorKind::PermissionDenied);
+        assert!(transport.write_word(0x000000, 3).is_err());
+        transport.write_word(0x000000, 3).unwrap();
+    }
+}
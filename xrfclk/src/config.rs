@@ -0,0 +1,473 @@
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::sequence::parse_register_name;
+use crate::Chip;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::num::ParseIntError;
+use std::path::Path;
+use tracing::debug;
+
+/// A clock frequency. The profile keys of the config files count in units of
+/// 10 kHz (`10240` is 102.4 MHz) and are nominal, e.g. the LMX2594 profile
+/// `73700` actually outputs 737.28 MHz.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Frequency {
+    hz: u64,
+}
+
+impl Frequency {
+    pub const fn from_hz(hz: u64) -> Self {
+        Self { hz }
+    }
+
+    pub const fn from_khz(khz: u64) -> Self {
+        Self { hz: khz * 1_000 }
+    }
+
+    /// A frequency given as profile key in units of 10 kHz.
+    pub const fn from_10khz(key: u64) -> Self {
+        Self { hz: key * 10_000 }
+    }
+
+    pub const fn hz(&self) -> u64 {
+        self.hz
+    }
+
+    pub fn mhz(&self) -> f64 {
+        self.hz as f64 / 1e6
+    }
+
+    /// The profile key in units of 10 kHz, the frequency rounded down.
+    pub const fn as_10khz(&self) -> u64 {
+        self.hz / 10_000
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} MHz", self.mhz())
+    }
+}
+
+/// Register address embedded in an SPI word of `chip`.
+pub fn word_address(chip: Chip, word: u32) -> u16 {
+    match chip {
+        Chip::LMX2594 => ((word >> 16) & 0x7F) as u16,
+        Chip::LMK04828 | Chip::LMK04832 => ((word >> 8) & 0x7FFF) as u16,
+        Chip::LMK04208 => (word & 0x1F) as u16,
+    }
+}
+
+/// SPI word of `chip` addressing `address` with every data bit cleared.
+pub fn empty_word(chip: Chip, address: u16) -> u32 {
+    let address = address as u32;
+
+    match chip {
+        Chip::LMX2594 => (address & 0x7F) << 16,
+        Chip::LMK04828 | Chip::LMK04832 => (address & 0x7FFF) << 8,
+        Chip::LMK04208 => address & 0x1F,
+    }
+}
+
+/// Parses a register word written as hex, with or without `0x` prefix.
+pub(crate) fn parse_hex_word(value: &str) -> Result<u32, ParseIntError> {
+    let value = value.trim();
+    let digits = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(value);
+
+    u32::from_str_radix(digits, 16)
+}
+
+/// Register words of one chip keyed by their address. Every word is a full
+/// SPI word, and the address it carries always matches its key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterSet {
+    chip: Chip,
+    /// the `R0 (INIT)` write that resets the chip before programming
+    init: Option<u32>,
+    registers: BTreeMap<u16, u32>,
+}
+
+impl RegisterSet {
+    pub fn new(chip: Chip) -> Self {
+        Self {
+            chip,
+            init: None,
+            registers: BTreeMap::new(),
+        }
+    }
+
+    /// Builds a register set out of `R<n>` / `R0 (INIT)` names and words.
+    pub fn from_named(chip: Chip, values: &HashMap<String, u32>) -> Result<Self, XRFClkError> {
+        let mut registers = Self::new(chip);
+
+        for (name, word) in values {
+            registers.insert_named(name, *word)?;
+        }
+
+        Ok(registers)
+    }
+
+    pub fn chip(&self) -> Chip {
+        self.chip
+    }
+
+    fn check_address(&self, address: u16, word: u32) -> Result<(), XRFClkError> {
+        if word_address(self.chip, word) != address {
+            debug!(
+                "register word {word:#08X} of chip {} does not address R{address}",
+                self.chip
+            );
+            return Err(XRFClkError::from(XRFClkErrorKind::InvalidRegister).with_chip(self.chip));
+        }
+
+        Ok(())
+    }
+
+    /// Adds or replaces the word of `address`, returning the old one.
+    pub fn insert(&mut self, address: u16, word: u32) -> Result<Option<u32>, XRFClkError> {
+        self.check_address(address, word)?;
+        Ok(self.registers.insert(address, word))
+    }
+
+    /// Like `insert`, with the register given by its name in the config.
+    pub fn insert_named(&mut self, name: &str, word: u32) -> Result<Option<u32>, XRFClkError> {
+        match parse_register_name(name) {
+            Some((address, true)) => {
+                self.check_address(address, word)?;
+                Ok(self.init.replace(word))
+            }
+            Some((address, false)) => self.insert(address, word),
+            None => {
+                debug!("invalid register name {name}");
+                Err(XRFClkError::from(XRFClkErrorKind::InvalidRegister))
+            }
+        }
+    }
+
+    pub fn get(&self, address: u16) -> Option<u32> {
+        self.registers.get(&address).copied()
+    }
+
+    /// The word of `address`, inserted with all data bits cleared if the set
+    /// does not contain it yet.
+    pub(crate) fn word_mut(&mut self, address: u16) -> &mut u32 {
+        self.registers
+            .entry(address)
+            .or_insert(empty_word(self.chip, address))
+    }
+
+    pub fn init(&self) -> Option<u32> {
+        self.init
+    }
+
+    /// Number of registers, not counting the `R0 (INIT)` write.
+    pub fn len(&self) -> usize {
+        self.registers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.registers.is_empty()
+    }
+
+    /// Registers in ascending address order.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u32)> + '_ {
+        self.registers
+            .iter()
+            .map(|(address, word)| (*address, *word))
+    }
+
+    /// The register set in the `R<n>` naming scheme of the config files.
+    pub fn to_named(&self) -> HashMap<String, u32> {
+        self.init
+            .map(|word| ("R0 (INIT)".to_string(), word))
+            .into_iter()
+            .chain(
+                self.iter()
+                    .map(|(address, word)| (format!("R{address}"), word)),
+            )
+            .collect()
+    }
+}
+
+/// Register set of a chip for one output frequency, along with where it
+/// comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClockProfile {
+    /// nominal frequency the profile is selected by
+    pub frequency: Frequency,
+    /// frequency the chip has to be fed with for this profile
+    pub reference: Option<Frequency>,
+    pub description: Option<String>,
+    pub board: Option<String>,
+    pub registers: RegisterSet,
+}
+
+impl ClockProfile {
+    pub fn from(frequency: Frequency, registers: RegisterSet) -> Self {
+        Self {
+            frequency,
+            reference: None,
+            description: None,
+            board: None,
+            registers,
+        }
+    }
+
+    pub fn chip(&self) -> Chip {
+        self.registers.chip()
+    }
+}
+
+/// Every known clock profile, by chip and frequency.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    profiles: HashMap<Chip, BTreeMap<Frequency, ClockProfile>>,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a profile, replacing the one of the same chip and frequency.
+    pub fn insert(&mut self, profile: ClockProfile) -> Option<ClockProfile> {
+        self.profiles
+            .entry(profile.chip())
+            .or_default()
+            .insert(profile.frequency, profile)
+    }
+
+    pub fn profile(&self, chip: Chip, frequency: Frequency) -> Option<&ClockProfile> {
+        self.profiles.get(&chip)?.get(&frequency)
+    }
+
+    pub fn registers(&self, chip: Chip, frequency: Frequency) -> Option<&RegisterSet> {
+        self.profile(chip, frequency)
+            .map(|profile| &profile.registers)
+    }
+
+    /// Profiles of `chip` in ascending frequency order.
+    pub fn profiles(&self, chip: Chip) -> impl Iterator<Item = &ClockProfile> + '_ {
+        self.profiles
+            .get(&chip)
+            .into_iter()
+            .flat_map(|profiles| profiles.values())
+    }
+
+    pub fn chips(&self) -> impl Iterator<Item = Chip> + '_ {
+        self.profiles.keys().copied()
+    }
+
+    /// Layers `overlay` over this config. Profiles are replaced as a whole,
+    /// so a profile of the overlay never inherits registers from the profile
+    /// it replaces.
+    pub fn merge(&mut self, overlay: Config) {
+        for (chip, profiles) in overlay.profiles {
+            self.profiles.entry(chip).or_default().extend(profiles);
+        }
+    }
+}
+
+/// A profile in a config file: either just the register map or the
+/// register map along with metadata.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawProfile {
+    Described(DescribedProfile),
+    Registers(HashMap<String, String>),
+}
+
+/// Misspelled metadata keys are errors rather than silently dropped.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DescribedProfile {
+    registers: HashMap<String, String>,
+    reference_hz: Option<u64>,
+    description: Option<String>,
+    board: Option<String>,
+}
+
+type RawConfig = HashMap<Chip, HashMap<String, RawProfile>>;
+
+fn invalid_config() -> XRFClkError {
+    XRFClkError::from(XRFClkErrorKind::InvalidConfig)
+}
+
+fn config_from_raw(raw_config: RawConfig) -> Result<Config, XRFClkError> {
+    let mut config = Config::new();
+
+    for (chip, profiles) in raw_config {
+        for (key, raw_profile) in profiles {
+            let key = key
+                .trim()
+                .parse::<u64>()
+                .map_err(|e| invalid_config().with_chip(chip).with_source(e))?;
+
+            let (values, reference_hz, description, board) = match raw_profile {
+                RawProfile::Described(DescribedProfile {
+                    registers,
+                    reference_hz,
+                    description,
+                    board,
+                }) => (registers, reference_hz, description, board),
+                RawProfile::Registers(registers) => (registers, None, None, None),
+            };
+
+            let mut registers = RegisterSet::new(chip);
+            for (name, value) in values {
+                let word = parse_hex_word(&value).map_err(|e| {
+                    debug!("invalid word {value} of {name} in profile {key} of chip {chip}");
+                    let error = invalid_config().with_chip(chip).with_frequency(key);
+                    match parse_register_name(&name) {
+                        Some((address, _)) => error.with_register(address),
+                        None => error,
+                    }
+                    .with_source(e)
+                })?;
+                registers
+                    .insert_named(&name, word)
+                    .map_err(|e| e.with_chip(chip).with_frequency(key))?;
+            }
+
+            config.insert(ClockProfile {
+                frequency: Frequency::from_10khz(key),
+                reference: reference_hz.map(Frequency::from_hz),
+                description,
+                board,
+                registers,
+            });
+        }
+    }
+
+    Ok(config)
+}
+
+/// Parses a config in the format of the embedded `config.json`: chip name,
+/// then the profile key in units of 10 kHz, then register name to hex word.
+/// Instead of the bare register map a profile may also be an object with
+/// `registers` and the optional `reference_hz`, `description` and `board`.
+pub fn parse_config_json(contents: &str) -> Result<Config, XRFClkError> {
+    let raw_config: RawConfig =
+        serde_json::from_str(contents).map_err(|e| invalid_config().with_source(e))?;
+
+    config_from_raw(raw_config)
+}
+
+/// Same layout as `parse_config_json`, written as TOML tables, e.g.
+/// `[lmx2594.10240]` followed by `R112 = "0x700000"`.
+pub fn parse_config_toml(contents: &str) -> Result<Config, XRFClkError> {
+    let raw_config: RawConfig =
+        toml::from_str(contents).map_err(|e| invalid_config().with_source(e))?;
+
+    config_from_raw(raw_config)
+}
+
+/// The config that is embedded into the library at compile time.
+pub fn load_config_from_file() -> Result<Config, XRFClkError> {
+    parse_config_json(include_str!("config.json"))
+}
+
+/// Loads a JSON or TOML config from disk, chosen by the file extension.
+pub fn load_config_from_path(path: &Path) -> Result<Config, XRFClkError> {
+    debug!("loading config from {}", path.display());
+
+    let contents = fs::read_to_string(path).map_err(|e| XRFClkError::io(e, path))?;
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => parse_config_json(&contents),
+        Some("toml") => parse_config_toml(&contents),
+        _ => Err(XRFClkError::from(XRFClkErrorKind::InvalidFilePath)),
+    }
+    .map_err(|e| e.with_path(path))
+}
+
+/// The embedded config with every file of `paths` layered over it in order.
+pub fn load_config_with_overrides(paths: &[&Path]) -> Result<Config, XRFClkError> {
+    let mut config = load_config_from_file()?;
+
+    for path in paths {
+        config.merge(load_config_from_path(path)?);
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_if_the_json_parses() {
+        let config = load_config_from_file().unwrap();
+        let registers = config
+            .registers(Chip::LMK04828, Frequency::from_10khz(50025))
+            .unwrap();
+
+        assert_eq!(registers.init(), Some(0x000090));
+        assert_eq!(registers.get(0x100), Some(0x010006));
+    }
+
+    #[test]
+    fn toml_configs_merge_over_the_defaults() {
+        let overlay = parse_config_toml(
+            "[lmx2594.12345]\nR0 = \"0x00249C\"\n\n\
+             [lmk04828.50025]\ndescription = \"R0 only\"\nboard = \"zcu111\"\n\
+             reference_hz = 160000000\n\n[lmk04828.50025.registers]\nR0 = \"0x000010\"\n",
+        )
+        .unwrap();
+
+        let mut config = load_config_from_file().unwrap();
+        config.merge(overlay);
+
+        assert_eq!(config.profiles(Chip::LMX2594).count(), 5);
+        assert_eq!(
+            config
+                .registers(Chip::LMX2594, Frequency::from_khz(123450))
+                .unwrap()
+                .get(0),
+            Some(0x00249C)
+        );
+
+        let profile = config
+            .profile(Chip::LMK04828, Frequency::from_hz(500_250_000))
+            .unwrap();
+        assert_eq!(profile.registers.len(), 1);
+        assert_eq!(profile.reference, Some(Frequency::from_hz(160_000_000)));
+        assert_eq!(profile.board.as_deref(), Some("zcu111"));
+    }
+
+    #[test]
+    fn invalid_configs_are_errors() {
+        assert!(parse_config_json("{\"lmx2594\": {\"10240\": {\"R0\": \"0xZZ\"}}}").is_err());
+        assert!(parse_config_json("{\"lmx2594\": {\"fast\": {}}}").is_err());
+        assert!(parse_config_json("{\"lmx9999\": {}}").is_err());
+        assert!(parse_config_json(
+            "{\"lmx2594\": {\"10240\": {\"registers\": {}, \"descripton\": \"typo\"}}}"
+        )
+        .is_err());
+
+        let error =
+            parse_config_json("{\"lmx2594\": {\"10240\": {\"R36\": \"0x24XX\"}}}").unwrap_err();
+        assert_eq!(error.kind(), XRFClkErrorKind::InvalidConfig);
+        assert_eq!(error.chip(), Some(Chip::LMX2594));
+        assert_eq!(error.frequency(), Some(10240));
+        assert_eq!(error.register(), Some(36));
+        assert!(load_config_from_path(Path::new("/nonexistent/config.json")).is_err());
+    }
+
+    #[test]
+    fn embedded_addresses_have_to_match_the_name() {
+        let error =
+            parse_config_json("{\"lmx2594\": {\"10240\": {\"R1\": \"0x00249C\"}}}").unwrap_err();
+        assert_eq!(error.kind(), XRFClkErrorKind::InvalidRegister);
+
+        let mut registers = RegisterSet::new(Chip::LMK04208);
+        assert!(registers.insert(12, 0x8000000C).is_ok());
+        assert!(registers.insert(13, 0x8000000C).is_err());
+    }
+}
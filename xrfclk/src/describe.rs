@@ -0,0 +1,592 @@
+use crate::config::{Frequency, RegisterSet};
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::planner::lmk048xx::pll2_prescaler;
+use crate::planner::lmx2594::CHANNEL_DIVIDERS;
+use crate::regmap::{lmk04208, lmk04828, lmk04832, lmx2594, FieldName};
+use crate::Chip;
+use std::collections::HashMap;
+use std::fmt;
+use tracing::debug;
+
+/// A PLL of the chip as its registers configure it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PllDescription {
+    pub name: &'static str,
+    pub enabled: bool,
+    /// total divider between the reference and the phase detector
+    pub r: u32,
+    /// feedback divider, fractional on the LMX2594
+    pub n: f64,
+    /// frequency at the reference input, OSCin or CLKin0 for PLL1
+    pub input_hz: f64,
+    pub phase_detector_hz: f64,
+    /// frequency the PLL runs at, the VCO or the VCXO for PLL1
+    pub output_hz: f64,
+}
+
+/// A clock output and what it generates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputDescription {
+    pub name: String,
+    pub enabled: bool,
+    /// what drives the output, e.g. `VCO`, `CHDIV` or `SYSREF`
+    pub source: &'static str,
+    pub divider: Option<u32>,
+    pub frequency_hz: Option<f64>,
+    /// output power setting or driver format
+    pub power: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SysrefDescription {
+    pub enabled: bool,
+    pub mode: &'static str,
+    pub divider: u32,
+    /// `None` where the frequency does not follow from a single divider
+    pub frequency_hz: Option<f64>,
+}
+
+/// What a register set makes a chip generate, for a given reference.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClockDescription {
+    pub chip: Chip,
+    /// frequency at OSCin
+    pub reference: Frequency,
+    pub plls: Vec<PllDescription>,
+    pub vco_hz: f64,
+    pub outputs: Vec<OutputDescription>,
+    /// `None` if the chip has no SYSREF generator
+    pub sysref: Option<SysrefDescription>,
+}
+
+fn mhz(hz: f64) -> String {
+    format!("{:.6} MHz", hz / 1e6)
+}
+
+impl fmt::Display for ClockDescription {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{}: reference {}",
+            self.chip,
+            mhz(self.reference.hz() as f64)
+        )?;
+
+        for pll in &self.plls {
+            if !pll.enabled {
+                writeln!(f, "  {}: powered down", pll.name)?;
+                continue;
+            }
+            writeln!(
+                f,
+                "  {}: {} R {} N {} phase detector {} -> {}",
+                pll.name,
+                mhz(pll.input_hz),
+                pll.r,
+                pll.n,
+                mhz(pll.phase_detector_hz),
+                mhz(pll.output_hz)
+            )?;
+        }
+        writeln!(f, "  VCO: {}", mhz(self.vco_hz))?;
+
+        for output in &self.outputs {
+            if !output.enabled {
+                writeln!(f, "  {}: off", output.name)?;
+                continue;
+            }
+            write!(f, "  {}: {}", output.name, output.source)?;
+            if let Some(divider) = output.divider {
+                write!(f, " / {divider}")?;
+            }
+            if let Some(frequency) = output.frequency_hz {
+                write!(f, " = {}", mhz(frequency))?;
+            }
+            writeln!(f, ", {}", output.power)?;
+        }
+
+        if let Some(sysref) = &self.sysref {
+            match (sysref.enabled, sysref.frequency_hz) {
+                (false, _) => writeln!(f, "  SYSREF: off")?,
+                (true, Some(frequency)) => writeln!(
+                    f,
+                    "  SYSREF: {}, divider {} = {}",
+                    sysref.mode,
+                    sysref.divider,
+                    mhz(frequency)
+                )?,
+                (true, None) => {
+                    writeln!(f, "  SYSREF: {}, divider {}", sysref.mode, sysref.divider)?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes a register set in the naming scheme of the config files, as
+/// returned by `RegisterSet::to_named` or read back from a chip.
+pub fn describe(
+    chip: Chip,
+    registers: &HashMap<String, u32>,
+    reference: Frequency,
+) -> Result<ClockDescription, XRFClkError> {
+    describe_registers(&RegisterSet::from_named(chip, registers)?, reference)
+}
+
+/// Like `describe`, for a register set.
+pub fn describe_registers(
+    registers: &RegisterSet,
+    reference: Frequency,
+) -> Result<ClockDescription, XRFClkError> {
+    match registers.chip() {
+        Chip::LMX2594 => describe_lmx2594(registers, reference),
+        Chip::LMK04828 | Chip::LMK04832 => describe_lmk0482x(registers, reference),
+        Chip::LMK04208 => describe_lmk04208(registers, reference),
+    }
+}
+
+/// Reads a field that fits into 32 bits.
+fn field(registers: &RegisterSet, name: FieldName) -> Result<u32, XRFClkError> {
+    Ok(registers.read_field(name)? as u32)
+}
+
+fn flag(registers: &RegisterSet, name: FieldName) -> Result<bool, XRFClkError> {
+    Ok(registers.read_field(name)? != 0)
+}
+
+fn describe_lmx2594(
+    registers: &RegisterSet,
+    reference: Frequency,
+) -> Result<ClockDescription, XRFClkError> {
+    let doubler = if flag(registers, lmx2594::OSC_2X)? {
+        2.0
+    } else {
+        1.0
+    };
+    // MULT 1 bypasses the multiplier
+    let mult = field(registers, lmx2594::MULT)?.max(1) as f64;
+    let r = field(registers, lmx2594::PLL_R_PRE)?.max(1) * field(registers, lmx2594::PLL_R)?.max(1);
+    let phase_detector_hz = reference.hz() as f64 * doubler * mult / r as f64;
+
+    let den = registers.read_field(lmx2594::PLL_DEN)?.max(1);
+    let n = registers.read_field(lmx2594::PLL_N)? as f64
+        + registers.read_field(lmx2594::PLL_NUM)? as f64 / den as f64;
+    let vco_hz = phase_detector_hz * n;
+
+    let chdiv = CHANNEL_DIVIDERS
+        .get(field(registers, lmx2594::CHDIV)? as usize)
+        .copied();
+
+    let output = |name: &str, pd, mux, pwr| {
+        let (source, divider) = match field(registers, mux)? {
+            0 => ("CHDIV", chdiv),
+            1 => ("VCO", None),
+            2 => ("SYSREF", None),
+            _ => ("high impedance", None),
+        };
+        let frequency_hz = match source {
+            "CHDIV" => divider.map(|divider| vco_hz / divider as f64),
+            "VCO" => Some(vco_hz),
+            _ => None,
+        };
+
+        Ok::<_, XRFClkError>(OutputDescription {
+            name: name.to_string(),
+            enabled: !flag(registers, pd)? && source != "high impedance",
+            source,
+            divider,
+            frequency_hz,
+            power: format!("power {}", field(registers, pwr)?),
+        })
+    };
+    let mut outputs = vec![
+        output(
+            "RFoutA",
+            lmx2594::OUTA_PD,
+            lmx2594::OUTA_MUX,
+            lmx2594::OUTA_PWR,
+        )?,
+        output(
+            "RFoutB",
+            lmx2594::OUTB_PD,
+            lmx2594::OUTB_MUX,
+            lmx2594::OUTB_PWR,
+        )?,
+    ];
+    // OUTA_MUX has no SYSREF input
+    if outputs[0].source == "SYSREF" {
+        outputs[0].source = "reserved";
+        outputs[0].enabled = false;
+    }
+
+    let sysref = SysrefDescription {
+        enabled: flag(registers, lmx2594::SYSREF_EN)?,
+        mode: match (
+            flag(registers, lmx2594::SYSREF_REPEAT)?,
+            flag(registers, lmx2594::SYSREF_PULSE)?,
+        ) {
+            (false, _) => "master mode",
+            (true, false) => "repeater mode",
+            (true, true) => "pulser mode",
+        },
+        divider: field(registers, lmx2594::SYSREF_DIV)?,
+        frequency_hz: None,
+    };
+
+    Ok(ClockDescription {
+        chip: Chip::LMX2594,
+        reference,
+        plls: vec![PllDescription {
+            name: "PLL",
+            enabled: !flag(registers, lmx2594::POWERDOWN)?,
+            r,
+            n,
+            input_hz: reference.hz() as f64,
+            phase_detector_hz,
+            output_hz: vco_hz,
+        }],
+        vco_hz,
+        outputs,
+        sysref: Some(sysref),
+    })
+}
+
+/// Driver format names of the LMK0482x clock outputs.
+fn lmk0482x_format(code: u32) -> String {
+    match code {
+        0 => "powered down".to_string(),
+        1 => "LVDS".to_string(),
+        2 => "HSDS 6 mA".to_string(),
+        3 => "HSDS 8 mA".to_string(),
+        4 => "HSDS 10 mA".to_string(),
+        5 => "LVPECL 1600 mV".to_string(),
+        6 => "LVPECL 2000 mV".to_string(),
+        7 => "LCPECL".to_string(),
+        code => format!("format {code}"),
+    }
+}
+
+/// PLL1 locks the VCXO at OSCin to CLKin0 by comparing CLKin0 / `r`, the
+/// R divider of CLKin0, with `feedback_hz` / `n`, PLL1_N. The feedback is
+/// OSCin itself, or a clock output in 0-delay mode, so the CLKin0 it
+/// expects follows from the VCXO.
+fn lmk_pll1(
+    reference: Frequency,
+    feedback_hz: f64,
+    r: u32,
+    n: u32,
+    enabled: bool,
+) -> PllDescription {
+    let phase_detector_hz = feedback_hz / n as f64;
+
+    PllDescription {
+        name: "PLL1",
+        enabled,
+        r,
+        n: n as f64,
+        input_hz: phase_detector_hz * r as f64,
+        phase_detector_hz,
+        output_hz: reference.hz() as f64,
+    }
+}
+
+fn unsupported_feedback(registers: &RegisterSet) -> XRFClkError {
+    debug!(
+        "the PLL1 feedback of the {} registers is not decoded",
+        registers.chip()
+    );
+    XRFClkError::from(XRFClkErrorKind::UnsupportedOperation).with_chip(registers.chip())
+}
+
+fn describe_lmk0482x(
+    registers: &RegisterSet,
+    reference: Frequency,
+) -> Result<ClockDescription, XRFClkError> {
+    let chip = registers.chip();
+    let doubler = if flag(registers, lmk04828::PLL2_REF_2X_EN)? {
+        2.0
+    } else {
+        1.0
+    };
+    let r = field(registers, lmk04828::PLL2_R)?.max(1);
+    let p = pll2_prescaler(field(registers, lmk04828::PLL2_P)?);
+    let n = field(registers, lmk04828::PLL2_N)?;
+    let phase_detector_hz = reference.hz() as f64 * doubler / r as f64;
+    let vco_hz = phase_detector_hz * (p * n) as f64;
+
+    let pll2 = PllDescription {
+        name: "PLL2",
+        enabled: !flag(registers, lmk04828::PLL2_PD)?,
+        r,
+        n: (p * n) as f64,
+        input_hz: reference.hz() as f64,
+        phase_detector_hz,
+        output_hz: vco_hz,
+    };
+
+    let sysref_divider = field(registers, lmk04828::SYSREF_DIV)?.max(1);
+    let sysref_hz = vco_hz / sysref_divider as f64;
+
+    let mut outputs = Vec::new();
+    for pair in 0..7 {
+        let (x, y) = (2 * pair, 2 * pair + 1);
+
+        let pd = flag(registers, lmk04828::CLKOUT_PD[pair])?;
+        let (divider, bypass, y_is_sysref, y_pd, x_format, y_format) = match chip {
+            Chip::LMK04832 => {
+                let y_is_sysref = flag(registers, lmk04832::CLKOUT_SRC_MUX[y])?;
+                (
+                    field(registers, lmk04832::DCLK_DIV[pair])?,
+                    flag(registers, lmk04832::DCLK_BYP[pair])?,
+                    y_is_sysref,
+                    // SCLKX_Y_PD only powers down the SYSREF path
+                    y_is_sysref && flag(registers, lmk04832::SCLK_PD[pair])?,
+                    field(registers, lmk04832::CLKOUT_FMT[x])?,
+                    field(registers, lmk04832::CLKOUT_FMT[y])?,
+                )
+            }
+            _ => (
+                // a divider of 32 is encoded as 0
+                match field(registers, lmk04828::DCLKOUT_DIV[pair])? {
+                    0 => 32,
+                    divider => divider,
+                },
+                field(registers, lmk04828::DCLKOUT_MUX[pair])? == 2,
+                flag(registers, lmk04828::SDCLKOUT_MUX[pair])?,
+                flag(registers, lmk04828::SDCLKOUT_PD[pair])?,
+                field(registers, lmk04828::DCLKOUT_FMT[pair])?,
+                field(registers, lmk04828::SDCLKOUT_FMT[pair])?,
+            ),
+        };
+
+        let device_clock = |name: String, pd: bool, format: u32| OutputDescription {
+            name,
+            enabled: !pd && format != 0,
+            source: if bypass { "VCO" } else { "DCLK" },
+            divider: (!bypass).then_some(divider),
+            frequency_hz: Some(if bypass {
+                vco_hz
+            } else {
+                vco_hz / divider.max(1) as f64
+            }),
+            power: lmk0482x_format(format),
+        };
+
+        outputs.push(device_clock(format!("CLKout{x}"), pd, x_format));
+        outputs.push(if y_is_sysref {
+            OutputDescription {
+                name: format!("CLKout{y}"),
+                enabled: !pd && !y_pd && y_format != 0,
+                source: "SYSREF",
+                divider: Some(sysref_divider),
+                frequency_hz: Some(sysref_hz),
+                power: lmk0482x_format(y_format),
+            }
+        } else {
+            device_clock(format!("CLKout{y}"), pd || y_pd, y_format)
+        });
+    }
+
+    let sysref = SysrefDescription {
+        enabled: !flag(registers, lmk04828::SYSREF_PD)?,
+        mode: match field(registers, lmk04828::SYSREF_MUX)? {
+            0 => "normal SYNC",
+            1 => "re-clocked",
+            2 => "pulser",
+            _ => "continuous",
+        },
+        divider: sysref_divider,
+        frequency_hz: Some(sysref_hz),
+    };
+
+    // in 0-delay mode FB_MUX feeds CLKout6, CLKout8 or SYSREF back to PLL1
+    let feedback_hz = if flag(registers, lmk04828::PLL1_NCLK_MUX)? {
+        match field(registers, lmk04828::FB_MUX)? {
+            0 => outputs[6].frequency_hz,
+            1 => outputs[8].frequency_hz,
+            2 => Some(sysref_hz),
+            _ => None,
+        }
+        .ok_or_else(|| unsupported_feedback(registers))?
+    } else {
+        reference.hz() as f64
+    };
+    let pll1 = lmk_pll1(
+        reference,
+        feedback_hz,
+        field(registers, lmk04828::CLKIN0_R)?.max(1),
+        field(registers, lmk04828::PLL1_N)?.max(1),
+        !flag(registers, lmk04828::PLL1_PD)?,
+    );
+
+    Ok(ClockDescription {
+        chip,
+        reference,
+        plls: vec![pll1, pll2],
+        vco_hz,
+        outputs,
+        sysref: Some(sysref),
+    })
+}
+
+/// Driver type names of the LMK04208 clock outputs.
+fn lmk04208_type(code: u32) -> String {
+    match code {
+        0 => "powered down".to_string(),
+        1 => "LVDS".to_string(),
+        2 => "LVPECL 700 mV".to_string(),
+        3 => "LVPECL 1200 mV".to_string(),
+        4 => "LVPECL 1600 mV".to_string(),
+        5 => "LVPECL 2000 mV".to_string(),
+        6 => "LVCMOS".to_string(),
+        code => format!("type {code}"),
+    }
+}
+
+fn describe_lmk04208(
+    registers: &RegisterSet,
+    reference: Frequency,
+) -> Result<ClockDescription, XRFClkError> {
+    // the 0-delay modes feed back a clock output, which is not decoded
+    if [2, 5].contains(&field(registers, lmk04208::MODE)?) {
+        return Err(unsupported_feedback(registers));
+    }
+    // PLL1 has no power down of its own
+    let pll1 = lmk_pll1(
+        reference,
+        reference.hz() as f64,
+        field(registers, lmk04208::PLL1_R)?.max(1),
+        field(registers, lmk04208::PLL1_N)?.max(1),
+        true,
+    );
+
+    let doubler = if flag(registers, lmk04208::EN_PLL2_REF_2X)? {
+        2.0
+    } else {
+        1.0
+    };
+    let r = field(registers, lmk04208::PLL2_R)?.max(1);
+    let p = pll2_prescaler(field(registers, lmk04208::PLL2_P)?);
+    let n = field(registers, lmk04208::PLL2_N)?;
+    let phase_detector_hz = reference.hz() as f64 * doubler / r as f64;
+    let vco_hz = phase_detector_hz * (p * n) as f64;
+
+    let pll2 = PllDescription {
+        name: "PLL2",
+        enabled: !flag(registers, lmk04208::POWERDOWN)?,
+        r,
+        n: (p * n) as f64,
+        input_hz: reference.hz() as f64,
+        phase_detector_hz,
+        output_hz: vco_hz,
+    };
+
+    let mut outputs = Vec::new();
+    for output in 0..6 {
+        let divider = field(registers, lmk04208::CLKOUT_DIV[output])?.max(1);
+        let output_type = field(registers, lmk04208::CLKOUT_TYPE[output])?;
+
+        outputs.push(OutputDescription {
+            name: format!("CLKout{output}"),
+            enabled: !flag(registers, lmk04208::CLKOUT_PD[output])? && output_type != 0,
+            source: "VCO",
+            divider: Some(divider),
+            frequency_hz: Some(vco_hz / divider as f64),
+            power: lmk04208_type(output_type),
+        });
+    }
+
+    Ok(ClockDescription {
+        chip: Chip::LMK04208,
+        reference,
+        plls: vec![pll1, pll2],
+        vco_hz,
+        outputs,
+        sysref: None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::load_config_from_file;
+    use crate::planner::lmk048xx::PLL1_PD_MAX_HZ;
+
+    #[test]
+    fn describes_the_lmk04828_profiles() {
+        let config = load_config_from_file().unwrap();
+        let reference = Frequency::from_hz(160_000_000);
+
+        for (profile, sysref_hz) in [(50025, 10e6), (50023, 7.8125e6)] {
+            let registers = config
+                .registers(Chip::LMK04828, Frequency::from_10khz(profile))
+                .unwrap();
+            let description = describe(Chip::LMK04828, &registers.to_named(), reference).unwrap();
+
+            assert_eq!(description.vco_hz, 3e9);
+            assert_eq!(description.plls[1].phase_detector_hz, 20e6);
+            assert_eq!(
+                description.sysref.as_ref().unwrap().frequency_hz,
+                Some(sysref_hz)
+            );
+            for output in description.outputs.iter().filter(|output| output.enabled) {
+                match output.source {
+                    "SYSREF" => assert_eq!(output.frequency_hz, Some(sysref_hz)),
+                    _ => assert_eq!(output.frequency_hz, Some(500e6)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decodes_pll1_from_the_vcxo() {
+        let config = load_config_from_file().unwrap();
+
+        // 0-delay mode, PLL1 compares the SYSREF output with a 10 MHz CLKin0
+        for profile in [50018, 50025] {
+            let registers = config
+                .registers(Chip::LMK04828, Frequency::from_10khz(profile))
+                .unwrap();
+            let pll1 = &describe_registers(registers, Frequency::from_hz(160_000_000))
+                .unwrap()
+                .plls[0];
+
+            assert_eq!(pll1.output_hz, 160e6);
+            assert_eq!(pll1.input_hz, 10e6);
+            assert!(pll1.phase_detector_hz <= PLL1_PD_MAX_HZ as f64);
+        }
+
+        // OSCin feedback, the VCXO is PLL1_N times the phase detector
+        let registers = config
+            .registers(Chip::LMK04832, Frequency::from_10khz(12288))
+            .unwrap();
+        let pll1 = &describe_registers(registers, Frequency::from_hz(122_880_000))
+            .unwrap()
+            .plls[0];
+        assert_eq!(pll1.phase_detector_hz, 102_400.0);
+        assert_eq!(pll1.phase_detector_hz * pll1.n, pll1.output_hz);
+        assert_eq!(pll1.input_hz, 12.288e6);
+    }
+
+    #[test]
+    fn describes_the_lmx2594_outputs() {
+        let config = load_config_from_file().unwrap();
+        let registers = config
+            .registers(Chip::LMX2594, Frequency::from_10khz(40960))
+            .unwrap();
+        let mut registers = registers.clone();
+
+        registers.write_field(lmx2594::OUTA_MUX, 0).unwrap();
+        registers.write_field(lmx2594::CHDIV, 1).unwrap();
+        registers.write_field(lmx2594::OUTA_PWR, 31).unwrap();
+        let description = describe_registers(&registers, Frequency::from_hz(122_880_000)).unwrap();
+
+        let outa = &description.outputs[0];
+        assert_eq!((outa.source, outa.divider), ("CHDIV", Some(4)));
+        assert_eq!(outa.frequency_hz, Some(description.vco_hz / 4.0));
+        assert_eq!(outa.power, "power 31");
+        assert!(description.to_string().contains("RFoutA: CHDIV / 4"));
+    }
+}
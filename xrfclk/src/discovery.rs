@@ -0,0 +1,284 @@
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::identity::{self, DeviceIdentity};
+use crate::system::SystemRoot;
+use crate::{runtime, spi_device_bind_in_blocking, write_sysfs_file, Chip};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// How long `bind_spidev` waits for udev to create the spidev node.
+pub const DEVICE_NODE_TIMEOUT: Duration = Duration::from_secs(2);
+pub const DEVICE_NODE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+fn cleanse_c_strings(s: &mut String) -> String {
+    s.retain(|c| !c.is_whitespace());
+    s.trim_matches(char::from(0)).to_string()
+}
+
+/// A clock chip found in sysfs, along with the driver binding it had when
+/// it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClockChipDescriptor {
+    pub chip: Chip,
+    /// SPI device name, e.g. `spi1.2`
+    pub name: String,
+    pub bus: u32,
+    pub chip_select: u32,
+    /// driver the chip was bound to, e.g. `xrfclk` or `spidev`
+    pub driver: Option<String>,
+    /// `driver_override` of the chip
+    pub driver_override: Option<String>,
+    /// bytes per SPI word from the device tree, only LMK nodes have it
+    pub num_bytes: Option<u32>,
+    pub identities: Vec<DeviceIdentity>,
+    /// the directory of the chip below `/sys/bus/spi/devices`
+    pub path: PathBuf,
+    root: SystemRoot,
+}
+
+impl ClockChipDescriptor {
+    /// The spidev node the chip is reachable through once bound to spidev.
+    pub fn device_path(&self) -> PathBuf {
+        self.root.device_path(&self.name)
+    }
+
+    /// The driver the chip is bound to right now.
+    pub fn current_driver(&self) -> Option<String> {
+        read_driver(&self.path)
+    }
+}
+
+fn read_driver(device_path: &Path) -> Option<String> {
+    fs::read_link(device_path.join("driver"))
+        .ok()?
+        .file_name()?
+        .to_str()
+        .map(str::to_string)
+}
+
+fn read_driver_override(device_path: &Path) -> Option<String> {
+    let driver_override = fs::read_to_string(device_path.join("driver_override")).ok()?;
+    let driver_override = driver_override.trim();
+
+    match driver_override {
+        "" | "(null)" => None,
+        _ => Some(driver_override.to_string()),
+    }
+}
+
+fn read_num_bytes(device_path: &Path) -> Option<u32> {
+    let mut bytes = [0u8; 4];
+    fs::File::open(device_path.join("of_node/num_bytes"))
+        .and_then(|mut file| file.read_exact(&mut bytes))
+        .ok()?;
+
+    Some(u32::from_be_bytes(bytes))
+}
+
+/// Lists every TI clock chip in sysfs. Nothing is written, the drivers of
+/// the chips stay as they are.
+pub async fn enumerate_clock_chips() -> Result<Vec<ClockChipDescriptor>, XRFClkError> {
+    runtime::blocking(enumerate_clock_chips_blocking).await
+}
+
+/// Like `enumerate_clock_chips` with sysfs below `root`.
+pub async fn enumerate_clock_chips_in(
+    root: &SystemRoot,
+) -> Result<Vec<ClockChipDescriptor>, XRFClkError> {
+    let root = root.clone();
+    runtime::blocking(move || enumerate_clock_chips_in_blocking(&root)).await
+}
+
+/// Moves the chip over to spidev and returns its device node once it exists.
+/// A chip that is bound to spidev already is left alone.
+pub async fn bind_spidev(descriptor: &ClockChipDescriptor) -> Result<PathBuf, XRFClkError> {
+    bind_spidev_with_timeout(descriptor, DEVICE_NODE_TIMEOUT).await
+}
+
+/// Like `bind_spidev` but waits up to `timeout` for the device node.
+pub async fn bind_spidev_with_timeout(
+    descriptor: &ClockChipDescriptor,
+    timeout: Duration,
+) -> Result<PathBuf, XRFClkError> {
+    let descriptor = descriptor.clone();
+    runtime::blocking(move || bind_spidev_with_timeout_blocking(&descriptor, timeout)).await
+}
+
+/// Polls until `device_path` exists. udev creates the spidev node some time
+/// after the bind, on first boot this can take a while.
+pub async fn wait_for_device_node(
+    device_path: &Path,
+    timeout: Duration,
+) -> Result<(), XRFClkError> {
+    let device_path = device_path.to_path_buf();
+    runtime::blocking(move || wait_for_device_node_blocking(&device_path, timeout)).await
+}
+
+/// Undoes `bind_spidev`: the chip gets the driver and `driver_override` back
+/// it had when it was enumerated.
+pub async fn restore_driver(descriptor: &ClockChipDescriptor) -> Result<(), XRFClkError> {
+    let descriptor = descriptor.clone();
+    runtime::blocking(move || restore_driver_blocking(&descriptor)).await
+}
+
+/// Like `enumerate_clock_chips`, blocks the calling thread.
+pub fn enumerate_clock_chips_blocking() -> Result<Vec<ClockChipDescriptor>, XRFClkError> {
+    enumerate_clock_chips_in_blocking(&SystemRoot::default())
+}
+
+/// Like `enumerate_clock_chips_in`, blocks the calling thread.
+pub fn enumerate_clock_chips_in_blocking(
+    root: &SystemRoot,
+) -> Result<Vec<ClockChipDescriptor>, XRFClkError> {
+    let spi_devices = root.spi_devices();
+    let io_error = |e| XRFClkError::io(e, &spi_devices);
+    let mut descriptors = Vec::new();
+
+    for file in fs::read_dir(&spi_devices).map_err(io_error)? {
+        let file = file.map_err(io_error)?;
+        let path = file.path();
+        let compatible_path = path.join("of_node/compatible");
+
+        debug!("processing spi device: {}", path.display());
+
+        let Some(name) = file.file_name().to_str().map(str::to_string) else {
+            debug!("spi device {} has an invalid name", path.display());
+            continue;
+        };
+
+        // the compatible string is of the form e.g. 'ti,lmx2594'
+        let compatible = match fs::read_to_string(&compatible_path) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("cannot read spi device {}: {e}", compatible_path.display());
+                continue;
+            }
+        };
+
+        let mut chip_string = match compatible.split_once(",") {
+            Some((_, value)) => value.to_string(),
+            None => {
+                debug!("cannot split spi device string for {name}");
+                continue;
+            }
+        };
+        chip_string = cleanse_c_strings(&mut chip_string);
+
+        let Ok(chip) = Chip::from_str(&chip_string) else {
+            debug!("spi device not having valid chip string: {chip_string}");
+            continue;
+        };
+
+        let Ok(DeviceIdentity::Spi { bus, chip_select }) = name.parse() else {
+            debug!("spi device {name} is not named spi<bus>.<cs>");
+            continue;
+        };
+
+        descriptors.push(ClockChipDescriptor {
+            chip,
+            bus,
+            chip_select,
+            driver: read_driver(&path),
+            driver_override: read_driver_override(&path),
+            num_bytes: read_num_bytes(&path),
+            identities: identity::sysfs_identities(&path, &name),
+            name,
+            path,
+            root: root.clone(),
+        });
+    }
+
+    descriptors.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(descriptors)
+}
+
+/// Like `bind_spidev`, blocks the calling thread.
+pub fn bind_spidev_blocking(descriptor: &ClockChipDescriptor) -> Result<PathBuf, XRFClkError> {
+    bind_spidev_with_timeout_blocking(descriptor, DEVICE_NODE_TIMEOUT)
+}
+
+/// Like `bind_spidev_with_timeout`, blocks the calling thread.
+pub fn bind_spidev_with_timeout_blocking(
+    descriptor: &ClockChipDescriptor,
+    timeout: Duration,
+) -> Result<PathBuf, XRFClkError> {
+    let current_driver = descriptor.current_driver();
+
+    if current_driver.as_deref() != Some("spidev") {
+        if current_driver.is_some() {
+            debug!("unbinding {} from {current_driver:?}", descriptor.name);
+            write_sysfs_file(&descriptor.path.join("driver/unbind"), &descriptor.name)
+                .map_err(|e| e.with_chip(descriptor.chip))?;
+        }
+
+        debug!("binding {} to spidev", descriptor.name);
+        spi_device_bind_in_blocking(&descriptor.root, &descriptor.path, &descriptor.name)
+            .map_err(|e| e.with_chip(descriptor.chip))?;
+    }
+
+    let device_path = descriptor.device_path();
+    wait_for_device_node_blocking(&device_path, timeout)
+        .map_err(|e| e.with_chip(descriptor.chip))?;
+
+    Ok(device_path)
+}
+
+/// Like `wait_for_device_node`, blocks the calling thread.
+pub fn wait_for_device_node_blocking(
+    device_path: &Path,
+    timeout: Duration,
+) -> Result<(), XRFClkError> {
+    let start = Instant::now();
+
+    while !device_path.exists() {
+        if start.elapsed() >= timeout {
+            return Err(XRFClkError::from(XRFClkErrorKind::DeviceTimeout).with_path(device_path));
+        }
+        std::thread::sleep(DEVICE_NODE_POLL_INTERVAL);
+    }
+
+    debug!(
+        "{} appeared after {:?}",
+        device_path.display(),
+        start.elapsed()
+    );
+    Ok(())
+}
+
+/// Like `restore_driver`, blocks the calling thread.
+pub fn restore_driver_blocking(descriptor: &ClockChipDescriptor) -> Result<(), XRFClkError> {
+    if descriptor.current_driver() == descriptor.driver {
+        return Ok(());
+    }
+
+    let with_chip = |e: XRFClkError| e.with_chip(descriptor.chip);
+
+    if descriptor.current_driver().is_some() {
+        write_sysfs_file(&descriptor.path.join("driver/unbind"), &descriptor.name)
+            .map_err(with_chip)?;
+    }
+
+    // a single newline clears the override
+    let driver_override = descriptor.driver_override.as_deref().unwrap_or("\n");
+    write_sysfs_file(&descriptor.path.join("driver_override"), driver_override)
+        .map_err(with_chip)?;
+
+    if let Some(driver) = &descriptor.driver {
+        debug!("binding {} back to {driver}", descriptor.name);
+        let driver_path = descriptor.root.spi_driver(driver);
+
+        // the module of the driver may have been unloaded in the meantime
+        if !driver_path.exists() {
+            return Err(XRFClkError::from(XRFClkErrorKind::DeviceNotFound)
+                .with_chip(descriptor.chip)
+                .with_path(&driver_path));
+        }
+
+        write_sysfs_file(&driver_path.join("bind"), &descriptor.name).map_err(with_chip)?;
+    }
+
+    Ok(())
+}
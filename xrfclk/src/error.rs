@@ -1,17 +1,40 @@
+use crate::Chip;
+use std::error::Error;
 use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
+/// Error of every fallible operation of this crate. Besides its kind it
+/// carries whatever context was known where it happened: the file, the chip,
+/// the frequency, the register and the error that caused it.
 #[derive(Debug, Clone)]
 pub struct XRFClkError {
     kind: XRFClkErrorKind,
+    path: Option<PathBuf>,
+    chip: Option<Chip>,
+    frequency: Option<u64>,
+    register: Option<u16>,
+    source: Option<Arc<dyn Error + Send + Sync>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum XRFClkErrorKind {
     UnknownError = 0,
     IOError = 1,
     InvalidFrequency = 2,
     InvalidChipString = 3,
     InvalidFilePath = 4,
+    InvalidRegister = 5,
+    ReadbackMismatch = 6,
+    LockTimeout = 7,
+    UnsupportedOperation = 8,
+    InvalidConfig = 9,
+    InvalidBoard = 10,
+    DeviceNotFound = 11,
+    MissingRegister = 12,
+    PermissionDenied = 13,
+    DeviceTimeout = 14,
+    ReferenceFailed = 15,
 }
 
 impl fmt::Display for XRFClkErrorKind {
@@ -22,6 +45,17 @@ impl fmt::Display for XRFClkErrorKind {
             Self::InvalidFrequency => "InvalidFrequency",
             Self::InvalidChipString => "InvalidChipString",
             Self::InvalidFilePath => "InvalidFilePath",
+            Self::InvalidRegister => "InvalidRegister",
+            Self::ReadbackMismatch => "ReadbackMismatch",
+            Self::LockTimeout => "LockTimeout",
+            Self::UnsupportedOperation => "UnsupportedOperation",
+            Self::InvalidConfig => "InvalidConfig",
+            Self::InvalidBoard => "InvalidBoard",
+            Self::DeviceNotFound => "DeviceNotFound",
+            Self::MissingRegister => "MissingRegister",
+            Self::PermissionDenied => "PermissionDenied",
+            Self::DeviceTimeout => "DeviceTimeout",
+            Self::ReferenceFailed => "ReferenceFailed",
         };
         write!(f, "{err_string}")
     }
@@ -29,18 +63,131 @@ impl fmt::Display for XRFClkErrorKind {
 
 impl fmt::Display for XRFClkError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "xrfclk error of kind: {} occurred", self.kind)
+        write!(f, "xrfclk error of kind: {} occurred", self.kind)?;
+
+        if let Some(chip) = self.chip {
+            write!(f, ", chip: {chip}")?;
+        }
+        if let Some(frequency) = self.frequency {
+            write!(f, ", frequency: {frequency}")?;
+        }
+        if let Some(register) = self.register {
+            write!(f, ", register: R{register}")?;
+        }
+        if let Some(path) = &self.path {
+            write!(f, ", path: {}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for XRFClkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| source.as_ref() as &(dyn Error + 'static))
     }
 }
 
 impl XRFClkError {
     pub fn from(kind: XRFClkErrorKind) -> Self {
-        Self { kind }
+        Self {
+            kind,
+            path: None,
+            chip: None,
+            frequency: None,
+            register: None,
+            source: None,
+        }
+    }
+
+    /// An io error that happened while accessing `path`.
+    pub fn io(error: std::io::Error, path: &Path) -> Self {
+        Self::from_io(error).with_path(path)
+    }
+
+    fn from_io(error: std::io::Error) -> Self {
+        let kind = match error.kind() {
+            std::io::ErrorKind::PermissionDenied => XRFClkErrorKind::PermissionDenied,
+            _ => XRFClkErrorKind::IOError,
+        };
+
+        Self::from(kind).with_source(error)
+    }
+
+    pub fn kind(&self) -> XRFClkErrorKind {
+        self.kind
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn chip(&self) -> Option<Chip> {
+        self.chip
+    }
+
+    /// The profile key in units of 10 kHz the operation was about.
+    pub fn frequency(&self) -> Option<u64> {
+        self.frequency
+    }
+
+    /// Address of the register the operation was about.
+    pub fn register(&self) -> Option<u16> {
+        self.register
+    }
+
+    pub fn with_path(mut self, path: &Path) -> Self {
+        self.path = Some(path.to_path_buf());
+        self
+    }
+
+    pub fn with_chip(mut self, chip: Chip) -> Self {
+        self.chip = Some(chip);
+        self
+    }
+
+    pub fn with_frequency(mut self, frequency: u64) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    pub fn with_register(mut self, register: u16) -> Self {
+        self.register = Some(register);
+        self
+    }
+
+    pub fn with_source(mut self, source: impl Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Arc::new(source));
+        self
     }
 }
 
 impl From<std::io::Error> for XRFClkError {
-    fn from(_: std::io::Error) -> XRFClkError {
-        Self::from(XRFClkErrorKind::IOError)
+    fn from(error: std::io::Error) -> XRFClkError {
+        Self::from_io(error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn io_errors_keep_their_cause() {
+        let error = XRFClkError::io(
+            std::io::Error::from(std::io::ErrorKind::PermissionDenied),
+            Path::new("/sys/bus/spi/drivers/spidev/bind"),
+        )
+        .with_chip(Chip::LMX2594);
+
+        assert_eq!(error.kind(), XRFClkErrorKind::PermissionDenied);
+        assert!(error.source().is_some());
+
+        let message = error.to_string();
+        assert!(message.contains("/sys/bus/spi/drivers/spidev/bind"));
+        assert!(message.contains("lmx2594"));
+        // the cause is left to `source()`, chain printers would repeat it
+        assert!(!message.contains(&error.source().unwrap().to_string()));
     }
 }
@@ -0,0 +1,354 @@
+use crate::config::RegisterSet;
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::schedule::Schedule;
+use crate::{find_devices, Chip, Config, LMKDevice, LMXDevice};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Names a single clock chip of the system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceIdentity {
+    /// SPI bus number and chip select, written as `spi1.2`
+    Spi { bus: u32, chip_select: u32 },
+    /// name of the device tree node, e.g. `lmx2594@2`
+    Node(String),
+}
+
+impl fmt::Display for DeviceIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Spi { bus, chip_select } => write!(f, "spi{bus}.{chip_select}"),
+            Self::Node(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl FromStr for DeviceIdentity {
+    type Err = XRFClkError;
+
+    /// `spi<bus>.<cs>` is an SPI identity, every other non-empty string a
+    /// device tree node name.
+    fn from_str(s: &str) -> Result<DeviceIdentity, Self::Err> {
+        let s = s.trim();
+
+        if let Some((bus, chip_select)) = s.strip_prefix("spi").and_then(|s| s.split_once('.')) {
+            if let (Ok(bus), Ok(chip_select)) = (bus.parse(), chip_select.parse()) {
+                return Ok(Self::Spi { bus, chip_select });
+            }
+        }
+
+        if s.is_empty() {
+            return Err(XRFClkError::from(XRFClkErrorKind::InvalidChipString));
+        }
+
+        Ok(Self::Node(s.to_string()))
+    }
+}
+
+/// The identities of the SPI device `spi_name` (e.g. `spi1.2`) whose sysfs
+/// directory is `device_path`.
+pub(crate) fn sysfs_identities(device_path: &Path, spi_name: &str) -> Vec<DeviceIdentity> {
+    let mut identities = Vec::with_capacity(2);
+
+    match spi_name.parse() {
+        Ok(identity @ DeviceIdentity::Spi { .. }) => identities.push(identity),
+        _ => debug!("spi device {spi_name} has an unexpected name"),
+    }
+
+    // of_node links to the node in /sys/firmware/devicetree
+    if let Ok(node) = fs::read_link(device_path.join("of_node")) {
+        if let Some(name) = node.file_name().and_then(|name| name.to_str()) {
+            identities.push(DeviceIdentity::Node(name.to_string()));
+        }
+    }
+
+    identities
+}
+
+/// What to program into a single device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeviceSetting {
+    /// profile key in units of 10 kHz
+    Frequency(u64),
+    Registers(RegisterSet),
+}
+
+/// Outcome of programming a single device.
+#[derive(Debug, Clone)]
+pub struct DeviceReport {
+    pub identity: DeviceIdentity,
+    /// `None` if no device has the identity
+    pub chip: Option<Chip>,
+    pub device: String,
+    pub result: Result<(), XRFClkError>,
+}
+
+impl DeviceReport {
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+impl fmt::Display for DeviceReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let chip = self
+            .chip
+            .map(|chip| chip.to_string())
+            .unwrap_or_else(|| "no device".to_string());
+
+        match &self.result {
+            Ok(()) => write!(f, "{} ({chip} on {}): ok", self.identity, self.device),
+            Err(e) => write!(f, "{} ({chip} on {}): {e}", self.identity, self.device),
+        }
+    }
+}
+
+/// The identities of `settings` that `device_identities` matches and the
+/// setting they agree on. `Err` holds the identities if they disagree.
+fn resolve<'a>(
+    device_identities: &[DeviceIdentity],
+    settings: &'a HashMap<DeviceIdentity, DeviceSetting>,
+) -> Result<Option<(Vec<DeviceIdentity>, &'a DeviceSetting)>, Vec<DeviceIdentity>> {
+    let mut matches: Vec<(&DeviceIdentity, &DeviceSetting)> = settings
+        .iter()
+        .filter(|(identity, _)| device_identities.contains(identity))
+        .collect();
+    matches.sort_by_key(|(identity, _)| identity.to_string());
+
+    let identities = matches
+        .iter()
+        .map(|(identity, _)| (*identity).clone())
+        .collect();
+    match matches.first() {
+        None => Ok(None),
+        Some((_, setting)) if matches.iter().all(|(_, other)| other == setting) => {
+            Ok(Some((identities, *setting)))
+        }
+        Some(_) => Err(identities),
+    }
+}
+
+/// Programs every device that matches an identity of `settings` through a
+/// `Schedule`, so the LMKs come first as they provide the reference of the
+/// LMXs. Devices without a setting are left alone. A failing device does
+/// not stop the others of its stage, every identity gets a report.
+///
+/// A device that several identities name, e.g. `spi1.2` and its node
+/// name, is programmed once. If they ask for different settings it is not
+/// programmed at all and each of them reports `InvalidConfig`.
+pub async fn program_devices(
+    lmk_devices: &[LMKDevice],
+    lmx_devices: &[LMXDevice],
+    settings: &HashMap<DeviceIdentity, DeviceSetting>,
+) -> Vec<DeviceReport> {
+    let mut schedule = Schedule::new();
+    // the identities behind each job, in the order the schedule reports them
+    let mut jobs = Vec::with_capacity(settings.len());
+    let mut reports = Vec::with_capacity(settings.len());
+
+    let conflict = |identities: Vec<DeviceIdentity>, chip: Chip, device: String| {
+        debug!("{identities:?} ask for different settings of the {chip} on {device}");
+        identities
+            .into_iter()
+            .map(|identity| DeviceReport {
+                identity,
+                chip: Some(chip),
+                device: device.clone(),
+                result: Err(XRFClkError::from(XRFClkErrorKind::InvalidConfig).with_chip(chip)),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    for lmk_device in lmk_devices {
+        match resolve(lmk_device.identities(), settings) {
+            Ok(Some((identities, setting))) => {
+                schedule = schedule.with_lmk(lmk_device, setting.clone());
+                jobs.push(identities);
+            }
+            Ok(None) => (),
+            Err(identities) => reports.extend(conflict(
+                identities,
+                lmk_device.chip(),
+                lmk_device.location(),
+            )),
+        }
+    }
+    for lmx_device in lmx_devices {
+        match resolve(lmx_device.identities(), settings) {
+            Ok(Some((identities, setting))) => {
+                schedule = schedule.with_lmx(lmx_device, setting.clone());
+                jobs.push(identities);
+            }
+            Ok(None) => (),
+            Err(identities) => reports.extend(conflict(
+                identities,
+                lmx_device.chip(),
+                lmx_device.location(),
+            )),
+        }
+    }
+
+    match schedule.run().await {
+        Ok(program_reports) => {
+            for (report, identities) in program_reports.into_iter().zip(jobs) {
+                reports.extend(identities.into_iter().map(|identity| DeviceReport {
+                    identity,
+                    chip: Some(report.chip),
+                    device: report.device.clone(),
+                    result: report.result.clone(),
+                }));
+            }
+        }
+        Err(e) => reports.extend(jobs.into_iter().flatten().map(|identity| DeviceReport {
+            identity,
+            chip: None,
+            device: String::new(),
+            result: Err(e.clone()),
+        })),
+    }
+
+    for identity in settings.keys() {
+        if !reports.iter().any(|report| report.identity == *identity) {
+            let report = DeviceReport {
+                identity: identity.clone(),
+                chip: None,
+                device: String::new(),
+                result: Err(XRFClkError::from(XRFClkErrorKind::DeviceNotFound)),
+            };
+            warn!("{report}");
+            reports.push(report);
+        }
+    }
+
+    reports
+}
+
+/// Like `set_ref_clks`, but with a frequency or register set per device.
+/// Only fails if the devices cannot be found, the outcome of every single
+/// device is in its report.
+pub async fn set_device_clks(
+    config: Arc<Config>,
+    settings: &HashMap<DeviceIdentity, DeviceSetting>,
+) -> Result<Vec<DeviceReport>, XRFClkError> {
+    let (lmk_devices, lmx_devices) = find_devices(config).await?;
+
+    Ok(program_devices(&lmk_devices, &lmx_devices, settings).await)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::transport::RecordingTransport;
+    use crate::{load_config_from_file, Frequency};
+
+    #[test]
+    fn parses_identities() {
+        assert_eq!(
+            "spi1.2".parse::<DeviceIdentity>().unwrap(),
+            DeviceIdentity::Spi {
+                bus: 1,
+                chip_select: 2
+            }
+        );
+        assert_eq!(
+            "lmx2594@1".parse::<DeviceIdentity>().unwrap(),
+            DeviceIdentity::Node("lmx2594@1".to_string())
+        );
+        assert!("".parse::<DeviceIdentity>().is_err());
+    }
+
+    #[tokio::test]
+    async fn programs_every_device_on_its_own() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let adc = RecordingTransport::new();
+        let dac = RecordingTransport::new();
+        let lmx_devices = [
+            LMXDevice::from_transport(Chip::LMX2594, Box::new(adc.clone()), config.clone())
+                .with_identity("spi1.1".parse().unwrap()),
+            LMXDevice::from_transport(Chip::LMX2594, Box::new(dac.clone()), config.clone())
+                .with_identity("lmx2594@2".parse().unwrap()),
+        ];
+
+        let settings = HashMap::from([
+            ("spi1.1".parse().unwrap(), DeviceSetting::Frequency(40960)),
+            (
+                "lmx2594@2".parse().unwrap(),
+                DeviceSetting::Frequency(12345),
+            ),
+            ("spi1.3".parse().unwrap(), DeviceSetting::Frequency(10240)),
+        ]);
+
+        let reports = program_devices(&[], &lmx_devices, &settings).await;
+
+        assert_eq!(reports.len(), 3);
+        assert!(!adc.words().is_empty());
+        assert!(dac.words().is_empty());
+
+        let failed: Vec<XRFClkErrorKind> = reports
+            .iter()
+            .filter_map(|report| report.result.as_ref().err().map(|e| e.kind()))
+            .collect();
+        assert!(failed.contains(&XRFClkErrorKind::InvalidFrequency));
+        assert!(failed.contains(&XRFClkErrorKind::DeviceNotFound));
+    }
+
+    #[tokio::test]
+    async fn aliases_program_a_device_once() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let transport = RecordingTransport::new();
+        let lmx_devices =
+            [
+                LMXDevice::from_transport(
+                    Chip::LMX2594,
+                    Box::new(transport.clone()),
+                    config.clone(),
+                )
+                .with_identity("spi1.2".parse().unwrap())
+                .with_identity("lmx2594@2".parse().unwrap()),
+            ];
+
+        let settings = HashMap::from([
+            ("spi1.2".parse().unwrap(), DeviceSetting::Frequency(40960)),
+            (
+                "lmx2594@2".parse().unwrap(),
+                DeviceSetting::Frequency(40960),
+            ),
+        ]);
+        let reports = program_devices(&[], &lmx_devices, &settings).await;
+
+        assert_eq!(reports.len(), 2);
+        assert!(reports.iter().all(DeviceReport::is_ok));
+        let registers = config
+            .registers(Chip::LMX2594, Frequency::from_10khz(40960))
+            .unwrap();
+        let r112 = registers.get(112).unwrap();
+        let writes = transport
+            .words()
+            .iter()
+            .filter(|word| **word == r112)
+            .count();
+        assert_eq!(writes, 1);
+
+        // different settings for the same device program nothing
+        transport.clear();
+        let settings = HashMap::from([
+            ("spi1.2".parse().unwrap(), DeviceSetting::Frequency(40960)),
+            (
+                "lmx2594@2".parse().unwrap(),
+                DeviceSetting::Frequency(10240),
+            ),
+        ]);
+        let reports = program_devices(&[], &lmx_devices, &settings).await;
+
+        assert_eq!(reports.len(), 2);
+        assert!(reports.iter().all(|report| {
+            report.result.as_ref().unwrap_err().kind() == XRFClkErrorKind::InvalidConfig
+        }));
+        assert!(transport.words().is_empty());
+    }
+}
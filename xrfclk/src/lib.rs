@@ -1,29 +1,79 @@
+pub mod board;
+pub mod bridge;
+pub mod config;
+pub mod describe;
+pub mod discovery;
 pub mod error;
+pub mod identity;
+pub mod lock;
+pub mod planner;
+pub mod readback;
+pub mod regmap;
+mod runtime;
+pub mod schedule;
+pub mod sequence;
+pub mod simulator;
+pub mod system;
+pub mod tics;
+pub mod transport;
+pub mod verify;
 
 use serde::{de, Deserialize, Deserializer};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs;
-use std::io::{Read, Write};
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tracing::{debug, warn};
 
+pub use crate::board::{set_board_clks, Board, BoardProfile};
+pub use crate::config::{
+    load_config_from_file, load_config_from_path, load_config_with_overrides, parse_config_json,
+    parse_config_toml, ClockProfile, Config, Frequency, RegisterSet,
+};
+pub use crate::describe::{describe, ClockDescription};
+pub use crate::discovery::{
+    bind_spidev, enumerate_clock_chips, restore_driver, ClockChipDescriptor,
+};
+pub use crate::identity::{set_device_clks, DeviceIdentity, DeviceSetting};
+use crate::lock::{LmkLockState, Lmx2594LockState, LockReport};
+use crate::readback::{Lmx2594Muxout, RegisterDump};
+pub use crate::schedule::Schedule;
+use crate::sequence::ProgrammingSequence;
+pub use crate::system::SystemRoot;
+use crate::transport::{SpiTransport, SpidevTransport};
+use crate::verify::VerifyReport;
+
+/// An LMK clock chip. Clones share the transport, the async methods run on
+/// a clone so the SPI access can be handed to the blocking pool, see the
+/// `tokio` feature. Every async method has a `_blocking` twin for callers
+/// without an executor.
+#[derive(Clone)]
 pub struct LMKDevice {
-    unix_spi_device_string: PathBuf,
+    transport: Arc<Mutex<Box<dyn SpiTransport>>>,
     chip_name: Chip,
     number_of_bytes: u32,
     config: Arc<Config>,
+    identities: Vec<DeviceIdentity>,
 }
 
+/// An LMX clock chip, see `LMKDevice` for clones and the `_blocking`
+/// methods.
+#[derive(Clone)]
 pub struct LMXDevice {
-    unix_spi_device_string: PathBuf,
+    transport: Arc<Mutex<Box<dyn SpiTransport>>>,
     chip_name: Chip,
     config: Arc<Config>,
+    identities: Vec<DeviceIdentity>,
+    /// R0 as the chip holds it, needed to toggle MUXOUT_LD_SEL for
+    /// readback. `None` until this device programmed or read it.
+    r0: Arc<Mutex<Option<u32>>>,
 }
 
-#[derive(Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
 pub enum Chip {
     LMX2594 = 0,
     LMK04832 = 1,
@@ -72,38 +122,97 @@ impl<'de> Deserialize<'de> for Chip {
     }
 }
 
-fn cleanse_c_strings(s: &mut String) -> String {
-    s.retain(|c| !c.is_whitespace());
-    s.trim_matches(char::from(0)).to_string()
+pub fn generate_device_path(device_name: String) -> PathBuf {
+    SystemRoot::default().device_path(&device_name)
 }
 
-type RawConfig = HashMap<Chip, HashMap<u64, HashMap<String, String>>>;
-type Config = HashMap<Chip, HashMap<u64, HashMap<String, u32>>>;
+/// `frequency` is the profile key in units of 10 kHz.
+fn lookup_register_values(
+    config: &Config,
+    chip: Chip,
+    frequency: u64,
+) -> Result<&RegisterSet, error::XRFClkError> {
+    config
+        .registers(chip, Frequency::from_10khz(frequency))
+        .ok_or_else(|| {
+            error::XRFClkError::from(error::XRFClkErrorKind::InvalidFrequency)
+                .with_chip(chip)
+                .with_frequency(frequency)
+        })
+}
 
-pub fn load_config_from_file() -> Config {
-    let included_string = include_str!("config.json");
+/// Locks state shared between device clones, a poisoned lock is an
+/// `UnknownError` rather than a panic.
+fn lock_shared<T: ?Sized>(
+    shared: &Mutex<T>,
+) -> Result<std::sync::MutexGuard<'_, T>, error::XRFClkError> {
+    shared
+        .lock()
+        .map_err(|_| error::XRFClkError::from(error::XRFClkErrorKind::UnknownError))
+}
 
-    let raw_config: RawConfig =
-        serde_json::from_str(included_string).expect("wrong json at compile time");
-    let mut config: Config = Config::new();
+/// Reads back the verifiable registers of `register_values` with
+/// `read_words` and compares them. LMK and LMX devices only differ in how
+/// registers are read.
+fn verify_registers_on(
+    chip: Chip,
+    transport: &mut dyn SpiTransport,
+    register_values: &RegisterSet,
+    read_words: impl FnOnce(
+        &mut dyn SpiTransport,
+        &[u16],
+    ) -> Result<BTreeMap<u16, u32>, error::XRFClkError>,
+) -> Result<VerifyReport, error::XRFClkError> {
+    let addresses = verify::verifiable_registers(register_values);
+    let registers = read_words(transport, &addresses)?;
+
+    let report = verify::verify(register_values, &RegisterDump::from(chip, registers));
+
+    for mismatch in &report.mismatches {
+        warn!(
+            "chip {} on {} did not take register value {}",
+            chip,
+            transport.describe(),
+            mismatch
+        );
+    }
 
-    for (chip, chip_values) in raw_config {
-        let chip_entry: &mut HashMap<u64, HashMap<String, u32>> = config.entry(chip).or_default();
-        for (freq, register_values) in chip_values {
-            let freq_entry = chip_entry.entry(freq).or_default();
-            for (reg, mut value) in register_values {
-                value.remove(0);
-                value.remove(0);
-                freq_entry.insert(reg, u32::from_str_radix(&value, 16).unwrap());
-            }
+    Ok(report)
+}
+
+/// Polls `is_locked` until it reports lock or `timeout` has passed.
+fn wait_for_lock_of(
+    chip: Chip,
+    timeout: Duration,
+    mut is_locked: impl FnMut() -> Result<bool, error::XRFClkError>,
+) -> Result<(), error::XRFClkError> {
+    let start = Instant::now();
+
+    while !is_locked()? {
+        if start.elapsed() >= timeout {
+            return Err(
+                error::XRFClkError::from(error::XRFClkErrorKind::LockTimeout).with_chip(chip),
+            );
         }
+        std::thread::sleep(lock::LOCK_POLL_INTERVAL);
     }
 
-    config
+    Ok(())
 }
 
-pub fn generate_device_path(device_name: String) -> PathBuf {
-    PathBuf::from(format!("/dev/{}", device_name.replace("spi", "spidev")))
+/// Programs the profile for `frequency` with `set_clks` and reads it back
+/// with `verify_registers`.
+fn set_clks_verified_with(
+    config: &Config,
+    chip: Chip,
+    frequency: u64,
+    set_clks: impl FnOnce(u64) -> Result<(), error::XRFClkError>,
+    verify_registers: impl FnOnce(&RegisterSet) -> Result<VerifyReport, error::XRFClkError>,
+) -> Result<VerifyReport, error::XRFClkError> {
+    set_clks(frequency)?;
+
+    let values = lookup_register_values(config, chip, frequency)?;
+    verify_registers(values)
 }
 
 impl LMKDevice {
@@ -112,139 +221,702 @@ impl LMKDevice {
         unix_spi_device_string: PathBuf,
         number_of_bytes: u32,
         config: Arc<Config>,
+    ) -> Self {
+        Self::from_transport(
+            chip_name,
+            Box::new(SpidevTransport::from(unix_spi_device_string)),
+            number_of_bytes,
+            config,
+        )
+    }
+
+    pub fn from_transport(
+        chip_name: Chip,
+        transport: Box<dyn SpiTransport>,
+        number_of_bytes: u32,
+        config: Arc<Config>,
     ) -> Self {
         Self {
-            unix_spi_device_string,
+            transport: Arc::new(Mutex::new(transport)),
             chip_name,
             number_of_bytes,
             config,
+            identities: Vec::new(),
         }
     }
 
-    pub async fn write_registers(
+    /// Adds a name the device can be selected by, see `identity`.
+    pub fn with_identity(mut self, identity: DeviceIdentity) -> Self {
+        self.identities.push(identity);
+        self
+    }
+
+    pub fn identities(&self) -> &[DeviceIdentity] {
+        &self.identities
+    }
+
+    pub fn chip(&self) -> Chip {
+        self.chip_name
+    }
+
+    /// Where the device lives, e.g. its spidev node.
+    pub fn location(&self) -> String {
+        self.transport
+            .lock()
+            .map(|transport| transport.describe())
+            .unwrap_or_default()
+    }
+
+    /// Whether `other` is this device or a clone of it.
+    pub(crate) fn is_same_device(&self, other: &LMKDevice) -> bool {
+        Arc::ptr_eq(&self.transport, &other.transport)
+    }
+
+    pub fn write_registers_blocking(
         &self,
-        register_values: &HashMap<String, u32>,
+        register_values: &RegisterSet,
     ) -> Result<(), error::XRFClkError> {
+        if register_values.chip() != self.chip_name {
+            return Err(
+                error::XRFClkError::from(error::XRFClkErrorKind::InvalidConfig)
+                    .with_chip(register_values.chip()),
+            );
+        }
+
+        let mut transport = lock_shared(&self.transport)?;
+
         debug!(
             "writing {} register values of chip {} to {}",
             register_values.len(),
             &self.chip_name,
-            &self.unix_spi_device_string.display()
+            transport.describe()
         );
 
-        let mut file_handle = fs::OpenOptions::new()
-            .write(true)
-            .create(false)
-            .open(&self.unix_spi_device_string)?;
+        let sequence = ProgrammingSequence::new(register_values)?;
+
+        for value in sequence.words() {
+            transport.write_word(value, self.number_of_bytes as usize)?;
+            transport.flush()?;
+        }
 
-        for value in register_values.values() {
-            // makes sure to save the number in big endian
-            let bytes: [u8; 4] = value.to_be_bytes();
+        Ok(())
+    }
 
-            if self.number_of_bytes == 3 {
-                file_handle.write_all(&bytes[1..4])?;
+    fn read_words(
+        &self,
+        transport: &mut dyn SpiTransport,
+        addresses: &[u16],
+    ) -> Result<BTreeMap<u16, u32>, error::XRFClkError> {
+        let width = self.number_of_bytes as usize;
+        let mut registers = BTreeMap::new();
+
+        for address in addresses {
+            let request = readback::read_request(self.chip_name, *address);
+
+            let received = if self.chip_name == Chip::LMK04208 {
+                // the first write selects the register in R31, the content
+                // is shifted out during the following access
+                transport.write_word(request, width)?;
+                transport.flush()?;
+                transport.transfer_word(request, width)?
             } else {
-                file_handle.write_all(&bytes)?;
-            }
-            file_handle.flush()?;
+                transport.transfer_word(request, width)?
+            };
+
+            registers.insert(
+                *address,
+                readback::read_response(self.chip_name, *address, received),
+            );
         }
 
-        Ok(())
+        Ok(registers)
     }
 
-    pub async fn set_clks(&self, frequency: u64) -> Result<(), error::XRFClkError> {
+    pub fn read_register_blocking(&self, address: u16) -> Result<u32, error::XRFClkError> {
+        let mut transport = lock_shared(&self.transport)?;
+        let registers = self.read_words(transport.as_mut(), &[address])?;
+
+        Ok(registers[&address])
+    }
+
+    pub fn read_all_registers_blocking(&self) -> Result<RegisterDump, error::XRFClkError> {
+        let mut transport = lock_shared(&self.transport)?;
+
+        debug!(
+            "reading back all registers of chip {} from {}",
+            &self.chip_name,
+            transport.describe()
+        );
+
+        let addresses = readback::readable_registers(self.chip_name);
+        let registers = self.read_words(transport.as_mut(), &addresses)?;
+
+        Ok(RegisterDump::from(self.chip_name, registers))
+    }
+
+    pub fn lock_state_blocking(&self) -> Result<LmkLockState, error::XRFClkError> {
+        if self.chip_name == Chip::LMK04208 {
+            // the LMK04208 only signals lock on its LD pin
+            return Err(error::XRFClkError::from(
+                error::XRFClkErrorKind::UnsupportedOperation,
+            ));
+        }
+
+        let mut transport = lock_shared(&self.transport)?;
+        let registers = self.read_words(
+            transport.as_mut(),
+            &[
+                lock::LMK0482X_PLL1_LD_REGISTER,
+                lock::LMK0482X_PLL2_LD_REGISTER,
+            ],
+        )?;
+
+        Ok(LmkLockState::from_registers(
+            registers[&lock::LMK0482X_PLL1_LD_REGISTER],
+            registers[&lock::LMK0482X_PLL2_LD_REGISTER],
+        ))
+    }
+
+    /// Like `is_locked`, blocks the calling thread.
+    pub fn is_locked_blocking(&self) -> Result<bool, error::XRFClkError> {
+        Ok(self.lock_state_blocking()?.is_locked())
+    }
+
+    /// Like `wait_for_lock`, blocks the calling thread.
+    pub fn wait_for_lock_blocking(&self, timeout: Duration) -> Result<(), error::XRFClkError> {
+        wait_for_lock_of(self.chip_name, timeout, || self.is_locked_blocking())
+    }
+
+    pub fn set_clks_blocking(&self, frequency: u64) -> Result<(), error::XRFClkError> {
         debug!(
             "setting clocks of chip {} to frequency: {}",
             &self.chip_name, &frequency
         );
 
-        let frequency_map = self.config.get(&self.chip_name).unwrap();
+        let values = lookup_register_values(&self.config, self.chip_name, frequency)?;
+        self.write_registers_blocking(values)
+            .map_err(|e| e.with_chip(self.chip_name).with_frequency(frequency))
+    }
+
+    /// Like `verify_registers`, blocks the calling thread.
+    pub fn verify_registers_blocking(
+        &self,
+        register_values: &RegisterSet,
+    ) -> Result<VerifyReport, error::XRFClkError> {
+        let mut transport = lock_shared(&self.transport)?;
+        verify_registers_on(
+            self.chip_name,
+            transport.as_mut(),
+            register_values,
+            |transport, addresses| self.read_words(transport, addresses),
+        )
+    }
 
-        if let Some(values) = frequency_map.get(&frequency) {
-            self.write_registers(&values).await
-        } else {
-            Err(error::XRFClkError::from(
-                error::XRFClkErrorKind::InvalidFrequency,
-            ))
-        }
+    /// Like `set_clks_verified`, blocks the calling thread.
+    pub fn set_clks_verified_blocking(
+        &self,
+        frequency: u64,
+    ) -> Result<VerifyReport, error::XRFClkError> {
+        set_clks_verified_with(
+            &self.config,
+            self.chip_name,
+            frequency,
+            |frequency| self.set_clks_blocking(frequency),
+            |values| self.verify_registers_blocking(values),
+        )
+    }
+
+    pub async fn write_registers(
+        &self,
+        register_values: &RegisterSet,
+    ) -> Result<(), error::XRFClkError> {
+        let device = self.clone();
+        let register_values = register_values.clone();
+        runtime::blocking(move || device.write_registers_blocking(&register_values)).await
+    }
+
+    pub async fn read_register(&self, address: u16) -> Result<u32, error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.read_register_blocking(address)).await
+    }
+
+    pub async fn read_all_registers(&self) -> Result<RegisterDump, error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.read_all_registers_blocking()).await
+    }
+
+    pub async fn lock_state(&self) -> Result<LmkLockState, error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.lock_state_blocking()).await
+    }
+
+    /// True if PLL1 and PLL2 report lock.
+    pub async fn is_locked(&self) -> Result<bool, error::XRFClkError> {
+        Ok(self.lock_state().await?.is_locked())
+    }
+
+    /// Polls the lock detect status until both PLLs are locked or `timeout`
+    /// has passed.
+    pub async fn wait_for_lock(&self, timeout: Duration) -> Result<(), error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.wait_for_lock_blocking(timeout)).await
+    }
+
+    pub async fn set_clks(&self, frequency: u64) -> Result<(), error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.set_clks_blocking(frequency)).await
+    }
+
+    /// Reads back every register of `register_values` that is not read-only
+    /// or self-clearing and compares it with the expected value.
+    pub async fn verify_registers(
+        &self,
+        register_values: &RegisterSet,
+    ) -> Result<VerifyReport, error::XRFClkError> {
+        let device = self.clone();
+        let register_values = register_values.clone();
+        runtime::blocking(move || device.verify_registers_blocking(&register_values)).await
+    }
+
+    /// Like `set_clks` but reads the registers back afterwards. A report
+    /// with mismatches can be turned into an error with
+    /// `VerifyReport::into_result`.
+    pub async fn set_clks_verified(
+        &self,
+        frequency: u64,
+    ) -> Result<VerifyReport, error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.set_clks_verified_blocking(frequency)).await
     }
 }
 
 impl LMXDevice {
     pub fn from(chip_name: Chip, unix_spi_device_string: PathBuf, config: Arc<Config>) -> Self {
+        Self::from_transport(
+            chip_name,
+            Box::new(SpidevTransport::from(unix_spi_device_string)),
+            config,
+        )
+    }
+
+    pub fn from_transport(
+        chip_name: Chip,
+        transport: Box<dyn SpiTransport>,
+        config: Arc<Config>,
+    ) -> Self {
         Self {
-            unix_spi_device_string,
+            transport: Arc::new(Mutex::new(transport)),
             chip_name,
             config,
+            identities: Vec::new(),
+            r0: Arc::new(Mutex::new(None)),
         }
     }
 
-    pub async fn write_registers(
+    /// Adds a name the device can be selected by, see `identity`.
+    pub fn with_identity(mut self, identity: DeviceIdentity) -> Self {
+        self.identities.push(identity);
+        self
+    }
+
+    pub fn identities(&self) -> &[DeviceIdentity] {
+        &self.identities
+    }
+
+    pub fn chip(&self) -> Chip {
+        self.chip_name
+    }
+
+    /// Where the device lives, e.g. its spidev node.
+    pub fn location(&self) -> String {
+        self.transport
+            .lock()
+            .map(|transport| transport.describe())
+            .unwrap_or_default()
+    }
+
+    pub fn write_registers_blocking(
         &self,
-        register_values: &HashMap<String, u32>,
+        register_values: &RegisterSet,
     ) -> Result<(), error::XRFClkError> {
+        if register_values.chip() != self.chip_name {
+            return Err(
+                error::XRFClkError::from(error::XRFClkErrorKind::InvalidConfig)
+                    .with_chip(register_values.chip()),
+            );
+        }
+
+        let mut transport = lock_shared(&self.transport)?;
+
         debug!(
             "writing {} register values of chip {} to {}",
             register_values.len(),
             &self.chip_name,
-            &self.unix_spi_device_string.display()
+            transport.describe()
         );
 
-        let mut file_handle = fs::OpenOptions::new()
-            .write(true)
-            .create(false)
-            .open(&self.unix_spi_device_string)?;
-
-        // Program RESET = 1 to reset registers
-        let reset = 0x020000_u32.to_be_bytes();
-        file_handle.write_all(&reset[1..])?;
-        file_handle.flush()?;
-
-        // Program RESET = 0 to remove reset
-        let remove_reset = 0x0_u32.to_be_bytes();
-        file_handle.write_all(&remove_reset[1..])?;
-        file_handle.flush()?;
-
-        for value in register_values.values() {
-            let bytes = &value.to_be_bytes();
-            file_handle.write_all(&bytes[1..])?;
-            file_handle.flush()?;
-        }
+        let sequence = ProgrammingSequence::new(register_values)?;
 
-        // Program register R0 one additional time with FCAL_EN = 1
-        // to ensure that the VCO calibration runs from a stable state.
-        let stable = register_values
-            .get("R112")
-            .expect("Register 112 not specified for this device")
-            .to_be_bytes();
+        // reset, R112 down to R0 and the final FCAL_EN write of R0
+        for value in sequence.words() {
+            transport.write_word(value, 3)?;
+            transport.flush()?;
+        }
 
-        file_handle.write_all(&stable[1..])?;
-        file_handle.flush()?;
+        *lock_shared(&self.r0)? = register_values
+            .get(0)
+            .map(|r0| r0 & !readback::LMX2594_FCAL_EN);
 
         Ok(())
     }
 
-    pub async fn set_clks(&self, frequency: u64) -> Result<(), error::XRFClkError> {
+    /// What MUXOUT carries. Without a known R0 it is read from the chip,
+    /// which only tells its value if MUXOUT is in readback mode already.
+    fn muxout(
+        &self,
+        transport: &mut dyn SpiTransport,
+    ) -> Result<Lmx2594Muxout, error::XRFClkError> {
+        if let Some(r0) = *lock_shared(&self.r0)? {
+            return Ok(Lmx2594Muxout::Readback(r0));
+        }
+
+        let received = transport.transfer_word(readback::read_request(self.chip_name, 0), 3)?;
+        let muxout = Lmx2594Muxout::from_r0_read(received);
+        if let Lmx2594Muxout::Readback(r0) = muxout {
+            *lock_shared(&self.r0)? = Some(r0);
+        }
+
+        Ok(muxout)
+    }
+
+    /// Switches MUXOUT into readback mode, reads the registers and restores
+    /// R0. Only MUXOUT_LD_SEL is toggled, R0 is rewritten with FCAL_EN = 0
+    /// so the readback does not trigger a new VCO calibration.
+    ///
+    /// Fails with `UnsupportedOperation` if R0 is unknown, i.e. the chip was
+    /// programmed by someone else, and MUXOUT carries lock detect: R0 cannot
+    /// be read then and writing a guess would clobber the programmed one.
+    fn read_words(
+        &self,
+        transport: &mut dyn SpiTransport,
+        addresses: &[u16],
+    ) -> Result<BTreeMap<u16, u32>, error::XRFClkError> {
+        let r0 = match self.muxout(transport)? {
+            Lmx2594Muxout::Readback(r0) => r0 & !readback::LMX2594_FCAL_EN,
+            Lmx2594Muxout::LockDetect(_) => {
+                warn!(
+                    "R0 of chip {} on {} is unknown and MUXOUT carries lock detect, \
+                     registers can only be read back after programming the chip",
+                    &self.chip_name,
+                    transport.describe()
+                );
+                return Err(
+                    error::XRFClkError::from(error::XRFClkErrorKind::UnsupportedOperation)
+                        .with_chip(self.chip_name),
+                );
+            }
+        };
+        let readback_r0 = r0 & !readback::LMX2594_MUXOUT_LD_SEL;
+        let mut registers = BTreeMap::new();
+
+        if readback_r0 != r0 {
+            transport.write_word(readback_r0, 3)?;
+            transport.flush()?;
+        }
+
+        for address in addresses {
+            let received =
+                transport.transfer_word(readback::read_request(self.chip_name, *address), 3)?;
+
+            registers.insert(
+                *address,
+                readback::read_response(self.chip_name, *address, received),
+            );
+        }
+
+        if readback_r0 != r0 {
+            transport.write_word(r0, 3)?;
+            transport.flush()?;
+        }
+
+        Ok(registers)
+    }
+
+    pub fn read_register_blocking(&self, address: u16) -> Result<u32, error::XRFClkError> {
+        let mut transport = lock_shared(&self.transport)?;
+        let registers = self.read_words(transport.as_mut(), &[address])?;
+
+        Ok(registers[&address])
+    }
+
+    pub fn read_all_registers_blocking(&self) -> Result<RegisterDump, error::XRFClkError> {
+        let mut transport = lock_shared(&self.transport)?;
+
+        debug!(
+            "reading back all registers of chip {} from {}",
+            &self.chip_name,
+            transport.describe()
+        );
+
+        let addresses = readback::readable_registers(self.chip_name);
+        let registers = self.read_words(transport.as_mut(), &addresses)?;
+
+        Ok(RegisterDump::from(self.chip_name, registers))
+    }
+
+    /// rb_LD_VTUNE out of R110. If R0 is unknown and MUXOUT carries lock
+    /// detect, its level is reported instead so R0 is not touched.
+    pub fn lock_state_blocking(&self) -> Result<Lmx2594LockState, error::XRFClkError> {
+        let mut transport = lock_shared(&self.transport)?;
+
+        match self.muxout(transport.as_mut())? {
+            Lmx2594Muxout::LockDetect(true) => Ok(Lmx2594LockState::Locked),
+            Lmx2594Muxout::LockDetect(false) => Ok(Lmx2594LockState::UnlockedVtuneLow),
+            Lmx2594Muxout::Readback(_) => {
+                let registers =
+                    self.read_words(transport.as_mut(), &[lock::LMX2594_LD_REGISTER])?;
+                Ok(Lmx2594LockState::from_r110(
+                    registers[&lock::LMX2594_LD_REGISTER],
+                ))
+            }
+        }
+    }
+
+    /// Like `is_locked`, blocks the calling thread.
+    pub fn is_locked_blocking(&self) -> Result<bool, error::XRFClkError> {
+        Ok(self.lock_state_blocking()?.is_locked())
+    }
+
+    /// Like `wait_for_lock`, blocks the calling thread.
+    pub fn wait_for_lock_blocking(&self, timeout: Duration) -> Result<(), error::XRFClkError> {
+        wait_for_lock_of(self.chip_name, timeout, || self.is_locked_blocking())
+    }
+
+    pub fn set_clks_blocking(&self, frequency: u64) -> Result<(), error::XRFClkError> {
         debug!(
             "setting clocks of chip {} to frequency {}",
             &self.chip_name, &frequency
         );
 
-        let frequency_map = self.config.get(&self.chip_name).unwrap();
+        let values = lookup_register_values(&self.config, self.chip_name, frequency)?;
+        self.write_registers_blocking(values)
+            .map_err(|e| e.with_chip(self.chip_name).with_frequency(frequency))
+    }
 
-        if let Some(values) = frequency_map.get(&frequency) {
-            self.write_registers(&values).await
-        } else {
-            Err(error::XRFClkError::from(
-                error::XRFClkErrorKind::InvalidFrequency,
-            ))
-        }
+    /// Like `verify_registers`, blocks the calling thread.
+    pub fn verify_registers_blocking(
+        &self,
+        register_values: &RegisterSet,
+    ) -> Result<VerifyReport, error::XRFClkError> {
+        let mut transport = lock_shared(&self.transport)?;
+        verify_registers_on(
+            self.chip_name,
+            transport.as_mut(),
+            register_values,
+            |transport, addresses| self.read_words(transport, addresses),
+        )
     }
+
+    /// Like `set_clks_verified`, blocks the calling thread.
+    pub fn set_clks_verified_blocking(
+        &self,
+        frequency: u64,
+    ) -> Result<VerifyReport, error::XRFClkError> {
+        set_clks_verified_with(
+            &self.config,
+            self.chip_name,
+            frequency,
+            |frequency| self.set_clks_blocking(frequency),
+            |values| self.verify_registers_blocking(values),
+        )
+    }
+
+    pub async fn write_registers(
+        &self,
+        register_values: &RegisterSet,
+    ) -> Result<(), error::XRFClkError> {
+        let device = self.clone();
+        let register_values = register_values.clone();
+        runtime::blocking(move || device.write_registers_blocking(&register_values)).await
+    }
+
+    pub async fn read_register(&self, address: u16) -> Result<u32, error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.read_register_blocking(address)).await
+    }
+
+    pub async fn read_all_registers(&self) -> Result<RegisterDump, error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.read_all_registers_blocking()).await
+    }
+
+    pub async fn lock_state(&self) -> Result<Lmx2594LockState, error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.lock_state_blocking()).await
+    }
+
+    /// True if rb_LD_VTUNE reports lock.
+    pub async fn is_locked(&self) -> Result<bool, error::XRFClkError> {
+        Ok(self.lock_state().await?.is_locked())
+    }
+
+    /// Polls rb_LD_VTUNE until the PLL is locked or `timeout` has passed.
+    pub async fn wait_for_lock(&self, timeout: Duration) -> Result<(), error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.wait_for_lock_blocking(timeout)).await
+    }
+
+    pub async fn set_clks(&self, frequency: u64) -> Result<(), error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.set_clks_blocking(frequency)).await
+    }
+
+    /// Reads back every register of `register_values` that is not read-only
+    /// or self-clearing and compares it with the expected value.
+    pub async fn verify_registers(
+        &self,
+        register_values: &RegisterSet,
+    ) -> Result<VerifyReport, error::XRFClkError> {
+        let device = self.clone();
+        let register_values = register_values.clone();
+        runtime::blocking(move || device.verify_registers_blocking(&register_values)).await
+    }
+
+    /// Like `set_clks` but reads the registers back afterwards. A report
+    /// with mismatches can be turned into an error with
+    /// `VerifyReport::into_result`.
+    pub async fn set_clks_verified(
+        &self,
+        frequency: u64,
+    ) -> Result<VerifyReport, error::XRFClkError> {
+        let device = self.clone();
+        runtime::blocking(move || device.set_clks_verified_blocking(frequency)).await
+    }
+}
+
+/// Writes `contents` into a sysfs attribute, errors name the attribute.
+pub(crate) fn write_sysfs_file(path: &Path, contents: &str) -> Result<(), error::XRFClkError> {
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .map_err(|e| error::XRFClkError::io(e, path))?;
+
+    file.write_all(contents.as_bytes())
+        .and_then(|_| file.flush())
+        .map_err(|e| error::XRFClkError::io(e, path))
 }
 
-pub async fn spi_device_bind(
+pub async fn spi_device_bind(device_string: &Path, chip: &str) -> Result<(), error::XRFClkError> {
+    spi_device_bind_in(&SystemRoot::default(), device_string, chip).await
+}
+
+/// Like `spi_device_bind` with the spidev driver below `root`.
+pub async fn spi_device_bind_in(
+    root: &SystemRoot,
     device_string: &Path,
-    chip: &String,
+    chip: &str,
+) -> Result<(), error::XRFClkError> {
+    let (root, device_string, chip) = (root.clone(), device_string.to_path_buf(), chip.to_string());
+    runtime::blocking(move || spi_device_bind_in_blocking(&root, &device_string, &chip)).await
+}
+
+pub async fn find_devices(
+    config: Arc<Config>,
+) -> Result<(Vec<LMKDevice>, Vec<LMXDevice>), error::XRFClkError> {
+    find_devices_in(config, &SystemRoot::default()).await
+}
+
+/// Like `find_devices` with sysfs and the device nodes below `root`. Every
+/// clock chip is moved over to spidev, see `discovery::bind_spidev`.
+pub async fn find_devices_in(
+    config: Arc<Config>,
+    root: &SystemRoot,
+) -> Result<(Vec<LMKDevice>, Vec<LMXDevice>), error::XRFClkError> {
+    let root = root.clone();
+    runtime::blocking(move || find_devices_in_blocking(config, &root)).await
+}
+
+/// Binds the clock chips of `descriptors` to spidev and opens them, leaving
+/// every other chip alone. `discovery::restore_driver` gives them back.
+pub async fn open_devices(
+    config: Arc<Config>,
+    descriptors: &[ClockChipDescriptor],
+) -> Result<(Vec<LMKDevice>, Vec<LMXDevice>), error::XRFClkError> {
+    let descriptors = descriptors.to_vec();
+    runtime::blocking(move || open_devices_blocking(config, &descriptors)).await
+}
+
+/// Programs `lmk_freq` into every LMK, then `lmx_freq` into every LMX. If
+/// devices fail, `schedule::ProgramFailures::of` the error lists all of
+/// them.
+pub async fn set_ref_clks(
+    config: Arc<Config>,
+    lmk_freq: u64,
+    lmx_freq: u64,
+) -> Result<(), error::XRFClkError> {
+    set_ref_clks_in(config, &SystemRoot::default(), lmk_freq, lmx_freq).await
+}
+
+/// Like `set_ref_clks` with sysfs and the device nodes below `root`.
+pub async fn set_ref_clks_in(
+    config: Arc<Config>,
+    root: &SystemRoot,
+    lmk_freq: u64,
+    lmx_freq: u64,
+) -> Result<(), error::XRFClkError> {
+    let root = root.clone();
+    runtime::blocking(move || set_ref_clks_in_blocking(config, &root, lmk_freq, lmx_freq)).await
+}
+
+/// Like `set_ref_clks` but waits up to `lock_timeout` for every device to
+/// report lock afterwards. Devices that do not lock in time are reported as
+/// not locked instead of failing the whole call.
+pub async fn set_ref_clks_and_wait_for_lock(
+    config: Arc<Config>,
+    lmk_freq: u64,
+    lmx_freq: u64,
+    lock_timeout: Duration,
+) -> Result<Vec<LockReport>, error::XRFClkError> {
+    set_ref_clks_and_wait_for_lock_in(
+        config,
+        &SystemRoot::default(),
+        lmk_freq,
+        lmx_freq,
+        lock_timeout,
+    )
+    .await
+}
+
+/// Like `set_ref_clks_and_wait_for_lock` with sysfs and the device nodes
+/// below `root`.
+pub async fn set_ref_clks_and_wait_for_lock_in(
+    config: Arc<Config>,
+    root: &SystemRoot,
+    lmk_freq: u64,
+    lmx_freq: u64,
+    lock_timeout: Duration,
+) -> Result<Vec<LockReport>, error::XRFClkError> {
+    let root = root.clone();
+    runtime::blocking(move || {
+        set_ref_clks_and_wait_for_lock_in_blocking(config, &root, lmk_freq, lmx_freq, lock_timeout)
+    })
+    .await
+}
+
+pub fn spi_device_bind_blocking(
+    device_string: &Path,
+    chip: &str,
+) -> Result<(), error::XRFClkError> {
+    spi_device_bind_in_blocking(&SystemRoot::default(), device_string, chip)
+}
+
+/// Like `spi_device_bind_in`, blocks the calling thread.
+pub fn spi_device_bind_in_blocking(
+    root: &SystemRoot,
+    device_string: &Path,
+    chip: &str,
 ) -> Result<(), error::XRFClkError> {
     let bind_file = device_string.to_path_buf().join("driver_override");
 
@@ -254,136 +926,346 @@ pub async fn spi_device_bind(
         &chip
     );
 
-    let mut driver_override_file = fs::OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(&bind_file)?;
+    write_sysfs_file(&bind_file, "spidev")?;
+    write_sysfs_file(&root.spi_driver("spidev").join("bind"), chip)?;
 
-    driver_override_file.write_all("spidev".as_bytes())?;
-    driver_override_file.flush()?;
+    Ok(())
+}
 
-    let mut bind_file = fs::OpenOptions::new()
-        .write(true)
-        .read(false)
-        .open(std::path::PathBuf::from("/sys/bus/spi/drivers/spidev/bind"))?;
+pub fn find_devices_blocking(
+    config: Arc<Config>,
+) -> Result<(Vec<LMKDevice>, Vec<LMXDevice>), error::XRFClkError> {
+    find_devices_in_blocking(config, &SystemRoot::default())
+}
 
-    bind_file.write_all(chip.as_bytes())?;
-    bind_file.flush()?;
+/// Like `find_devices_in`, blocks the calling thread.
+pub fn find_devices_in_blocking(
+    config: Arc<Config>,
+    root: &SystemRoot,
+) -> Result<(Vec<LMKDevice>, Vec<LMXDevice>), error::XRFClkError> {
+    debug!("finding devices below {}", root.path().display());
 
-    Ok(())
+    open_devices_blocking(config, &discovery::enumerate_clock_chips_in_blocking(root)?)
 }
 
-pub async fn find_devices(
+/// Like `open_devices`, blocks the calling thread.
+pub fn open_devices_blocking(
     config: Arc<Config>,
+    descriptors: &[ClockChipDescriptor],
 ) -> Result<(Vec<LMKDevice>, Vec<LMXDevice>), error::XRFClkError> {
-    debug!("finding devices on this system!");
-
     let mut lmx_devices = Vec::new();
     let mut lmk_devices = Vec::new();
 
-    const LINUX_SPI_DEVICES: &str = "/sys/bus/spi/devices/";
-
-    for file in fs::read_dir(LINUX_SPI_DEVICES)? {
-        // file is of the form e.g. 'ti,lmx2594'
-        debug!("processing spi device: {:?}", &file);
-
-        let unwrapped_file = file?;
-        let file_path = unwrapped_file.path().clone();
-        let file_name = unwrapped_file.path().join("of_node/compatible");
-        let spi_name = unwrapped_file
-            .file_name()
-            .into_string()
-            .map_err(|_| error::XRFClkError::from(error::XRFClkErrorKind::InvalidChipString))?;
-
-        // processing the file name to figure out the hardware behind this driver
-        let file_contents = match fs::read_to_string(&file_name) {
-            Ok(value) => value,
-            Err(e) => {
-                warn!("cannot read spi device: {e}");
-                continue;
-            }
-        };
+    for descriptor in descriptors {
+        let chip = descriptor.chip;
 
-        let mut chip_string = match file_contents.split_once(",") {
-            Some((_, value)) => value.to_string(),
-            None => {
-                debug!("cannot split spi device string for {}", file_name.display());
-                continue;
+        let number_of_bytes = match (chip.is_lmk(), descriptor.num_bytes) {
+            (true, None) => {
+                return Err(
+                    error::XRFClkError::from(error::XRFClkErrorKind::InvalidFilePath)
+                        .with_chip(chip)
+                        .with_path(&descriptor.path.join("of_node/num_bytes")),
+                )
             }
+            (_, num_bytes) => num_bytes.unwrap_or(3),
         };
 
-        chip_string = cleanse_c_strings(&mut chip_string);
-
-        match Chip::from_str(&chip_string) {
-            Ok(chip) => {
-                // unbinding the file
-                if file_path.join("driver").exists() {
-                    debug!("bind file exists unbinding it!");
-
-                    let mut unbind_file = fs::OpenOptions::new()
-                        .write(true)
-                        .create(true)
-                        .truncate(true)
-                        .open(file_path.join("driver/unbind"))?;
-
-                    unbind_file.write_all(spi_name.as_bytes())?;
-                    unbind_file.flush()?;
-                }
-
-                debug!("creating bind file! using spi dev: {}", &spi_name);
-                spi_device_bind(&file_path, &spi_name).await?;
-
-                let device_path = generate_device_path(spi_name);
-
-                if chip.is_lmk() {
-                    let mut bytes: [u8; 4] = [42u8; 4];
-                    fs::File::open(file_path.clone().join("of_node/num_bytes"))?
-                        .read_exact(&mut bytes)?;
-
-                    lmk_devices.push(LMKDevice::from(
-                        chip,
-                        device_path,
-                        u32::from_be_bytes(bytes),
-                        config.clone(),
-                    ))
-                } else {
-                    lmx_devices.push(LMXDevice::from(chip, device_path, config.clone()))
-                }
-            }
-            Err(_) => {
-                debug!("spi device not having valid chip string: {}", &chip_string);
-            }
+        let device_path = discovery::bind_spidev_blocking(descriptor)?;
+        let identities = descriptor.identities.iter().cloned();
+
+        if chip.is_lmk() {
+            let device = LMKDevice::from(chip, device_path, number_of_bytes, config.clone());
+            lmk_devices.push(
+                identities
+                    .into_iter()
+                    .fold(device, LMKDevice::with_identity),
+            )
+        } else {
+            let device = LMXDevice::from(chip, device_path, config.clone());
+            lmx_devices.push(
+                identities
+                    .into_iter()
+                    .fold(device, LMXDevice::with_identity),
+            )
         }
     }
 
     Ok((lmk_devices, lmx_devices))
 }
 
-pub async fn set_ref_clks(
+/// Like `set_ref_clks`, blocks the calling thread.
+pub fn set_ref_clks_blocking(
+    config: Arc<Config>,
+    lmk_freq: u64,
+    lmx_freq: u64,
+) -> Result<(), error::XRFClkError> {
+    set_ref_clks_in_blocking(config, &SystemRoot::default(), lmk_freq, lmx_freq)
+}
+
+/// Like `set_ref_clks_in`, blocks the calling thread.
+pub fn set_ref_clks_in_blocking(
     config: Arc<Config>,
+    root: &SystemRoot,
     lmk_freq: u64,
     lmx_freq: u64,
 ) -> Result<(), error::XRFClkError> {
-    let (lmk_devices, lmx_devices) = find_devices(config).await?;
+    let (lmk_devices, lmx_devices) = find_devices_in_blocking(config, root)?;
+    let reports = ref_clks_schedule(&lmk_devices, &lmx_devices, lmk_freq, lmx_freq).run_blocking();
+
+    schedule::check_reports(&reports)
+}
+
+/// `lmk_freq` for every LMK and `lmx_freq` for every LMX.
+fn ref_clks_schedule(
+    lmk_devices: &[LMKDevice],
+    lmx_devices: &[LMXDevice],
+    lmk_freq: u64,
+    lmx_freq: u64,
+) -> Schedule {
+    let schedule = lmk_devices
+        .iter()
+        .fold(Schedule::new(), |schedule, device| {
+            schedule.with_lmk(device, DeviceSetting::Frequency(lmk_freq))
+        });
+
+    lmx_devices.iter().fold(schedule, |schedule, device| {
+        schedule.with_lmx(device, DeviceSetting::Frequency(lmx_freq))
+    })
+}
+
+/// Like `set_ref_clks_and_wait_for_lock`, blocks the calling thread.
+pub fn set_ref_clks_and_wait_for_lock_blocking(
+    config: Arc<Config>,
+    lmk_freq: u64,
+    lmx_freq: u64,
+    lock_timeout: Duration,
+) -> Result<Vec<LockReport>, error::XRFClkError> {
+    set_ref_clks_and_wait_for_lock_in_blocking(
+        config,
+        &SystemRoot::default(),
+        lmk_freq,
+        lmx_freq,
+        lock_timeout,
+    )
+}
+
+/// Like `set_ref_clks_and_wait_for_lock_in`, blocks the calling thread.
+pub fn set_ref_clks_and_wait_for_lock_in_blocking(
+    config: Arc<Config>,
+    root: &SystemRoot,
+    lmk_freq: u64,
+    lmx_freq: u64,
+    lock_timeout: Duration,
+) -> Result<Vec<LockReport>, error::XRFClkError> {
+    let (lmk_devices, lmx_devices) = find_devices_in_blocking(config, root)?;
+    let program_reports =
+        ref_clks_schedule(&lmk_devices, &lmx_devices, lmk_freq, lmx_freq).run_blocking();
+    schedule::check_reports(&program_reports)?;
+
+    let mut reports = Vec::with_capacity(lmk_devices.len() + lmx_devices.len());
+
+    for lmk_device in &lmk_devices {
+        let locked = match lmk_device.wait_for_lock_blocking(lock_timeout) {
+            Ok(()) => Some(true),
+            Err(e) if e.kind() == error::XRFClkErrorKind::UnsupportedOperation => None,
+            Err(e) if e.kind() == error::XRFClkErrorKind::LockTimeout => Some(false),
+            Err(e) => return Err(e),
+        };
 
-    for lmk_device in lmk_devices {
-        lmk_device.set_clks(lmk_freq).await?;
+        reports.push(LockReport {
+            chip: lmk_device.chip(),
+            device: lmk_device.location(),
+            locked,
+        });
     }
 
-    for lmx_device in lmx_devices {
-        lmx_device.set_clks(lmx_freq).await?;
+    for lmx_device in &lmx_devices {
+        let locked = match lmx_device.wait_for_lock_blocking(lock_timeout) {
+            Ok(()) => true,
+            Err(e) if e.kind() == error::XRFClkErrorKind::LockTimeout => false,
+            Err(e) => return Err(e),
+        };
+
+        reports.push(LockReport {
+            chip: lmx_device.chip(),
+            device: lmx_device.location(),
+            locked: Some(locked),
+        });
     }
 
-    Ok(())
+    for report in &reports {
+        debug!("{report}");
+    }
+
+    Ok(reports)
 }
 
 #[cfg(test)]
 mod test {
-    use crate::load_config_from_file;
+    use crate::error::XRFClkErrorKind;
+    use crate::sequence::ProgrammingSequence;
+    use crate::simulator::Lmx2594Simulator;
+    use crate::transport::{RecordingTransport, SpiTransport};
+    use crate::{load_config_from_file, readback, Chip, Frequency, LMKDevice, LMXDevice};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn lmx_set_clks_writes_the_programming_sequence() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let transport = RecordingTransport::new();
+        let device =
+            LMXDevice::from_transport(Chip::LMX2594, Box::new(transport.clone()), config.clone());
+
+        device.set_clks(40960).await.unwrap();
+
+        let sequence = ProgrammingSequence::new(
+            config
+                .registers(Chip::LMX2594, Frequency::from_10khz(40960))
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(transport.words(), sequence.words().collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn the_blocking_api_needs_no_runtime() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let transport = RecordingTransport::new();
+        let device =
+            LMXDevice::from_transport(Chip::LMX2594, Box::new(transport.clone()), config.clone());
+
+        device.set_clks_blocking(40960).unwrap();
+
+        // clones share the transport and the R0 shadow
+        let r0 = config
+            .registers(Chip::LMX2594, Frequency::from_10khz(40960))
+            .and_then(|registers| registers.get(0))
+            .unwrap();
+        transport.push_response(0x000200);
+
+        let r110 = device.clone().read_register_blocking(110).unwrap();
+        assert_eq!(r110, 0x6E0200);
+        assert_eq!(
+            transport.words().last(),
+            Some(&(r0 & !readback::LMX2594_FCAL_EN | readback::LMX2594_MUXOUT_LD_SEL))
+        );
+    }
+
+    #[tokio::test]
+    async fn lmx_readback_toggles_muxout() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let transport = RecordingTransport::new();
+        let device =
+            LMXDevice::from_transport(Chip::LMX2594, Box::new(transport.clone()), config.clone());
+
+        device.set_clks(10240).await.unwrap();
+        transport.clear();
+        transport.push_response(0x00_0400);
+
+        assert_eq!(device.read_register(110).await.unwrap(), 0x6E0400);
+        assert_eq!(transport.words(), vec![0x002490, 0xEE0000, 0x002494]);
+    }
 
     #[test]
-    fn check_if_the_json_parses() {
-        load_config_from_file();
+    fn readback_through_another_device_keeps_r0() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let simulator = Lmx2594Simulator::from(Frequency::from_hz(122_880_000));
+        let fresh_device = || {
+            LMXDevice::from_transport(Chip::LMX2594, Box::new(simulator.clone()), config.clone())
+        };
+
+        fresh_device().set_clks_blocking(73700).unwrap();
+        let r0 = simulator.registers().get(0).unwrap();
+
+        // MUXOUT carries lock detect, R0 cannot be read back
+        let device = fresh_device();
+        assert_eq!(
+            device.read_all_registers_blocking().unwrap_err().kind(),
+            XRFClkErrorKind::UnsupportedOperation
+        );
+        assert!(device.is_locked_blocking().unwrap());
+        assert_eq!(simulator.registers().get(0), Some(r0));
+
+        // in readback mode R0 is read first and restored as it was
+        let readback_r0 = r0 & !readback::LMX2594_FCAL_EN & !readback::LMX2594_MUXOUT_LD_SEL;
+        simulator.clone().write_word(readback_r0, 3).unwrap();
+        let device = fresh_device();
+        assert_eq!(
+            device.read_all_registers_blocking().unwrap().get(0),
+            Some(readback_r0)
+        );
+        assert!(device.is_locked_blocking().unwrap());
+        assert_eq!(simulator.registers().get(0), Some(readback_r0));
+    }
+
+    #[tokio::test]
+    async fn lmk_read_all_registers_returns_a_dump() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let transport = RecordingTransport::new();
+        let device =
+            LMKDevice::from_transport(Chip::LMK04828, Box::new(transport.clone()), 3, config);
+
+        transport.push_response(0x000010);
+        let dump = device.read_all_registers().await.unwrap();
+
+        assert_eq!(dump.get(0), Some(0x000010));
+        assert_eq!(dump.get(0x183), Some(0x018300));
+        assert_eq!(transport.words()[0], 0x800000);
+    }
+
+    #[tokio::test]
+    async fn lmk_set_clks_verified_reports_mismatches() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let transport = RecordingTransport::new();
+        let device =
+            LMKDevice::from_transport(Chip::LMK04832, Box::new(transport.clone()), 3, config);
+
+        // nothing answers on the bus, every non-zero field reads back wrong
+        let report = device.set_clks_verified(12288).await.unwrap();
+
+        assert!(!report.is_ok());
+        assert!(report.checked > 0);
+    }
+
+    #[tokio::test]
+    async fn lmx_wait_for_lock() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let transport = RecordingTransport::new();
+        let device = LMXDevice::from_transport(Chip::LMX2594, Box::new(transport.clone()), config);
+
+        // R0 is unknown, so the lock detect level on MUXOUT is polled
+        transport.push_response(0x000000);
+        transport.push_response(0xFFFFFF);
+        device.wait_for_lock(Duration::from_secs(1)).await.unwrap();
+        assert_eq!(transport.words(), vec![0x800000, 0x800000]);
+
+        let timeout = device.wait_for_lock(Duration::ZERO).await.unwrap_err();
+        assert_eq!(timeout.kind(), XRFClkErrorKind::LockTimeout);
+    }
+
+    #[tokio::test]
+    async fn lmk04208_cannot_report_lock() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let device = LMKDevice::from_transport(
+            Chip::LMK04208,
+            Box::new(RecordingTransport::new()),
+            4,
+            config,
+        );
+
+        let error = device.is_locked().await.unwrap_err();
+        assert_eq!(error.kind(), XRFClkErrorKind::UnsupportedOperation);
+    }
+
+    #[tokio::test]
+    async fn lmk_set_clks_rejects_unknown_frequency() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let transport = RecordingTransport::new();
+        let device =
+            LMKDevice::from_transport(Chip::LMK04828, Box::new(transport.clone()), 3, config);
+
+        assert!(device.set_clks(1).await.is_err());
+        assert!(transport.words().is_empty());
     }
 }
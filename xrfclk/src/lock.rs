@@ -0,0 +1,109 @@
+use crate::Chip;
+use std::fmt;
+use std::time::Duration;
+
+/// Interval between two lock detect reads while waiting for lock.
+pub const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// LMX2594 register holding rb_LD_VTUNE in bits [10:9].
+pub(crate) const LMX2594_LD_REGISTER: u16 = 110;
+/// LMK0482x registers holding RB_PLL1_LD and RB_PLL2_LD in bit 1.
+pub(crate) const LMK0482X_PLL1_LD_REGISTER: u16 = 0x182;
+pub(crate) const LMK0482X_PLL2_LD_REGISTER: u16 = 0x183;
+
+/// The lock detect state of an LMX2594 as reported by rb_LD_VTUNE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lmx2594LockState {
+    UnlockedVtuneLow,
+    Invalid,
+    Locked,
+    UnlockedVtuneHigh,
+}
+
+impl Lmx2594LockState {
+    /// Decodes the rb_LD_VTUNE field out of an R110 word.
+    pub fn from_r110(word: u32) -> Self {
+        match (word >> 9) & 0b11 {
+            0 => Self::UnlockedVtuneLow,
+            1 => Self::Invalid,
+            2 => Self::Locked,
+            _ => Self::UnlockedVtuneHigh,
+        }
+    }
+
+    pub fn is_locked(&self) -> bool {
+        *self == Self::Locked
+    }
+}
+
+/// Lock detect state of both PLLs of an LMK04828/LMK04832.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LmkLockState {
+    pub pll1: bool,
+    pub pll2: bool,
+}
+
+impl LmkLockState {
+    /// Decodes RB_PLL1_LD and RB_PLL2_LD out of the 0x182/0x183 words.
+    pub fn from_registers(pll1: u32, pll2: u32) -> Self {
+        Self {
+            pll1: pll1 & (1 << 1) != 0,
+            pll2: pll2 & (1 << 1) != 0,
+        }
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.pll1 && self.pll2
+    }
+}
+
+/// Lock state of a single device after programming.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockReport {
+    pub chip: Chip,
+    /// where the device lives, e.g. its spidev node
+    pub device: String,
+    /// `None` if the chip cannot report its lock state over SPI
+    pub locked: Option<bool>,
+}
+
+impl LockReport {
+    pub fn is_locked(&self) -> bool {
+        self.locked == Some(true)
+    }
+}
+
+impl fmt::Display for LockReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = match self.locked {
+            Some(true) => "locked",
+            Some(false) => "not locked",
+            None => "lock state unknown",
+        };
+        write!(f, "{} on {}: {}", self.chip, self.device, state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decodes_lmx2594_ld_vtune() {
+        assert_eq!(
+            Lmx2594LockState::from_r110(0x6E0400),
+            Lmx2594LockState::Locked
+        );
+        assert_eq!(
+            Lmx2594LockState::from_r110(0x6E0600),
+            Lmx2594LockState::UnlockedVtuneHigh
+        );
+        assert!(!Lmx2594LockState::from_r110(0x6E0000).is_locked());
+    }
+
+    #[test]
+    fn lmk_needs_both_plls_locked() {
+        assert!(LmkLockState::from_registers(0x018202, 0x018302).is_locked());
+        assert!(!LmkLockState::from_registers(0x018202, 0x018304).is_locked());
+    }
+}
@@ -0,0 +1,471 @@
+use super::gcd;
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::regmap::{lmk04828, lmk04832};
+use crate::{load_config_from_file, Chip, Frequency, RegisterSet};
+
+pub const PLL1_PD_MAX_HZ: u64 = 40_000_000;
+/// Largest CLKin0_R and PLL1_N.
+const PLL1_DIVIDER_MAX: u64 = 16383;
+
+/// Number of clock output pairs (CLKout0/1 up to CLKout12/13).
+pub const OUTPUT_PAIRS: u8 = 7;
+
+pub const SYSREF_DIV_MIN: u32 = 8;
+pub const SYSREF_DIV_MAX: u32 = 8191;
+
+/// Limits of a member of the LMK0482x family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceLimits {
+    /// (min, max) of VCO0 and VCO1
+    pub vco_ranges: [(u64, u64); 2],
+    pub pll2_pd_max_hz: u64,
+    pub dclk_div_max: u32,
+    /// profile of the embedded config the planned fields are patched into
+    template_profile: u64,
+}
+
+impl DeviceLimits {
+    pub fn of(chip: Chip) -> Result<Self, XRFClkError> {
+        match chip {
+            Chip::LMK04828 => Ok(Self {
+                vco_ranges: [
+                    (2_370_000_000, 2_630_000_000),
+                    (2_920_000_000, 3_080_000_000),
+                ],
+                pll2_pd_max_hz: 155_000_000,
+                dclk_div_max: 32,
+                template_profile: 50025,
+            }),
+            Chip::LMK04832 => Ok(Self {
+                vco_ranges: [
+                    (2_440_000_000, 2_580_000_000),
+                    (2_945_000_000, 3_255_000_000),
+                ],
+                pll2_pd_max_hz: 320_000_000,
+                dclk_div_max: 1023,
+                template_profile: 12288,
+            }),
+            _ => Err(XRFClkError::from(XRFClkErrorKind::InvalidChipString)),
+        }
+    }
+}
+
+/// How a divider is requested, either directly or through the frequency it
+/// has to produce from the VCO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DividerRequest {
+    Divider(u32),
+    Frequency(u64),
+}
+
+/// Desired clock tree of an LMK04828/LMK04832.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LmkRequest {
+    pub chip: Chip,
+    /// reference on CLKin0, `None` leaves PLL1 as configured in the template
+    pub clkin_hz: Option<u64>,
+    /// VCXO frequency at OSCin
+    pub vcxo_hz: u64,
+    pub vco_hz: u64,
+    /// fixes PLL2_R instead of searching for it
+    pub pll2_r: Option<u32>,
+    /// fixes PLL2_P instead of searching for it
+    pub pll2_p: Option<u32>,
+    /// (output pair, divider) for every pair that should be enabled
+    pub outputs: Vec<(u8, DividerRequest)>,
+    pub sysref: Option<DividerRequest>,
+}
+
+impl LmkRequest {
+    pub fn from(chip: Chip, vcxo_hz: u64, vco_hz: u64) -> Self {
+        Self {
+            chip,
+            clkin_hz: None,
+            vcxo_hz,
+            vco_hz,
+            pll2_r: None,
+            pll2_p: None,
+            outputs: Vec::new(),
+            sysref: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pll1Plan {
+    pub clkin_r: u32,
+    pub pll1_n: u32,
+    pub phase_detector_hz: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputPlan {
+    pub pair: u8,
+    pub divider: u32,
+    pub frequency_hz: u64,
+}
+
+/// Register level settings for the requested clock tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LmkPlan {
+    pub chip: Chip,
+    pub vcxo_hz: u64,
+    pub vco_hz: u64,
+    /// 0 for VCO0, 1 for VCO1
+    pub vco: u32,
+    pub pll1: Option<Pll1Plan>,
+    pub pll2_ref_2x: bool,
+    pub pll2_r: u32,
+    pub pll2_p: u32,
+    pub pll2_n: u32,
+    pub pll2_phase_detector_hz: u64,
+    pub outputs: Vec<OutputPlan>,
+    /// (SYSREF_DIV, SYSREF frequency)
+    pub sysref: Option<(u32, u64)>,
+}
+
+fn invalid_frequency() -> XRFClkError {
+    XRFClkError::from(XRFClkErrorKind::InvalidFrequency)
+}
+
+/// Prescaler value of a PLL2_P register code.
+pub fn pll2_prescaler(code: u32) -> u32 {
+    match code {
+        0 => 8,
+        1 => 2,
+        code => code,
+    }
+}
+
+/// Resolves a divider request against the VCO frequency.
+fn divider(vco_hz: u64, request: DividerRequest, min: u32, max: u32) -> Result<u32, XRFClkError> {
+    let divider = match request {
+        DividerRequest::Divider(divider) => divider,
+        DividerRequest::Frequency(0) => return Err(invalid_frequency()),
+        DividerRequest::Frequency(frequency) => {
+            if !vco_hz.is_multiple_of(frequency) {
+                return Err(invalid_frequency());
+            }
+            (vco_hz / frequency)
+                .try_into()
+                .map_err(|_| invalid_frequency())?
+        }
+    };
+
+    if (min..=max).contains(&divider) {
+        Ok(divider)
+    } else {
+        Err(invalid_frequency())
+    }
+}
+
+impl LmkPlan {
+    pub fn new(request: &LmkRequest) -> Result<Self, XRFClkError> {
+        let limits = DeviceLimits::of(request.chip)?;
+
+        let vco = limits
+            .vco_ranges
+            .iter()
+            .position(|(min, max)| (*min..=*max).contains(&request.vco_hz))
+            .ok_or_else(invalid_frequency)? as u32;
+
+        let pll1 = match request.clkin_hz {
+            Some(clkin_hz) => Some(Self::pll1(clkin_hz, request.vcxo_hz)?),
+            None => None,
+        };
+
+        let (pll2_ref_2x, pll2_r, pll2_p, pll2_n) = Self::pll2(request, &limits)?;
+        let doubler = if pll2_ref_2x { 2 } else { 1 };
+
+        let mut outputs = Vec::with_capacity(request.outputs.len());
+        for (pair, output) in &request.outputs {
+            if *pair >= OUTPUT_PAIRS || outputs.iter().any(|o: &OutputPlan| o.pair == *pair) {
+                return Err(invalid_frequency());
+            }
+
+            let divider = divider(request.vco_hz, *output, 1, limits.dclk_div_max)?;
+            outputs.push(OutputPlan {
+                pair: *pair,
+                divider,
+                frequency_hz: request.vco_hz / divider as u64,
+            });
+        }
+
+        let sysref = match request.sysref {
+            Some(sysref) => {
+                let divider = divider(request.vco_hz, sysref, SYSREF_DIV_MIN, SYSREF_DIV_MAX)?;
+                Some((divider, request.vco_hz / divider as u64))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            chip: request.chip,
+            vcxo_hz: request.vcxo_hz,
+            vco_hz: request.vco_hz,
+            vco,
+            pll1,
+            pll2_ref_2x,
+            pll2_r,
+            pll2_p,
+            pll2_n,
+            pll2_phase_detector_hz: request.vcxo_hz * doubler / pll2_r as u64,
+            outputs,
+            sysref,
+        })
+    }
+
+    /// PLL1 locks the VCXO to CLKin0 at the highest phase detector frequency
+    /// that divides both. CLKin0_R and PLL1_N both stop at 16383, so only
+    /// that many divisors of the common frequency are worth trying.
+    fn pll1(clkin_hz: u64, vcxo_hz: u64) -> Result<Pll1Plan, XRFClkError> {
+        // gcd(0, x) is x, which would plan a divider of 0
+        if clkin_hz == 0 || vcxo_hz == 0 {
+            return Err(invalid_frequency());
+        }
+        let common = gcd(clkin_hz, vcxo_hz);
+
+        let phase_detector_hz = (common.div_ceil(PLL1_PD_MAX_HZ)..=PLL1_DIVIDER_MAX)
+            .filter(|step| common.is_multiple_of(*step))
+            .map(|step| common / step)
+            .find(|phase_detector_hz| {
+                clkin_hz / phase_detector_hz <= PLL1_DIVIDER_MAX
+                    && vcxo_hz / phase_detector_hz <= PLL1_DIVIDER_MAX
+            })
+            .ok_or_else(invalid_frequency)?;
+
+        Ok(Pll1Plan {
+            clkin_r: (clkin_hz / phase_detector_hz) as u32,
+            pll1_n: (vcxo_hz / phase_detector_hz) as u32,
+            phase_detector_hz,
+        })
+    }
+
+    /// Searches PLL2_R, the OSCin doubler and PLL2_P for an integer PLL2_N,
+    /// preferring the highest phase detector frequency.
+    fn pll2(
+        request: &LmkRequest,
+        limits: &DeviceLimits,
+    ) -> Result<(bool, u32, u32, u32), XRFClkError> {
+        let r_range = match request.pll2_r {
+            Some(r) => r..=r,
+            None => 1..=4095,
+        };
+        let p_range = match request.pll2_p {
+            Some(p) => p..=p,
+            None => 2..=8,
+        };
+
+        if request.vcxo_hz == 0 || *r_range.start() < 1 || *r_range.end() > 4095 {
+            return Err(invalid_frequency());
+        }
+        if *p_range.start() < 2 || *p_range.end() > 8 {
+            return Err(invalid_frequency());
+        }
+
+        for r in r_range {
+            for doubler in [2, 1] {
+                let reference = request.vcxo_hz * doubler;
+                if !reference.is_multiple_of(r as u64) {
+                    continue;
+                }
+
+                let phase_detector_hz = reference / r as u64;
+                if phase_detector_hz > limits.pll2_pd_max_hz {
+                    continue;
+                }
+
+                for p in p_range.clone() {
+                    let step = phase_detector_hz * p as u64;
+                    if !request.vco_hz.is_multiple_of(step) {
+                        continue;
+                    }
+
+                    let n = request.vco_hz / step;
+                    if (1..=262143).contains(&n) {
+                        return Ok((doubler == 2, r, p, n as u32));
+                    }
+                }
+            }
+        }
+
+        Err(invalid_frequency())
+    }
+
+    /// Patches the planned fields into an existing register set of the chip.
+    pub fn apply(&self, registers: &mut RegisterSet) -> Result<(), XRFClkError> {
+        let zero_delay = registers
+            .read_field(lmk04828::FB_MUX_EN)
+            .is_ok_and(|enabled| enabled != 0);
+        // PLL2_P codes 1 and 2 both select a prescaler of 2, keep the one
+        // that is already there
+        let pll2_p_code = match registers.read_field(lmk04828::PLL2_P) {
+            Ok(code) if pll2_prescaler(code as u32) == self.pll2_p => code as u32,
+            _ => self.pll2_p % 8,
+        };
+
+        let mut fields = vec![
+            (lmk04828::VCO_MUX, self.vco),
+            (lmk04828::PLL2_R, self.pll2_r),
+            (lmk04828::PLL2_P, pll2_p_code),
+            (lmk04828::PLL2_REF_2X_EN, self.pll2_ref_2x as u32),
+            (lmk04828::PLL2_N, self.pll2_n),
+        ];
+
+        if let Some(pll1) = &self.pll1 {
+            fields.push((lmk04828::CLKIN0_R, pll1.clkin_r));
+            fields.push((lmk04828::PLL1_N, pll1.pll1_n));
+        }
+
+        // outside of zero delay mode PLL2_N_CAL has to match PLL2_N
+        if !zero_delay {
+            fields.push((lmk04828::PLL2_N_CAL, self.pll2_n));
+        }
+
+        for output in &self.outputs {
+            let pair = output.pair as usize;
+
+            fields.push((lmk04828::CLKOUT_PD[pair], 0));
+            fields.push(match self.chip {
+                Chip::LMK04832 => (lmk04832::DCLK_DIV[pair], output.divider),
+                // 32 is encoded as 0
+                _ => (lmk04828::DCLKOUT_DIV[pair], output.divider % 32),
+            });
+        }
+
+        if let Some((divider, _)) = self.sysref {
+            fields.push((lmk04828::SYSREF_DIV, divider));
+        }
+
+        for (name, value) in fields {
+            registers.write_field(name, value as u64)?;
+        }
+
+        Ok(())
+    }
+
+    /// A complete register set: the planned fields on top of the default
+    /// profile of the chip in the embedded config.
+    pub fn registers(&self) -> Result<RegisterSet, XRFClkError> {
+        // the chip has been checked when the plan was made
+        let profile = DeviceLimits::of(self.chip).unwrap().template_profile;
+        let mut registers = load_config_from_file()?
+            .registers(self.chip, Frequency::from_10khz(profile))
+            .cloned()
+            .ok_or_else(|| XRFClkError::from(XRFClkErrorKind::InvalidConfig))?;
+        self.apply(&mut registers)?;
+        Ok(registers)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn reproduces_the_lmk04828_profiles() {
+        let config = load_config_from_file().unwrap();
+
+        for (profile, sysref_div) in [(50025, 300), (50023, 384)] {
+            let mut request = LmkRequest::from(Chip::LMK04828, 160_000_000, 3_000_000_000);
+            request.pll2_r = Some(8);
+            request.pll2_p = Some(2);
+            request.outputs = (0..OUTPUT_PAIRS)
+                .map(|pair| (pair, DividerRequest::Frequency(500_000_000)))
+                .collect();
+            request.sysref = Some(DividerRequest::Divider(sysref_div));
+
+            let plan = LmkPlan::new(&request).unwrap();
+            assert_eq!(plan.pll2_n, 75);
+            assert_eq!(
+                &plan.registers().unwrap(),
+                config
+                    .registers(Chip::LMK04828, Frequency::from_10khz(profile))
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn reproduces_the_lmk04828_lmx_reference_profile() {
+        let config = load_config_from_file().unwrap();
+
+        let mut request = LmkRequest::from(Chip::LMK04828, 160_000_000, 2_949_120_000);
+        request.outputs = (0..OUTPUT_PAIRS)
+            .map(|pair| (pair, DividerRequest::Frequency(122_880_000)))
+            .collect();
+        request.sysref = Some(DividerRequest::Divider(384));
+
+        let mut plan = LmkPlan::new(&request).unwrap();
+        assert_eq!(
+            (plan.pll2_ref_2x, plan.pll2_r, plan.pll2_n),
+            (true, 125, 576)
+        );
+        // 0-delay mode feeds PLL1 with the 7.68 MHz SYSREF, not the VCXO
+        plan.pll1 = Some(Pll1Plan {
+            clkin_r: 125,
+            pll1_n: 96,
+            phase_detector_hz: 80_000,
+        });
+        assert_eq!(
+            &plan.registers().unwrap(),
+            config
+                .registers(Chip::LMK04828, Frequency::from_10khz(12288))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reproduces_the_lmk04832_profile() {
+        let config = load_config_from_file().unwrap();
+
+        let mut request = LmkRequest::from(Chip::LMK04832, 122_880_000, 2_949_120_000);
+        request.pll2_r = Some(2);
+        request.outputs = [(0, 192), (1, 24), (4, 24), (5, 24), (6, 512)]
+            .into_iter()
+            .map(|(pair, divider)| (pair, DividerRequest::Divider(divider)))
+            .collect();
+        request.sysref = Some(DividerRequest::Frequency(960_000));
+
+        let plan = LmkPlan::new(&request).unwrap();
+        assert_eq!((plan.pll2_ref_2x, plan.pll2_p, plan.pll2_n), (true, 2, 12));
+        assert_eq!(
+            &plan.registers().unwrap(),
+            config
+                .registers(Chip::LMK04832, Frequency::from_10khz(12288))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn validates_against_device_limits() {
+        let request = LmkRequest::from(Chip::LMK04828, 122_880_000, 2_800_000_000);
+        assert!(LmkPlan::new(&request).is_err());
+
+        let mut request = LmkRequest::from(Chip::LMK04828, 122_880_000, 2_949_120_000);
+        request.outputs = vec![(0, DividerRequest::Divider(64))];
+        assert!(LmkPlan::new(&request).is_err());
+
+        let mut request = LmkRequest::from(Chip::LMK04832, 122_880_000, 2_949_120_000);
+        request.clkin_hz = Some(10_000_000);
+        request.outputs = vec![(0, DividerRequest::Divider(64))];
+        let plan = LmkPlan::new(&request).unwrap();
+        assert_eq!(
+            plan.pll1,
+            Some(Pll1Plan {
+                clkin_r: 125,
+                pll1_n: 1536,
+                phase_detector_hz: 80_000
+            })
+        );
+
+        // a large common frequency with no divisor that fits the dividers
+        let prime = 99_999_989;
+        assert!(LmkPlan::pll1(2 * prime, 3 * prime).is_err());
+
+        assert!(LmkPlan::pll1(0, 160_000_000).is_err());
+        assert!(LmkPlan::pll1(10_000_000, 0).is_err());
+
+        assert!(LmkPlan::new(&LmkRequest::from(Chip::LMX2594, 1, 1)).is_err());
+    }
+}
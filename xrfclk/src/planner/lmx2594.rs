@@ -0,0 +1,358 @@
+use super::gcd;
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::regmap::lmx2594;
+use crate::{load_config_from_file, Chip, Frequency, RegisterSet};
+
+pub const VCO_MIN_HZ: u64 = 7_500_000_000;
+pub const VCO_MAX_HZ: u64 = 15_000_000_000;
+
+pub const OSC_MIN_HZ: u64 = 5_000_000;
+pub const OSC_MAX_HZ: u64 = 1_400_000_000;
+/// highest input frequency the OSCin doubler accepts
+pub const OSC_2X_MAX_HZ: u64 = 200_000_000;
+
+pub const PD_MIN_HZ: u64 = 125_000;
+pub const PD_MAX_INTEGER_HZ: u64 = 400_000_000;
+pub const PD_MAX_FRACTIONAL_HZ: u64 = 300_000_000;
+
+/// Channel divider values, the index is the CHDIV register code.
+pub const CHANNEL_DIVIDERS: [u32; 18] = [
+    2, 4, 6, 8, 12, 16, 24, 32, 48, 64, 72, 96, 128, 192, 256, 384, 512, 768,
+];
+
+/// Profile of the embedded config the planned fields are patched into.
+const TEMPLATE_PROFILE: u64 = 10240;
+
+/// Source of an RF output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMux {
+    ChannelDivider = 0,
+    Vco = 1,
+}
+
+/// What the synthesizer should generate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lmx2594Request {
+    /// frequency at OSCin
+    pub reference_hz: u64,
+    pub outa_hz: u64,
+    /// `None` powers RFoutB down
+    pub outb_hz: Option<u64>,
+    pub osc_2x: bool,
+    /// input multiplier, 1 bypasses it, otherwise 3 to 7
+    pub mult: u32,
+    pub pll_r_pre: u32,
+    pub pll_r: u32,
+    /// sigma-delta modulator order, chosen from the N divider value if `None`
+    pub mash_order: Option<u32>,
+}
+
+impl Lmx2594Request {
+    /// Request with the reference fed straight into the phase detector.
+    pub fn from(reference_hz: u64, outa_hz: u64) -> Self {
+        Self {
+            reference_hz,
+            outa_hz,
+            outb_hz: None,
+            osc_2x: false,
+            mult: 1,
+            pll_r_pre: 1,
+            pll_r: 1,
+            mash_order: None,
+        }
+    }
+}
+
+/// Register level settings that produce the requested frequencies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lmx2594Plan {
+    pub reference_hz: u64,
+    pub phase_detector_hz: u64,
+    pub vco_hz: u64,
+    pub osc_2x: bool,
+    pub mult: u32,
+    pub pll_r_pre: u32,
+    pub pll_r: u32,
+    pub pll_n: u32,
+    pub pll_num: u32,
+    pub pll_den: u32,
+    pub mash_order: u32,
+    /// channel divider value, `None` if no output uses it
+    pub chdiv: Option<u32>,
+    pub outa_mux: OutputMux,
+    pub outb_mux: Option<OutputMux>,
+}
+
+/// Smallest N divider and PFD_DLY_SEL for a MASH order and VCO frequency.
+fn n_divider_limits(mash_order: u32, vco_hz: u64) -> (u32, u32) {
+    let high = vco_hz > 12_500_000_000;
+
+    match (mash_order, high) {
+        (0, false) | (1, false) => (28, 1),
+        (0, true) | (1, true) => (32, 2),
+        (2, false) => (32, 2),
+        (2, true) => (36, 3),
+        (3, false) => (36, 3),
+        (3, true) => (40, 4),
+        (_, false) => (44, 5),
+        (_, true) => (48, 6),
+    }
+}
+
+fn invalid_frequency() -> XRFClkError {
+    XRFClkError::from(XRFClkErrorKind::InvalidFrequency)
+}
+
+fn mux_for(vco_hz: u64, chdiv: Option<u32>, frequency: u64) -> Option<OutputMux> {
+    if vco_hz == frequency {
+        Some(OutputMux::Vco)
+    } else if chdiv.is_some_and(|divider| vco_hz == frequency * divider as u64) {
+        Some(OutputMux::ChannelDivider)
+    } else {
+        None
+    }
+}
+
+impl Lmx2594Plan {
+    /// Finds the lowest VCO frequency that produces all requested outputs
+    /// within the limits of the LMX2594.
+    pub fn new(request: &Lmx2594Request) -> Result<Self, XRFClkError> {
+        if !(OSC_MIN_HZ..=OSC_MAX_HZ).contains(&request.reference_hz)
+            || (request.osc_2x && request.reference_hz > OSC_2X_MAX_HZ)
+            || !(request.mult == 1 || (3..=7).contains(&request.mult))
+            || !(1..=4095).contains(&request.pll_r_pre)
+            || !(1..=255).contains(&request.pll_r)
+            || request.mash_order.is_some_and(|order| order > 4)
+            || request.outa_hz == 0
+        {
+            return Err(invalid_frequency());
+        }
+
+        let doubler = if request.osc_2x { 2 } else { 1 };
+        let pd_numerator = request.reference_hz * doubler * request.mult as u64;
+        let pd_denominator = (request.pll_r_pre * request.pll_r) as u64;
+
+        if !pd_numerator.is_multiple_of(pd_denominator) {
+            return Err(invalid_frequency());
+        }
+        let phase_detector_hz = pd_numerator / pd_denominator;
+
+        if !(PD_MIN_HZ..=PD_MAX_INTEGER_HZ).contains(&phase_detector_hz) {
+            return Err(invalid_frequency());
+        }
+
+        let candidates = std::iter::once(None).chain(CHANNEL_DIVIDERS.iter().map(|d| Some(*d)));
+
+        for chdiv in candidates {
+            let vco_hz = request.outa_hz * chdiv.unwrap_or(1) as u64;
+            if !(VCO_MIN_HZ..=VCO_MAX_HZ).contains(&vco_hz) {
+                continue;
+            }
+
+            let outa_mux = mux_for(vco_hz, chdiv, request.outa_hz).ok_or_else(invalid_frequency)?;
+            let outb_mux = match request.outb_hz {
+                Some(frequency) => match mux_for(vco_hz, chdiv, frequency) {
+                    Some(mux) => Some(mux),
+                    None => continue,
+                },
+                None => None,
+            };
+
+            let (pll_n, pll_num, pll_den) = Self::n_divider(vco_hz, phase_detector_hz);
+            let mash_order = request
+                .mash_order
+                .unwrap_or(if pll_num == 0 { 0 } else { 3 });
+
+            if pll_num != 0 && (mash_order == 0 || phase_detector_hz > PD_MAX_FRACTIONAL_HZ) {
+                continue;
+            }
+            if pll_n < n_divider_limits(mash_order, vco_hz).0 || pll_n >= 1 << 19 {
+                continue;
+            }
+
+            let uses_chdiv = outa_mux == OutputMux::ChannelDivider
+                || outb_mux == Some(OutputMux::ChannelDivider);
+
+            return Ok(Self {
+                reference_hz: request.reference_hz,
+                phase_detector_hz,
+                vco_hz,
+                osc_2x: request.osc_2x,
+                mult: request.mult,
+                pll_r_pre: request.pll_r_pre,
+                pll_r: request.pll_r,
+                pll_n,
+                pll_num,
+                pll_den,
+                mash_order,
+                chdiv: if uses_chdiv { chdiv } else { None },
+                outa_mux,
+                outb_mux,
+            });
+        }
+
+        Err(invalid_frequency())
+    }
+
+    /// Splits fVCO / fPD into N + NUM / DEN. Fractions that do not fit into
+    /// 32 bits are rounded to the closest value with the largest denominator.
+    fn n_divider(vco_hz: u64, phase_detector_hz: u64) -> (u32, u32, u32) {
+        let divisor = gcd(vco_hz, phase_detector_hz);
+        let numerator = vco_hz / divisor;
+        let denominator = phase_detector_hz / divisor;
+
+        let n = (numerator / denominator) as u32;
+        let remainder = numerator % denominator;
+
+        if denominator <= u32::MAX as u64 {
+            (n, remainder as u32, denominator as u32)
+        } else {
+            let den = u32::MAX as u64;
+            let num =
+                (remainder as u128 * den as u128 + denominator as u128 / 2) / denominator as u128;
+            (n, num as u32, den as u32)
+        }
+    }
+
+    /// The frequency the VCO actually runs at, which differs from
+    /// `vco_hz` if the fraction had to be rounded.
+    pub fn actual_vco_hz(&self) -> f64 {
+        self.phase_detector_hz as f64
+            * (self.pll_n as f64 + self.pll_num as f64 / self.pll_den as f64)
+    }
+
+    fn chdiv_code(&self) -> Option<u32> {
+        let chdiv = self.chdiv?;
+        CHANNEL_DIVIDERS
+            .iter()
+            .position(|divider| *divider == chdiv)
+            .map(|code| code as u32)
+    }
+
+    /// Patches the planned fields into an existing LMX2594 register set.
+    pub fn apply(&self, registers: &mut RegisterSet) -> Result<(), XRFClkError> {
+        let pd_mhz = self.phase_detector_hz / 1_000_000;
+        let fcal_hpfd_adj = match pd_mhz {
+            0..=100 => 0,
+            101..=150 => 1,
+            151..=200 => 2,
+            _ => 3,
+        };
+        let fcal_lpfd_adj = match self.phase_detector_hz {
+            10_000_000.. => 0,
+            5_000_000.. => 1,
+            2_500_000.. => 2,
+            _ => 3,
+        };
+        let cal_clk_div = match self.reference_hz {
+            0..=200_000_000 => 0,
+            200_000_001..=400_000_000 => 1,
+            400_000_001..=800_000_000 => 2,
+            _ => 3,
+        };
+
+        let fields = [
+            (lmx2594::FCAL_HPFD_ADJ, fcal_hpfd_adj),
+            (lmx2594::FCAL_LPFD_ADJ, fcal_lpfd_adj),
+            (lmx2594::CAL_CLK_DIV, cal_clk_div),
+            // reference path
+            (lmx2594::OSC_2X, self.osc_2x as u32),
+            (lmx2594::MULT, self.mult),
+            (lmx2594::PLL_R, self.pll_r),
+            (lmx2594::PLL_R_PRE, self.pll_r_pre),
+            // N divider
+            (lmx2594::PLL_N, self.pll_n),
+            (
+                lmx2594::PFD_DLY_SEL,
+                n_divider_limits(self.mash_order, self.vco_hz).1,
+            ),
+            (lmx2594::PLL_DEN, self.pll_den),
+            (lmx2594::PLL_NUM, self.pll_num),
+            (lmx2594::MASH_ORDER, self.mash_order),
+            // outputs
+            (lmx2594::OUTA_PD, 0),
+            (lmx2594::OUTB_PD, self.outb_mux.is_none() as u32),
+            (lmx2594::OUTA_MUX, self.outa_mux as u32),
+        ];
+        for (name, value) in fields {
+            registers.write_field(name, value as u64)?;
+        }
+
+        if let Some(outb_mux) = self.outb_mux {
+            registers.write_field(lmx2594::OUTB_MUX, outb_mux as u64)?;
+        }
+
+        // CHDIV_DIV2 for all dividers above 2
+        if let Some(code) = self.chdiv_code() {
+            registers.write_field(lmx2594::CHDIV, code as u64)?;
+            registers.write_field(lmx2594::CHDIV_DIV2, (code > 0) as u64)?;
+        }
+
+        Ok(())
+    }
+
+    /// A complete register set: the planned fields on top of the default
+    /// LMX2594 profile of the embedded config.
+    pub fn registers(&self) -> Result<RegisterSet, XRFClkError> {
+        let mut registers = load_config_from_file()?
+            .registers(Chip::LMX2594, Frequency::from_10khz(TEMPLATE_PROFILE))
+            .cloned()
+            .ok_or_else(|| XRFClkError::from(XRFClkErrorKind::InvalidConfig))?;
+        self.apply(&mut registers)?;
+        Ok(registers)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const REFERENCE_HZ: u64 = 122_880_000;
+
+    #[test]
+    fn reproduces_the_embedded_profiles() {
+        let config = load_config_from_file().unwrap();
+
+        for (profile, frequency) in [
+            (10240, 102_400_000),
+            (20480, 204_800_000),
+            (40960, 409_600_000),
+            (73700, 737_280_000),
+        ] {
+            let mut request = Lmx2594Request::from(REFERENCE_HZ, frequency);
+            request.outb_hz = Some(frequency);
+            request.mash_order = Some(3);
+
+            let plan = Lmx2594Plan::new(&request).unwrap();
+            assert_eq!(
+                &plan.registers().unwrap(),
+                config
+                    .registers(Chip::LMX2594, Frequency::from_10khz(profile))
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn plans_fractional_frequencies() {
+        let plan = Lmx2594Plan::new(&Lmx2594Request::from(REFERENCE_HZ, 3_000_000_000)).unwrap();
+
+        assert_eq!(plan.chdiv, Some(4));
+        assert_eq!(plan.vco_hz, 12_000_000_000);
+        assert_eq!(plan.mash_order, 3);
+        assert_eq!(
+            plan.phase_detector_hz as u128
+                * (plan.pll_n as u128 * plan.pll_den as u128 + plan.pll_num as u128),
+            plan.vco_hz as u128 * plan.pll_den as u128
+        );
+    }
+
+    #[test]
+    fn rejects_unreachable_outputs() {
+        let mut request = Lmx2594Request::from(REFERENCE_HZ, 409_600_000);
+        request.outb_hz = Some(1_000_000_000);
+
+        assert!(Lmx2594Plan::new(&request).is_err());
+        assert!(Lmx2594Plan::new(&Lmx2594Request::from(REFERENCE_HZ, 1_000_000)).is_err());
+    }
+}
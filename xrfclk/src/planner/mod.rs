@@ -0,0 +1,38 @@
+pub mod lmk048xx;
+pub mod lmx2594;
+
+use crate::config::RegisterSet;
+
+pub(crate) fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Writes `value` into bits `msb..=lsb` of the data part of register
+/// `address`. Registers missing from `registers` start out as zero.
+pub(crate) fn set_field(registers: &mut RegisterSet, address: u16, msb: u32, lsb: u32, value: u32) {
+    let word = registers.word_mut(address);
+
+    let mask = ((1u64 << (msb - lsb + 1)) - 1) as u32;
+    *word = (*word & !(mask << lsb)) | ((value & mask) << lsb);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Chip;
+
+    #[test]
+    fn set_field_keeps_the_other_bits() {
+        let mut registers = RegisterSet::new(Chip::LMX2594);
+        registers.insert(44, 0x2C0C23).unwrap();
+
+        set_field(&mut registers, 44, 2, 0, 0);
+        set_field(&mut registers, 75, 10, 6, 6);
+
+        assert_eq!(registers.get(44), Some(0x2C0C20));
+        assert_eq!(registers.get(75), Some(0x4B0180));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::config::RegisterSet;
+use crate::regmap::register_map;
+use crate::Chip;
+use std::collections::BTreeMap;
+
+/// R/W bit of the 24-bit LMX2594 and LMK0482x SPI words.
+const READ_BIT: u32 = 1 << 23;
+
+/// MUXOUT_LD_SEL inside the LMX2594 R0 word: 0 = readback, 1 = lock detect.
+pub(crate) const LMX2594_MUXOUT_LD_SEL: u32 = 1 << 2;
+/// FCAL_EN inside the LMX2594 R0 word.
+pub(crate) const LMX2594_FCAL_EN: u32 = 1 << 3;
+/// R0 as it comes out of reset. Its reserved bits tell a read of R0 apart
+/// from the lock detect level on MUXOUT.
+pub(crate) const LMX2594_R0_DEFAULT: u32 = 0x002414;
+
+/// R31 of the LMK04208 with everything but READBACK_ADDR.
+const LMK04208_READBACK_R31: u32 = 0x0020_001F;
+const LMK04208_READBACK_ADDR_SHIFT: u32 = 16;
+
+/// Word that makes the chip shift out the content of `address`.
+pub(crate) fn read_request(chip: Chip, address: u16) -> u32 {
+    let address = address as u32;
+
+    match chip {
+        Chip::LMX2594 => READ_BIT | (address & 0x7F) << 16,
+        Chip::LMK04828 | Chip::LMK04832 => READ_BIT | (address & 0x7FFF) << 8,
+        // uWire readback: R31 selects the register that is shifted out on
+        // the next access
+        Chip::LMK04208 => LMK04208_READBACK_R31 | (address & 0x1F) << LMK04208_READBACK_ADDR_SHIFT,
+    }
+}
+
+/// Turns the bits clocked in during a read into a register word in the same
+/// format the config uses, i.e. with the address embedded.
+pub(crate) fn read_response(chip: Chip, address: u16, received: u32) -> u32 {
+    let address = address as u32;
+
+    match chip {
+        Chip::LMX2594 => (address << 16) | (received & 0xFFFF),
+        Chip::LMK04828 | Chip::LMK04832 => (address << 8) | (received & 0xFF),
+        Chip::LMK04208 => (received & !0x1F) | (address & 0x1F),
+    }
+}
+
+/// What MUXOUT of an LMX2594 carries, told apart by a read of R0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Lmx2594Muxout {
+    /// register readback, along with the R0 word that was read
+    Readback(u32),
+    /// the lock detect output, every bit clocked in is its level
+    LockDetect(bool),
+}
+
+impl Lmx2594Muxout {
+    /// Decodes the bits clocked in while reading R0. Only in readback mode
+    /// do the reserved bits of R0 come back at their fixed values.
+    pub(crate) fn from_r0_read(received: u32) -> Self {
+        let r0 = read_response(Chip::LMX2594, 0, received);
+        let fixed = register_map(Chip::LMX2594).fixed_bits(0);
+
+        if r0 & fixed == LMX2594_R0_DEFAULT & fixed {
+            Self::Readback(r0)
+        } else {
+            Self::LockDetect(received & 0xFFFF == 0xFFFF)
+        }
+    }
+}
+
+/// Every register address of `chip` that can be read back.
+pub fn readable_registers(chip: Chip) -> Vec<u16> {
+    match chip {
+        Chip::LMX2594 => (0..=112).collect(),
+        Chip::LMK04828 => [0, 2, 3, 4, 5, 6, 12, 13]
+            .into_iter()
+            .chain(0x100..=0x173)
+            .chain([0x17C, 0x17D])
+            .chain(0x182..=0x188)
+            .chain(0x1FFD..=0x1FFF)
+            .collect(),
+        Chip::LMK04832 => [0, 2, 3, 4, 5, 6, 12, 13]
+            .into_iter()
+            .chain(0x100..=0x173)
+            .chain([0x177])
+            .chain(0x182..=0x188)
+            .chain([0x555])
+            .chain(0x1FFD..=0x1FFF)
+            .collect(),
+        Chip::LMK04208 => (0..=16).chain(24..=31).collect(),
+    }
+}
+
+/// Register contents read back from a chip, keyed by register address.
+/// Values are full SPI words with the address embedded, like in the config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterDump {
+    chip: Chip,
+    registers: BTreeMap<u16, u32>,
+}
+
+impl RegisterDump {
+    pub fn from(chip: Chip, registers: BTreeMap<u16, u32>) -> Self {
+        Self { chip, registers }
+    }
+
+    pub fn chip(&self) -> Chip {
+        self.chip
+    }
+
+    pub fn get(&self, address: u16) -> Option<u32> {
+        self.registers.get(&address).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.registers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.registers.is_empty()
+    }
+
+    /// Registers in ascending address order.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, u32)> + '_ {
+        self.registers
+            .iter()
+            .map(|(address, value)| (*address, *value))
+    }
+
+    /// The dump as register set, e.g. to store it as a profile. Words
+    /// whose address does not match, like garbage shifted in from an
+    /// LMK04208, are left out.
+    pub fn to_register_set(&self) -> RegisterSet {
+        let mut registers = RegisterSet::new(self.chip);
+
+        for (address, word) in self.iter() {
+            let _ = registers.insert(address, word);
+        }
+
+        registers
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_requests_set_the_read_bit() {
+        assert_eq!(read_request(Chip::LMX2594, 110), 0xEE0000);
+        assert_eq!(read_request(Chip::LMK04828, 0x183), 0x818300);
+        assert_eq!(read_request(Chip::LMK04208, 12), 0x002C001F);
+    }
+
+    #[test]
+    fn muxout_modes_are_told_apart() {
+        assert_eq!(
+            Lmx2594Muxout::from_r0_read(0x00251C),
+            Lmx2594Muxout::Readback(0x00251C)
+        );
+        assert_eq!(
+            Lmx2594Muxout::from_r0_read(0xFFFFFF),
+            Lmx2594Muxout::LockDetect(true)
+        );
+        assert_eq!(
+            Lmx2594Muxout::from_r0_read(0x000000),
+            Lmx2594Muxout::LockDetect(false)
+        );
+    }
+
+    #[test]
+    fn read_responses_embed_the_address() {
+        assert_eq!(read_response(Chip::LMX2594, 110, 0xAB1234), 0x6E1234);
+        assert_eq!(read_response(Chip::LMK04832, 0x182, 0x123402), 0x018202);
+    }
+}
@@ -0,0 +1,811 @@
+use super::{Access, BitRange, Field, FieldName, RegisterMap};
+use crate::Chip;
+
+// fields `describe` uses
+pub const MODE: FieldName = FieldName::from("MODE");
+pub const PLL1_R: FieldName = FieldName::from("PLL1_R");
+pub const PLL1_N: FieldName = FieldName::from("PLL1_N");
+pub const EN_PLL2_REF_2X: FieldName = FieldName::from("EN_PLL2_REF_2X");
+pub const PLL2_R: FieldName = FieldName::from("PLL2_R");
+pub const PLL2_P: FieldName = FieldName::from("PLL2_P");
+pub const PLL2_N: FieldName = FieldName::from("PLL2_N");
+pub const POWERDOWN: FieldName = FieldName::from("POWERDOWN");
+
+/// CLKoutN_DIV of every output.
+pub const CLKOUT_DIV: [FieldName; 6] = [
+    FieldName::from("CLKout0_DIV"),
+    FieldName::from("CLKout1_DIV"),
+    FieldName::from("CLKout2_DIV"),
+    FieldName::from("CLKout3_DIV"),
+    FieldName::from("CLKout4_DIV"),
+    FieldName::from("CLKout5_DIV"),
+];
+
+/// CLKoutN_TYPE of every output.
+pub const CLKOUT_TYPE: [FieldName; 6] = [
+    FieldName::from("CLKout0_TYPE"),
+    FieldName::from("CLKout1_TYPE"),
+    FieldName::from("CLKout2_TYPE"),
+    FieldName::from("CLKout3_TYPE"),
+    FieldName::from("CLKout4_TYPE"),
+    FieldName::from("CLKout5_TYPE"),
+];
+
+/// CLKoutN_PD of every output.
+pub const CLKOUT_PD: [FieldName; 6] = [
+    FieldName::from("CLKout0_PD"),
+    FieldName::from("CLKout1_PD"),
+    FieldName::from("CLKout2_PD"),
+    FieldName::from("CLKout3_PD"),
+    FieldName::from("CLKout4_PD"),
+    FieldName::from("CLKout5_PD"),
+];
+
+/// Register words the fields start out from: the 122.88 MHz profile of the
+/// embedded config with RESET cleared.
+/// These are not the values after reset.
+const TEMPLATE: [u32; 25] = [
+    0x00143200, 0x00143201, 0x00140322, 0xC0140023, 0x40140024, 0x80141E05, 0x01100006, 0x01100007,
+    0x06010008, 0x55555549, 0x9102410A, 0x0401100B, 0x1B0C006C, 0x2302886D, 0x0200000E, 0x8000800F,
+    0xC1550410, 0x00000058, 0x02C9C419, 0x8FA8001A, 0x10001E1B, 0x0021201C, 0x0180033D, 0x0200033E,
+    0x003F001F,
+];
+
+const FIELDS: &[Field] = &[
+    Field::from(
+        "CLKout0_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 31, 31)],
+    ),
+    Field::from(
+        "CLKout0_OVR",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 30, 30)],
+    ),
+    Field::from(
+        "CLKout0_ADLY_SEL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 28, 28)],
+    ),
+    Field::from(
+        "CLKout0_DDLY",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0, 27, 18)],
+    ),
+    Field::from(
+        "RESET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 17, 17)],
+    ),
+    Field::from(
+        "CLKout0_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 16, 16)],
+    ),
+    Field::from(
+        "CLKout0_DIV",
+        Access::ReadWrite,
+        0x190,
+        &[BitRange::from(0, 15, 5)],
+    ),
+    Field::from(
+        "CLKout1_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(1, 31, 31)],
+    ),
+    Field::from(
+        "CLKout1_OVR",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(1, 30, 30)],
+    ),
+    Field::from(
+        "CLKout1_ADLY_SEL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(1, 28, 28)],
+    ),
+    Field::from(
+        "CLKout1_DDLY",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(1, 27, 18)],
+    ),
+    Field::from(
+        "POWERDOWN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(1, 17, 17)],
+    ),
+    Field::from(
+        "CLKout1_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(1, 16, 16)],
+    ),
+    Field::from(
+        "CLKout1_DIV",
+        Access::ReadWrite,
+        0x190,
+        &[BitRange::from(1, 15, 5)],
+    ),
+    Field::from(
+        "CLKout2_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(2, 31, 31)],
+    ),
+    Field::from(
+        "CLKout2_OVR",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(2, 30, 30)],
+    ),
+    Field::from(
+        "CLKout2_ADLY_SEL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(2, 28, 28)],
+    ),
+    Field::from(
+        "CLKout2_DDLY",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(2, 27, 18)],
+    ),
+    Field::from(
+        "CLKout2_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(2, 16, 16)],
+    ),
+    Field::from(
+        "CLKout2_DIV",
+        Access::ReadWrite,
+        0x19,
+        &[BitRange::from(2, 15, 5)],
+    ),
+    Field::from(
+        "CLKout3_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(3, 31, 31)],
+    ),
+    Field::from(
+        "CLKout3_OVR",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(3, 30, 30)],
+    ),
+    Field::from(
+        "CLKout3_ADLY_SEL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(3, 28, 28)],
+    ),
+    Field::from(
+        "CLKout3_DDLY",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(3, 27, 18)],
+    ),
+    Field::from(
+        "CLKout3_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(3, 16, 16)],
+    ),
+    Field::from(
+        "CLKout3_DIV",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(3, 15, 5)],
+    ),
+    Field::from(
+        "CLKout4_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(4, 31, 31)],
+    ),
+    Field::from(
+        "CLKout4_OVR",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(4, 30, 30)],
+    ),
+    Field::from(
+        "CLKout4_ADLY_SEL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(4, 28, 28)],
+    ),
+    Field::from(
+        "CLKout4_DDLY",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(4, 27, 18)],
+    ),
+    Field::from(
+        "CLKout4_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(4, 16, 16)],
+    ),
+    Field::from(
+        "CLKout4_DIV",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(4, 15, 5)],
+    ),
+    Field::from(
+        "CLKout5_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(5, 31, 31)],
+    ),
+    Field::from(
+        "CLKout5_OVR",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(5, 30, 30)],
+    ),
+    Field::from(
+        "CLKout5_ADLY_SEL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(5, 28, 28)],
+    ),
+    Field::from(
+        "CLKout5_DDLY",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(5, 27, 18)],
+    ),
+    Field::from(
+        "CLKout5_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(5, 16, 16)],
+    ),
+    Field::from(
+        "CLKout5_DIV",
+        Access::ReadWrite,
+        0xF0,
+        &[BitRange::from(5, 15, 5)],
+    ),
+    Field::from(
+        "CLKout1_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(6, 27, 24)],
+    ),
+    Field::from(
+        "CLKout0_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(6, 23, 20)],
+    ),
+    Field::from(
+        "CLKout1_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(6, 15, 11)],
+    ),
+    Field::from(
+        "CLKout0_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(6, 9, 5)],
+    ),
+    Field::from(
+        "CLKout3_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(7, 27, 24)],
+    ),
+    Field::from(
+        "CLKout2_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(7, 23, 20)],
+    ),
+    Field::from(
+        "CLKout3_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(7, 15, 11)],
+    ),
+    Field::from(
+        "CLKout2_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(7, 9, 5)],
+    ),
+    Field::from(
+        "CLKout5_TYPE",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(8, 27, 24)],
+    ),
+    Field::from(
+        "CLKout4_TYPE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(8, 23, 20)],
+    ),
+    Field::from(
+        "OSCout0_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(8, 19, 16)],
+    ),
+    Field::from(
+        "CLKout5_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(8, 15, 11)],
+    ),
+    Field::from(
+        "CLKout4_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(8, 9, 5)],
+    ),
+    Field::from(
+        "EN_OSCout0",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(10, 24, 24)],
+    ),
+    Field::from(
+        "OSCout0_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(10, 22, 22)],
+    ),
+    Field::from(
+        "OSCout_DIV",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(10, 18, 16)],
+    ),
+    Field::from(
+        "VCO_DIV",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(10, 10, 8)],
+    ),
+    Field::from(
+        "MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(11, 31, 27)],
+    ),
+    Field::from(
+        "EN_SYNC",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(11, 26, 26)],
+    ),
+    Field::from(
+        "NO_SYNC_CLKout5",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(11, 25, 25)],
+    ),
+    Field::from(
+        "NO_SYNC_CLKout4",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(11, 24, 24)],
+    ),
+    Field::from(
+        "NO_SYNC_CLKout3",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(11, 23, 23)],
+    ),
+    Field::from(
+        "NO_SYNC_CLKout2",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(11, 22, 22)],
+    ),
+    Field::from(
+        "NO_SYNC_CLKout1",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(11, 21, 21)],
+    ),
+    Field::from(
+        "NO_SYNC_CLKout0",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(11, 20, 20)],
+    ),
+    Field::from(
+        "SYNC_POL_INV",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(11, 16, 16)],
+    ),
+    Field::from(
+        "SYNC_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(11, 14, 12)],
+    ),
+    Field::from(
+        "EN_PLL2_XTAL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(11, 5, 5)],
+    ),
+    Field::from(
+        "LD_MUX",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(12, 31, 27)],
+    ),
+    Field::from(
+        "LD_TYPE",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(12, 26, 24)],
+    ),
+    Field::from(
+        "SYNC_PLL2_DLD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(12, 23, 23)],
+    ),
+    Field::from(
+        "SYNC_PLL1_DLD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(12, 22, 22)],
+    ),
+    Field::from(
+        "EN_TRACK",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(12, 8, 8)],
+    ),
+    Field::from(
+        "HOLDOVER_MODE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(12, 7, 6)],
+    ),
+    Field::from(
+        "HOLDOVER_MUX",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(13, 31, 27)],
+    ),
+    Field::from(
+        "HOLDOVER_TYPE",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(13, 26, 24)],
+    ),
+    Field::from(
+        "Status_CLKin1_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(13, 22, 20)],
+    ),
+    Field::from(
+        "Status_CLKin0_TYPE",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(13, 18, 16)],
+    ),
+    Field::from(
+        "DISABLE_DLD1_DET",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(13, 15, 15)],
+    ),
+    Field::from(
+        "Status_CLKin0_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(13, 14, 12)],
+    ),
+    Field::from(
+        "CLKin_SELECT_MODE",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(13, 11, 9)],
+    ),
+    Field::from(
+        "CLKin_Sel_INV",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(13, 8, 8)],
+    ),
+    Field::from(
+        "EN_CLKin1",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(13, 6, 6)],
+    ),
+    Field::from(
+        "EN_CLKin0",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(13, 5, 5)],
+    ),
+    Field::from(
+        "LOS_TIMEOUT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(14, 31, 30)],
+    ),
+    Field::from(
+        "EN_LOS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(14, 28, 28)],
+    ),
+    Field::from(
+        "Status_CLKin1_TYPE",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(14, 26, 24)],
+    ),
+    Field::from(
+        "CLKin1_BUF_TYPE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(14, 21, 21)],
+    ),
+    Field::from(
+        "CLKin0_BUF_TYPE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(14, 20, 20)],
+    ),
+    Field::from(
+        "DAC_HIGH_TRIP",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(14, 19, 14)],
+    ),
+    Field::from(
+        "DAC_LOW_TRIP",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(14, 11, 6)],
+    ),
+    Field::from(
+        "EN_VTUNE_RAIL_DET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(14, 5, 5)],
+    ),
+    Field::from(
+        "MAN_DAC",
+        Access::ReadWrite,
+        0x200,
+        &[BitRange::from(15, 31, 22)],
+    ),
+    Field::from(
+        "EN_MAN_DAC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(15, 20, 20)],
+    ),
+    Field::from(
+        "HOLDOVER_DLD_CNT",
+        Access::ReadWrite,
+        0x200,
+        &[BitRange::from(15, 19, 6)],
+    ),
+    Field::from(
+        "FORCE_HOLDOVER",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(15, 5, 5)],
+    ),
+    Field::from(
+        "PLL2_C4_LF",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(24, 31, 28)],
+    ),
+    Field::from(
+        "PLL2_C3_LF",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(24, 27, 24)],
+    ),
+    Field::from(
+        "PLL2_R4_LF",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(24, 22, 20)],
+    ),
+    Field::from(
+        "PLL2_R3_LF",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(24, 18, 16)],
+    ),
+    Field::from(
+        "PLL1_N_DLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(24, 14, 12)],
+    ),
+    Field::from(
+        "PLL1_R_DLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(24, 10, 8)],
+    ),
+    Field::from(
+        "PLL1_WND_SIZE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(24, 7, 6)],
+    ),
+    Field::from(
+        "DAC_CLK_DIV",
+        Access::ReadWrite,
+        0xB,
+        &[BitRange::from(25, 31, 22)],
+    ),
+    Field::from(
+        "PLL1_DLD_CNT",
+        Access::ReadWrite,
+        0x2710,
+        &[BitRange::from(25, 19, 6)],
+    ),
+    Field::from(
+        "PLL2_WND_SIZE",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(26, 31, 30)],
+    ),
+    Field::from(
+        "EN_PLL2_REF_2X",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(26, 29, 29)],
+    ),
+    Field::from(
+        "PLL2_CP_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(26, 28, 28)],
+    ),
+    Field::from(
+        "PLL2_CP_GAIN",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(26, 27, 26)],
+    ),
+    Field::from(
+        "PLL2_DLD_CNT",
+        Access::ReadWrite,
+        0x2000,
+        &[BitRange::from(26, 19, 6)],
+    ),
+    Field::from(
+        "PLL2_CP_TRI",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(26, 5, 5)],
+    ),
+    Field::from(
+        "PLL1_CP_POL",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(27, 28, 28)],
+    ),
+    Field::from(
+        "PLL1_CP_GAIN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(27, 27, 26)],
+    ),
+    Field::from(
+        "CLKin1_PreR_DIV",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(27, 23, 22)],
+    ),
+    Field::from(
+        "CLKin0_PreR_DIV",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(27, 21, 20)],
+    ),
+    Field::from(
+        "PLL1_R",
+        Access::ReadWrite,
+        0x78,
+        &[BitRange::from(27, 19, 6)],
+    ),
+    Field::from(
+        "PLL1_CP_TRI",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(27, 5, 5)],
+    ),
+    Field::from(
+        "PLL2_R",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(28, 31, 20)],
+    ),
+    Field::from(
+        "PLL1_N",
+        Access::ReadWrite,
+        0x480,
+        &[BitRange::from(28, 19, 6)],
+    ),
+    Field::from(
+        "OSCin_FREQ",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(29, 26, 24)],
+    ),
+    Field::from(
+        "PLL2_FAST_PDF",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(29, 23, 23)],
+    ),
+    Field::from(
+        "PLL2_N_CAL",
+        Access::ReadWrite,
+        0x19,
+        &[BitRange::from(29, 22, 5)],
+    ),
+    Field::from(
+        "PLL2_P",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(30, 26, 24)],
+    ),
+    Field::from(
+        "PLL2_N",
+        Access::ReadWrite,
+        0x19,
+        &[BitRange::from(30, 22, 5)],
+    ),
+    Field::from(
+        "READBACK_LE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(31, 21, 21)],
+    ),
+    Field::from(
+        "READBACK_ADDR",
+        Access::ReadWrite,
+        0x1F,
+        &[BitRange::from(31, 20, 16)],
+    ),
+    Field::from(
+        "uWire_LOCK",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(31, 5, 5)],
+    ),
+];
+
+/// R0 to R31 of the LMK04208. Fields sit in bits 31 to 5 of
+/// the uWire word, bits 4 to 0 hold the address. Registers missing
+/// from a set start out from `TEMPLATE`.
+pub static REGISTER_MAP: RegisterMap =
+    RegisterMap::from_template(Chip::LMK04208, &TEMPLATE, FIELDS);
@@ -0,0 +1,2082 @@
+use super::{Access, BitRange, Field, FieldName, RegisterMap};
+use crate::Chip;
+
+// fields the planner and `describe` use, the LMK04832 names them the same
+pub const VCO_MUX: FieldName = FieldName::from("VCO_MUX");
+pub const CLKIN0_R: FieldName = FieldName::from("CLKin0_R");
+pub const PLL1_N: FieldName = FieldName::from("PLL1_N");
+pub const PLL1_PD: FieldName = FieldName::from("PLL1_PD");
+pub const PLL1_NCLK_MUX: FieldName = FieldName::from("PLL1_NCLK_MUX");
+pub const FB_MUX: FieldName = FieldName::from("FB_MUX");
+pub const FB_MUX_EN: FieldName = FieldName::from("FB_MUX_EN");
+pub const PLL2_REF_2X_EN: FieldName = FieldName::from("PLL2_REF_2X_EN");
+pub const PLL2_R: FieldName = FieldName::from("PLL2_R");
+pub const PLL2_P: FieldName = FieldName::from("PLL2_P");
+pub const PLL2_N: FieldName = FieldName::from("PLL2_N");
+pub const PLL2_N_CAL: FieldName = FieldName::from("PLL2_N_CAL");
+pub const PLL2_PD: FieldName = FieldName::from("PLL2_PD");
+pub const SYSREF_DIV: FieldName = FieldName::from("SYSREF_DIV");
+pub const SYSREF_PD: FieldName = FieldName::from("SYSREF_PD");
+pub const SYSREF_MUX: FieldName = FieldName::from("SYSREF_MUX");
+
+/// CLKoutX_Y_PD of every pair of outputs, also on the LMK04832.
+pub const CLKOUT_PD: [FieldName; 7] = [
+    FieldName::from("CLKout0_1_PD"),
+    FieldName::from("CLKout2_3_PD"),
+    FieldName::from("CLKout4_5_PD"),
+    FieldName::from("CLKout6_7_PD"),
+    FieldName::from("CLKout8_9_PD"),
+    FieldName::from("CLKout10_11_PD"),
+    FieldName::from("CLKout12_13_PD"),
+];
+
+/// DCLKoutX_DIV of every pair of outputs.
+pub const DCLKOUT_DIV: [FieldName; 7] = [
+    FieldName::from("DCLKout0_DIV"),
+    FieldName::from("DCLKout2_DIV"),
+    FieldName::from("DCLKout4_DIV"),
+    FieldName::from("DCLKout6_DIV"),
+    FieldName::from("DCLKout8_DIV"),
+    FieldName::from("DCLKout10_DIV"),
+    FieldName::from("DCLKout12_DIV"),
+];
+
+/// DCLKoutX_MUX of every pair of outputs.
+pub const DCLKOUT_MUX: [FieldName; 7] = [
+    FieldName::from("DCLKout0_MUX"),
+    FieldName::from("DCLKout2_MUX"),
+    FieldName::from("DCLKout4_MUX"),
+    FieldName::from("DCLKout6_MUX"),
+    FieldName::from("DCLKout8_MUX"),
+    FieldName::from("DCLKout10_MUX"),
+    FieldName::from("DCLKout12_MUX"),
+];
+
+/// DCLKoutX_FMT of every pair of outputs.
+pub const DCLKOUT_FMT: [FieldName; 7] = [
+    FieldName::from("DCLKout0_FMT"),
+    FieldName::from("DCLKout2_FMT"),
+    FieldName::from("DCLKout4_FMT"),
+    FieldName::from("DCLKout6_FMT"),
+    FieldName::from("DCLKout8_FMT"),
+    FieldName::from("DCLKout10_FMT"),
+    FieldName::from("DCLKout12_FMT"),
+];
+
+/// SDCLKoutY_MUX of every pair of outputs.
+pub const SDCLKOUT_MUX: [FieldName; 7] = [
+    FieldName::from("SDCLKout1_MUX"),
+    FieldName::from("SDCLKout3_MUX"),
+    FieldName::from("SDCLKout5_MUX"),
+    FieldName::from("SDCLKout7_MUX"),
+    FieldName::from("SDCLKout9_MUX"),
+    FieldName::from("SDCLKout11_MUX"),
+    FieldName::from("SDCLKout13_MUX"),
+];
+
+/// SDCLKoutY_PD of every pair of outputs.
+pub const SDCLKOUT_PD: [FieldName; 7] = [
+    FieldName::from("SDCLKout1_PD"),
+    FieldName::from("SDCLKout3_PD"),
+    FieldName::from("SDCLKout5_PD"),
+    FieldName::from("SDCLKout7_PD"),
+    FieldName::from("SDCLKout9_PD"),
+    FieldName::from("SDCLKout11_PD"),
+    FieldName::from("SDCLKout13_PD"),
+];
+
+/// SDCLKoutY_FMT of every pair of outputs.
+pub const SDCLKOUT_FMT: [FieldName; 7] = [
+    FieldName::from("SDCLKout1_FMT"),
+    FieldName::from("SDCLKout3_FMT"),
+    FieldName::from("SDCLKout5_FMT"),
+    FieldName::from("SDCLKout7_FMT"),
+    FieldName::from("SDCLKout9_FMT"),
+    FieldName::from("SDCLKout11_FMT"),
+    FieldName::from("SDCLKout13_FMT"),
+];
+
+/// Register words the fields start out from: the 500.25 MHz profile of the
+/// embedded config with RESET cleared, registers it leaves out are zero.
+/// These are not the values after reset.
+const TEMPLATE: [u32; 133] = [
+    0x000010, 0x000200, 0x000306, 0x0004D0, 0x00055B, 0x000600, 0x000C51, 0x000D04, 0x010006,
+    0x010155, 0x010255, 0x010301, 0x010422, 0x010500, 0x010670, 0x010711, 0x010806, 0x010955,
+    0x010A55, 0x010B00, 0x010C22, 0x010D00, 0x010EF0, 0x010F30, 0x011006, 0x011155, 0x011255,
+    0x011301, 0x011422, 0x011500, 0x011670, 0x011711, 0x011806, 0x011955, 0x011A55, 0x011B01,
+    0x011C22, 0x011D00, 0x011E70, 0x011F07, 0x012006, 0x012155, 0x012255, 0x012301, 0x012422,
+    0x012500, 0x012670, 0x012711, 0x012806, 0x012955, 0x012A55, 0x012B00, 0x012C22, 0x012D00,
+    0x012EF0, 0x012F10, 0x013006, 0x013155, 0x013255, 0x013301, 0x013422, 0x013500, 0x013671,
+    0x013707, 0x013820, 0x013903, 0x013A01, 0x013B2C, 0x013C00, 0x013D01, 0x013E03, 0x013F0D,
+    0x014009, 0x014100, 0x014200, 0x014311, 0x0144DD, 0x01457F, 0x01460B, 0x01470E, 0x014806,
+    0x014946, 0x014A06, 0x014B02, 0x014C00, 0x014D00, 0x014EC0, 0x014F7F, 0x015000, 0x015102,
+    0x015200, 0x015300, 0x015401, 0x015500, 0x015602, 0x015703, 0x0158C0, 0x015900, 0x015A01,
+    0x015BDA, 0x015C20, 0x015D00, 0x015E00, 0x015F3E, 0x016000, 0x016108, 0x016244, 0x016300,
+    0x016400, 0x0165A0, 0x016600, 0x016700, 0x01684B, 0x016959, 0x016A20, 0x016B00, 0x016C00,
+    0x016D00, 0x016E1B, 0x0171AA, 0x017202, 0x017300, 0x017400, 0x017C15, 0x017D33, 0x018200,
+    0x018300, 0x018400, 0x018500, 0x018800, 0x1FFD00, 0x1FFE00, 0x1FFF53,
+];
+
+const FIELDS: &[Field] = &[
+    Field::from(
+        "RESET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x0, 7, 7)],
+    ),
+    Field::from(
+        "SPI_3WIRE_DIS",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x0, 4, 4)],
+    ),
+    Field::from(
+        "POWERDOWN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x2, 0, 0)],
+    ),
+    Field::from(
+        "ID_DEVICE_TYPE",
+        Access::ReadOnly,
+        0x6,
+        &[BitRange::from(0x3, 7, 0)],
+    ),
+    Field::from(
+        "ID_PROD",
+        Access::ReadOnly,
+        0xD05B,
+        &[BitRange::from(0x4, 7, 0), BitRange::from(0x5, 7, 0)],
+    ),
+    Field::from(
+        "ID_MASKREV",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x6, 7, 0)],
+    ),
+    Field::from(
+        "ID_VNDR",
+        Access::ReadOnly,
+        0x5104,
+        &[BitRange::from(0xC, 7, 0), BitRange::from(0xD, 7, 0)],
+    ),
+    Field::from(
+        "CLKout0_1_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x100, 6, 6)],
+    ),
+    Field::from(
+        "CLKout0_1_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x100, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout0_DIV",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x100, 4, 0)],
+    ),
+    Field::from(
+        "DCLKout0_DDLY_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x101, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout0_DDLY_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x101, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout0_DDLYd_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x102, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout0_DDLYd_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x102, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout0_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x103, 7, 3)],
+    ),
+    Field::from(
+        "DCLKout0_ADLY_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x103, 2, 2)],
+    ),
+    Field::from(
+        "DCLKout0_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x103, 1, 0)],
+    ),
+    Field::from(
+        "DCLKout0_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x104, 6, 6)],
+    ),
+    Field::from(
+        "SDCLKout1_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x104, 5, 5)],
+    ),
+    Field::from(
+        "SDCLKout1_DDLY",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x104, 4, 1)],
+    ),
+    Field::from(
+        "SDCLKout1_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x104, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout1_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x105, 4, 4)],
+    ),
+    Field::from(
+        "SDCLKout1_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x105, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout0_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x106, 7, 7)],
+    ),
+    Field::from(
+        "DCLKout0_HSg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x106, 6, 6)],
+    ),
+    Field::from(
+        "DCLKout0_ADLYg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x106, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout0_ADLY_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x106, 4, 4)],
+    ),
+    Field::from(
+        "CLKout0_1_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x106, 3, 3)],
+    ),
+    Field::from(
+        "SDCLKout1_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x106, 2, 1)],
+    ),
+    Field::from(
+        "SDCLKout1_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x106, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout1_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x107, 7, 7)],
+    ),
+    Field::from(
+        "SDCLKout1_FMT",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x107, 6, 4)],
+    ),
+    Field::from(
+        "DCLKout0_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x107, 3, 3)],
+    ),
+    Field::from(
+        "DCLKout0_FMT",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x107, 2, 0)],
+    ),
+    Field::from(
+        "CLKout2_3_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x108, 6, 6)],
+    ),
+    Field::from(
+        "CLKout2_3_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x108, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout2_DIV",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x108, 4, 0)],
+    ),
+    Field::from(
+        "DCLKout2_DDLY_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x109, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout2_DDLY_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x109, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout2_DDLYd_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x10A, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout2_DDLYd_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x10A, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout2_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10B, 7, 3)],
+    ),
+    Field::from(
+        "DCLKout2_ADLY_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10B, 2, 2)],
+    ),
+    Field::from(
+        "DCLKout2_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10B, 1, 0)],
+    ),
+    Field::from(
+        "DCLKout2_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10C, 6, 6)],
+    ),
+    Field::from(
+        "SDCLKout3_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x10C, 5, 5)],
+    ),
+    Field::from(
+        "SDCLKout3_DDLY",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x10C, 4, 1)],
+    ),
+    Field::from(
+        "SDCLKout3_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10C, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout3_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10D, 4, 4)],
+    ),
+    Field::from(
+        "SDCLKout3_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10D, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout2_DDLY_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x10E, 7, 7)],
+    ),
+    Field::from(
+        "DCLKout2_HSg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x10E, 6, 6)],
+    ),
+    Field::from(
+        "DCLKout2_ADLYg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x10E, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout2_ADLY_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x10E, 4, 4)],
+    ),
+    Field::from(
+        "CLKout2_3_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10E, 3, 3)],
+    ),
+    Field::from(
+        "SDCLKout3_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10E, 2, 1)],
+    ),
+    Field::from(
+        "SDCLKout3_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10E, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout3_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10F, 7, 7)],
+    ),
+    Field::from(
+        "SDCLKout3_FMT",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x10F, 6, 4)],
+    ),
+    Field::from(
+        "DCLKout2_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10F, 3, 3)],
+    ),
+    Field::from(
+        "DCLKout2_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10F, 2, 0)],
+    ),
+    Field::from(
+        "CLKout4_5_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x110, 6, 6)],
+    ),
+    Field::from(
+        "CLKout4_5_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x110, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout4_DIV",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x110, 4, 0)],
+    ),
+    Field::from(
+        "DCLKout4_DDLY_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x111, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout4_DDLY_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x111, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout4_DDLYd_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x112, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout4_DDLYd_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x112, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout4_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x113, 7, 3)],
+    ),
+    Field::from(
+        "DCLKout4_ADLY_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x113, 2, 2)],
+    ),
+    Field::from(
+        "DCLKout4_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x113, 1, 0)],
+    ),
+    Field::from(
+        "DCLKout4_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x114, 6, 6)],
+    ),
+    Field::from(
+        "SDCLKout5_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x114, 5, 5)],
+    ),
+    Field::from(
+        "SDCLKout5_DDLY",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x114, 4, 1)],
+    ),
+    Field::from(
+        "SDCLKout5_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x114, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout5_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x115, 4, 4)],
+    ),
+    Field::from(
+        "SDCLKout5_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x115, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout4_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x116, 7, 7)],
+    ),
+    Field::from(
+        "DCLKout4_HSg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x116, 6, 6)],
+    ),
+    Field::from(
+        "DCLKout4_ADLYg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x116, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout4_ADLY_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x116, 4, 4)],
+    ),
+    Field::from(
+        "CLKout4_5_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x116, 3, 3)],
+    ),
+    Field::from(
+        "SDCLKout5_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x116, 2, 1)],
+    ),
+    Field::from(
+        "SDCLKout5_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x116, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout5_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x117, 7, 7)],
+    ),
+    Field::from(
+        "SDCLKout5_FMT",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x117, 6, 4)],
+    ),
+    Field::from(
+        "DCLKout4_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x117, 3, 3)],
+    ),
+    Field::from(
+        "DCLKout4_FMT",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x117, 2, 0)],
+    ),
+    Field::from(
+        "CLKout6_7_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x118, 6, 6)],
+    ),
+    Field::from(
+        "CLKout6_7_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x118, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout6_DIV",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x118, 4, 0)],
+    ),
+    Field::from(
+        "DCLKout6_DDLY_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x119, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout6_DDLY_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x119, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout6_DDLYd_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x11A, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout6_DDLYd_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x11A, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout6_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11B, 7, 3)],
+    ),
+    Field::from(
+        "DCLKout6_ADLY_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11B, 2, 2)],
+    ),
+    Field::from(
+        "DCLKout6_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x11B, 1, 0)],
+    ),
+    Field::from(
+        "DCLKout6_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11C, 6, 6)],
+    ),
+    Field::from(
+        "SDCLKout7_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x11C, 5, 5)],
+    ),
+    Field::from(
+        "SDCLKout7_DDLY",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x11C, 4, 1)],
+    ),
+    Field::from(
+        "SDCLKout7_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11C, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout7_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11D, 4, 4)],
+    ),
+    Field::from(
+        "SDCLKout7_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11D, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout6_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11E, 7, 7)],
+    ),
+    Field::from(
+        "DCLKout6_HSg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x11E, 6, 6)],
+    ),
+    Field::from(
+        "DCLKout6_ADLYg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x11E, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout6_ADLY_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x11E, 4, 4)],
+    ),
+    Field::from(
+        "CLKout6_7_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11E, 3, 3)],
+    ),
+    Field::from(
+        "SDCLKout7_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11E, 2, 1)],
+    ),
+    Field::from(
+        "SDCLKout7_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11E, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout7_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11F, 7, 7)],
+    ),
+    Field::from(
+        "SDCLKout7_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11F, 6, 4)],
+    ),
+    Field::from(
+        "DCLKout6_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11F, 3, 3)],
+    ),
+    Field::from(
+        "DCLKout6_FMT",
+        Access::ReadWrite,
+        0x7,
+        &[BitRange::from(0x11F, 2, 0)],
+    ),
+    Field::from(
+        "CLKout8_9_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x120, 6, 6)],
+    ),
+    Field::from(
+        "CLKout8_9_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x120, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout8_DIV",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x120, 4, 0)],
+    ),
+    Field::from(
+        "DCLKout8_DDLY_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x121, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout8_DDLY_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x121, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout8_DDLYd_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x122, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout8_DDLYd_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x122, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout8_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x123, 7, 3)],
+    ),
+    Field::from(
+        "DCLKout8_ADLY_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x123, 2, 2)],
+    ),
+    Field::from(
+        "DCLKout8_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x123, 1, 0)],
+    ),
+    Field::from(
+        "DCLKout8_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x124, 6, 6)],
+    ),
+    Field::from(
+        "SDCLKout9_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x124, 5, 5)],
+    ),
+    Field::from(
+        "SDCLKout9_DDLY",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x124, 4, 1)],
+    ),
+    Field::from(
+        "SDCLKout9_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x124, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout9_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x125, 4, 4)],
+    ),
+    Field::from(
+        "SDCLKout9_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x125, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout8_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x126, 7, 7)],
+    ),
+    Field::from(
+        "DCLKout8_HSg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x126, 6, 6)],
+    ),
+    Field::from(
+        "DCLKout8_ADLYg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x126, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout8_ADLY_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x126, 4, 4)],
+    ),
+    Field::from(
+        "CLKout8_9_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x126, 3, 3)],
+    ),
+    Field::from(
+        "SDCLKout9_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x126, 2, 1)],
+    ),
+    Field::from(
+        "SDCLKout9_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x126, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout9_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x127, 7, 7)],
+    ),
+    Field::from(
+        "SDCLKout9_FMT",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x127, 6, 4)],
+    ),
+    Field::from(
+        "DCLKout8_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x127, 3, 3)],
+    ),
+    Field::from(
+        "DCLKout8_FMT",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x127, 2, 0)],
+    ),
+    Field::from(
+        "CLKout10_11_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x128, 6, 6)],
+    ),
+    Field::from(
+        "CLKout10_11_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x128, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout10_DIV",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x128, 4, 0)],
+    ),
+    Field::from(
+        "DCLKout10_DDLY_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x129, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout10_DDLY_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x129, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout10_DDLYd_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x12A, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout10_DDLYd_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x12A, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout10_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12B, 7, 3)],
+    ),
+    Field::from(
+        "DCLKout10_ADLY_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12B, 2, 2)],
+    ),
+    Field::from(
+        "DCLKout10_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12B, 1, 0)],
+    ),
+    Field::from(
+        "DCLKout10_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12C, 6, 6)],
+    ),
+    Field::from(
+        "SDCLKout11_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x12C, 5, 5)],
+    ),
+    Field::from(
+        "SDCLKout11_DDLY",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x12C, 4, 1)],
+    ),
+    Field::from(
+        "SDCLKout11_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12C, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout11_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12D, 4, 4)],
+    ),
+    Field::from(
+        "SDCLKout11_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12D, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout10_DDLY_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x12E, 7, 7)],
+    ),
+    Field::from(
+        "DCLKout10_HSg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x12E, 6, 6)],
+    ),
+    Field::from(
+        "DCLKout10_ADLYg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x12E, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout10_ADLY_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x12E, 4, 4)],
+    ),
+    Field::from(
+        "CLKout10_11_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12E, 3, 3)],
+    ),
+    Field::from(
+        "SDCLKout11_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12E, 2, 1)],
+    ),
+    Field::from(
+        "SDCLKout11_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12E, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout11_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12F, 7, 7)],
+    ),
+    Field::from(
+        "SDCLKout11_FMT",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x12F, 6, 4)],
+    ),
+    Field::from(
+        "DCLKout10_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12F, 3, 3)],
+    ),
+    Field::from(
+        "DCLKout10_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12F, 2, 0)],
+    ),
+    Field::from(
+        "CLKout12_13_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x130, 6, 6)],
+    ),
+    Field::from(
+        "CLKout12_13_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x130, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout12_DIV",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x130, 4, 0)],
+    ),
+    Field::from(
+        "DCLKout12_DDLY_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x131, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout12_DDLY_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x131, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout12_DDLYd_CNTH",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x132, 7, 4)],
+    ),
+    Field::from(
+        "DCLKout12_DDLYd_CNTL",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x132, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout12_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x133, 7, 3)],
+    ),
+    Field::from(
+        "DCLKout12_ADLY_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x133, 2, 2)],
+    ),
+    Field::from(
+        "DCLKout12_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x133, 1, 0)],
+    ),
+    Field::from(
+        "DCLKout12_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x134, 6, 6)],
+    ),
+    Field::from(
+        "SDCLKout13_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x134, 5, 5)],
+    ),
+    Field::from(
+        "SDCLKout13_DDLY",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x134, 4, 1)],
+    ),
+    Field::from(
+        "SDCLKout13_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x134, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout13_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x135, 4, 4)],
+    ),
+    Field::from(
+        "SDCLKout13_ADLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x135, 3, 0)],
+    ),
+    Field::from(
+        "DCLKout12_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x136, 7, 7)],
+    ),
+    Field::from(
+        "DCLKout12_HSg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x136, 6, 6)],
+    ),
+    Field::from(
+        "DCLKout12_ADLYg_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x136, 5, 5)],
+    ),
+    Field::from(
+        "DCLKout12_ADLY_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x136, 4, 4)],
+    ),
+    Field::from(
+        "CLKout12_13_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x136, 3, 3)],
+    ),
+    Field::from(
+        "SDCLKout13_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x136, 2, 1)],
+    ),
+    Field::from(
+        "SDCLKout13_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x136, 0, 0)],
+    ),
+    Field::from(
+        "SDCLKout13_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x137, 7, 7)],
+    ),
+    Field::from(
+        "SDCLKout13_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x137, 6, 4)],
+    ),
+    Field::from(
+        "DCLKout12_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x137, 3, 3)],
+    ),
+    Field::from(
+        "DCLKout12_FMT",
+        Access::ReadWrite,
+        0x7,
+        &[BitRange::from(0x137, 2, 0)],
+    ),
+    Field::from(
+        "VCO_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x138, 6, 5)],
+    ),
+    Field::from(
+        "OSCout_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x138, 4, 4)],
+    ),
+    Field::from(
+        "OSCout_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x138, 3, 0)],
+    ),
+    Field::from(
+        "SYSREF_MUX",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x139, 1, 0)],
+    ),
+    Field::from(
+        "SYSREF_DIV",
+        Access::ReadWrite,
+        0x12C,
+        &[BitRange::from(0x13A, 4, 0), BitRange::from(0x13B, 7, 0)],
+    ),
+    Field::from(
+        "SYSREF_DDLY",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x13C, 4, 0), BitRange::from(0x13D, 7, 0)],
+    ),
+    Field::from(
+        "SYSREF_PULSE_CNT",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x13E, 1, 0)],
+    ),
+    Field::from(
+        "PLL2_NCLK_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x13F, 4, 4)],
+    ),
+    Field::from(
+        "PLL1_NCLK_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x13F, 3, 3)],
+    ),
+    Field::from(
+        "FB_MUX",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(0x13F, 2, 1)],
+    ),
+    Field::from(
+        "FB_MUX_EN",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x13F, 0, 0)],
+    ),
+    Field::from(
+        "PLL1_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 7, 7)],
+    ),
+    Field::from(
+        "VCO_LDO_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 6, 6)],
+    ),
+    Field::from(
+        "VCO_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 5, 5)],
+    ),
+    Field::from(
+        "OSCin_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 4, 4)],
+    ),
+    Field::from(
+        "SYSREF_GBL_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x140, 3, 3)],
+    ),
+    Field::from(
+        "SYSREF_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 2, 2)],
+    ),
+    Field::from(
+        "SYSREF_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 1, 1)],
+    ),
+    Field::from(
+        "SYSREF_PLSR_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x140, 0, 0)],
+    ),
+    Field::from(
+        "DDLYd_SYSREF_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 7, 7)],
+    ),
+    Field::from(
+        "DDLYd12_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 6, 6)],
+    ),
+    Field::from(
+        "DDLYd10_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 5, 5)],
+    ),
+    Field::from(
+        "DDLYd8_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 4, 4)],
+    ),
+    Field::from(
+        "DDLYd6_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 3, 3)],
+    ),
+    Field::from(
+        "DDLYd4_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 2, 2)],
+    ),
+    Field::from(
+        "DDLYd2_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 1, 1)],
+    ),
+    Field::from(
+        "DDLYd0_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 0, 0)],
+    ),
+    Field::from(
+        "DDLYd_STEP_CNT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x142, 3, 0)],
+    ),
+    Field::from(
+        "SYSREF_CLR",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 7, 7)],
+    ),
+    Field::from(
+        "SYNC_1SHOT_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 6, 6)],
+    ),
+    Field::from(
+        "SYNC_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 5, 5)],
+    ),
+    Field::from(
+        "SYNC_EN",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x143, 4, 4)],
+    ),
+    Field::from(
+        "SYNC_PLL2_DLD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 3, 3)],
+    ),
+    Field::from(
+        "SYNC_PLL1_DLD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 2, 2)],
+    ),
+    Field::from(
+        "SYNC_MODE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x143, 1, 0)],
+    ),
+    Field::from(
+        "SYNC_DISSYSREF",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x144, 7, 7)],
+    ),
+    Field::from(
+        "SYNC_DIS12",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x144, 6, 6)],
+    ),
+    Field::from(
+        "SYNC_DIS10",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 5, 5)],
+    ),
+    Field::from(
+        "SYNC_DIS8",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x144, 4, 4)],
+    ),
+    Field::from(
+        "SYNC_DIS6",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x144, 3, 3)],
+    ),
+    Field::from(
+        "SYNC_DIS4",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x144, 2, 2)],
+    ),
+    Field::from(
+        "SYNC_DIS2",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 1, 1)],
+    ),
+    Field::from(
+        "SYNC_DIS0",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x144, 0, 0)],
+    ),
+    Field::from(
+        "CLKin2_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x146, 5, 5)],
+    ),
+    Field::from(
+        "CLKin1_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x146, 4, 4)],
+    ),
+    Field::from(
+        "CLKin0_EN",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x146, 3, 3)],
+    ),
+    Field::from(
+        "CLKin2_TYPE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x146, 2, 2)],
+    ),
+    Field::from(
+        "CLKin1_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x146, 1, 1)],
+    ),
+    Field::from(
+        "CLKin0_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x146, 0, 0)],
+    ),
+    Field::from(
+        "CLKin_SEL_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x147, 7, 7)],
+    ),
+    Field::from(
+        "CLKin_SEL_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x147, 6, 4)],
+    ),
+    Field::from(
+        "CLKin1_OUT_MUX",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x147, 3, 2)],
+    ),
+    Field::from(
+        "CLKin0_OUT_MUX",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(0x147, 1, 0)],
+    ),
+    Field::from(
+        "CLKin_SEL0_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x148, 5, 3)],
+    ),
+    Field::from(
+        "CLKin_SEL0_TYPE",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x148, 2, 0)],
+    ),
+    Field::from(
+        "SDIO_RDBK_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x149, 6, 6)],
+    ),
+    Field::from(
+        "CLKin_SEL1_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x149, 5, 3)],
+    ),
+    Field::from(
+        "CLKin_SEL1_TYPE",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x149, 2, 0)],
+    ),
+    Field::from(
+        "RESET_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14A, 5, 3)],
+    ),
+    Field::from(
+        "RESET_TYPE",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x14A, 2, 0)],
+    ),
+    Field::from(
+        "LOS_TIMEOUT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14B, 7, 6)],
+    ),
+    Field::from(
+        "LOS_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14B, 5, 5)],
+    ),
+    Field::from(
+        "TRACK_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14B, 4, 4)],
+    ),
+    Field::from(
+        "HOLDOVER_FORCE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14B, 3, 3)],
+    ),
+    Field::from(
+        "MAN_DAC_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14B, 2, 2)],
+    ),
+    Field::from(
+        "MAN_DAC",
+        Access::ReadWrite,
+        0x200,
+        &[BitRange::from(0x14B, 1, 0), BitRange::from(0x14C, 7, 0)],
+    ),
+    Field::from(
+        "DAC_TRIP_LOW",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14D, 5, 0)],
+    ),
+    Field::from(
+        "DAC_CLK_MULT",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x14E, 7, 6)],
+    ),
+    Field::from(
+        "DAC_TRIP_HIGH",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14E, 5, 0)],
+    ),
+    Field::from(
+        "DAC_CLK_CNTR",
+        Access::ReadWrite,
+        0x7F,
+        &[BitRange::from(0x14F, 7, 0)],
+    ),
+    Field::from(
+        "CLKin_OVERRIDE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 6, 6)],
+    ),
+    Field::from(
+        "HOLDOVER_PLL1_DET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 4, 4)],
+    ),
+    Field::from(
+        "HOLDOVER_LOS_DET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 3, 3)],
+    ),
+    Field::from(
+        "HOLDOVER_VTUNE_DET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 2, 2)],
+    ),
+    Field::from(
+        "HOLDOVER_HITLESS_SWITCH",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 1, 1)],
+    ),
+    Field::from(
+        "HOLDOVER_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 0, 0)],
+    ),
+    Field::from(
+        "HOLDOVER_DLD_CNT",
+        Access::ReadWrite,
+        0x200,
+        &[BitRange::from(0x151, 5, 0), BitRange::from(0x152, 7, 0)],
+    ),
+    Field::from(
+        "CLKin0_R",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x153, 5, 0), BitRange::from(0x154, 7, 0)],
+    ),
+    Field::from(
+        "CLKin1_R",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(0x155, 5, 0), BitRange::from(0x156, 7, 0)],
+    ),
+    Field::from(
+        "CLKin2_R",
+        Access::ReadWrite,
+        0x3C0,
+        &[BitRange::from(0x157, 5, 0), BitRange::from(0x158, 7, 0)],
+    ),
+    Field::from(
+        "PLL1_N",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x159, 5, 0), BitRange::from(0x15A, 7, 0)],
+    ),
+    Field::from(
+        "PLL1_WND_SIZE",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x15B, 7, 6)],
+    ),
+    Field::from(
+        "PLL1_CP_TRI",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x15B, 5, 5)],
+    ),
+    Field::from(
+        "PLL1_CP_POL",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x15B, 4, 4)],
+    ),
+    Field::from(
+        "PLL1_CP_GAIN",
+        Access::ReadWrite,
+        0xA,
+        &[BitRange::from(0x15B, 3, 0)],
+    ),
+    Field::from(
+        "PLL1_DLD_CNT",
+        Access::ReadWrite,
+        0x2000,
+        &[BitRange::from(0x15C, 5, 0), BitRange::from(0x15D, 7, 0)],
+    ),
+    Field::from(
+        "PLL1_R_DLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x15E, 5, 3)],
+    ),
+    Field::from(
+        "PLL1_N_DLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x15E, 2, 0)],
+    ),
+    Field::from(
+        "PLL1_LD_MUX",
+        Access::ReadWrite,
+        0x7,
+        &[BitRange::from(0x15F, 7, 3)],
+    ),
+    Field::from(
+        "PLL1_LD_TYPE",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x15F, 2, 0)],
+    ),
+    Field::from(
+        "PLL2_R",
+        Access::ReadWrite,
+        0x8,
+        &[BitRange::from(0x160, 3, 0), BitRange::from(0x161, 7, 0)],
+    ),
+    Field::from(
+        "PLL2_P",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(0x162, 7, 5)],
+    ),
+    Field::from(
+        "OSCin_FREQ",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x162, 4, 2)],
+    ),
+    Field::from(
+        "PLL2_XTAL_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x162, 1, 1)],
+    ),
+    Field::from(
+        "PLL2_REF_2X_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x162, 0, 0)],
+    ),
+    Field::from(
+        "PLL2_N_CAL",
+        Access::ReadWrite,
+        0xA0,
+        &[
+            BitRange::from(0x163, 1, 0),
+            BitRange::from(0x164, 7, 0),
+            BitRange::from(0x165, 7, 0),
+        ],
+    ),
+    Field::from(
+        "PLL2_N",
+        Access::ReadWrite,
+        0x4B,
+        &[
+            BitRange::from(0x166, 1, 0),
+            BitRange::from(0x167, 7, 0),
+            BitRange::from(0x168, 7, 0),
+        ],
+    ),
+    Field::from(
+        "PLL2_WND_SIZE",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(0x169, 6, 5)],
+    ),
+    Field::from(
+        "PLL2_CP_GAIN",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x169, 4, 3)],
+    ),
+    Field::from(
+        "PLL2_CP_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x169, 2, 2)],
+    ),
+    Field::from(
+        "PLL2_CP_TRI",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x169, 1, 1)],
+    ),
+    Field::from(
+        "SYSREF_REQ_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16A, 6, 6)],
+    ),
+    Field::from(
+        "PLL2_DLD_CNT",
+        Access::ReadWrite,
+        0x2000,
+        &[BitRange::from(0x16A, 5, 0), BitRange::from(0x16B, 7, 0)],
+    ),
+    Field::from(
+        "PLL2_LF_R4",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16C, 5, 3)],
+    ),
+    Field::from(
+        "PLL2_LF_R3",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16C, 2, 0)],
+    ),
+    Field::from(
+        "PLL2_LF_C4",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16D, 7, 4)],
+    ),
+    Field::from(
+        "PLL2_LF_C3",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16D, 3, 0)],
+    ),
+    Field::from(
+        "PLL2_LD_MUX",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x16E, 7, 3)],
+    ),
+    Field::from(
+        "PLL2_LD_TYPE",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x16E, 2, 0)],
+    ),
+    Field::from(
+        "PLL2_PRE_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x173, 6, 6)],
+    ),
+    Field::from(
+        "PLL2_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x173, 5, 5)],
+    ),
+    Field::from(
+        "VCO1_DIV",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x174, 4, 0)],
+    ),
+    Field::from(
+        "OPT_REG_1",
+        Access::ReadWrite,
+        0x15,
+        &[BitRange::from(0x17C, 7, 0)],
+    ),
+    Field::from(
+        "OPT_REG_2",
+        Access::ReadWrite,
+        0x33,
+        &[BitRange::from(0x17D, 7, 0)],
+    ),
+    Field::from(
+        "RB_PLL1_LD_LOST",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x182, 2, 2)],
+    ),
+    Field::from(
+        "RB_PLL1_LD",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x182, 1, 1)],
+    ),
+    Field::from(
+        "CLR_PLL1_LD_LOST",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x182, 0, 0)],
+    ),
+    Field::from(
+        "RB_PLL2_LD_LOST",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x183, 2, 2)],
+    ),
+    Field::from(
+        "RB_PLL2_LD",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x183, 1, 1)],
+    ),
+    Field::from(
+        "CLR_PLL2_LD_LOST",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x183, 0, 0)],
+    ),
+    Field::from(
+        "RB_DAC_VALUE",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 7, 6), BitRange::from(0x185, 7, 0)],
+    ),
+    Field::from(
+        "RB_CLKin2_SEL",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 5, 5)],
+    ),
+    Field::from(
+        "RB_CLKin1_SEL",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 4, 4)],
+    ),
+    Field::from(
+        "RB_CLKin0_SEL",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 3, 3)],
+    ),
+    Field::from(
+        "RB_CLKin1_LOS",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 1, 1)],
+    ),
+    Field::from(
+        "RB_CLKin0_LOS",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 0, 0)],
+    ),
+    Field::from(
+        "RB_HOLDOVER",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x188, 4, 4)],
+    ),
+    Field::from(
+        "SPI_LOCK",
+        Access::ReadWrite,
+        0x53,
+        &[
+            BitRange::from(0x1FFD, 7, 0),
+            BitRange::from(0x1FFE, 7, 0),
+            BitRange::from(0x1FFF, 7, 0),
+        ],
+    ),
+];
+
+/// Registers 0x000 to 0x1FFF of the LMK04828 (TI SNAS605), the data byte of
+/// each word. Registers missing from a set start out from `TEMPLATE`.
+pub static REGISTER_MAP: RegisterMap =
+    RegisterMap::from_template(Chip::LMK04828, &TEMPLATE, FIELDS);
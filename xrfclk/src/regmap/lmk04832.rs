@@ -0,0 +1,1860 @@
+use super::{Access, BitRange, Field, FieldName, RegisterMap};
+use crate::Chip;
+
+// fields the planner and `describe` use that the LMK04828 names the same
+pub use super::lmk04828::{
+    CLKIN0_R, CLKOUT_PD, FB_MUX, FB_MUX_EN, PLL1_N, PLL1_NCLK_MUX, PLL1_PD, PLL2_N, PLL2_N_CAL,
+    PLL2_P, PLL2_PD, PLL2_R, PLL2_REF_2X_EN, SYSREF_DIV, SYSREF_MUX, SYSREF_PD, VCO_MUX,
+};
+
+/// DCLKX_Y_DIV of every pair of outputs.
+pub const DCLK_DIV: [FieldName; 7] = [
+    FieldName::from("DCLK0_1_DIV"),
+    FieldName::from("DCLK2_3_DIV"),
+    FieldName::from("DCLK4_5_DIV"),
+    FieldName::from("DCLK6_7_DIV"),
+    FieldName::from("DCLK8_9_DIV"),
+    FieldName::from("DCLK10_11_DIV"),
+    FieldName::from("DCLK12_13_DIV"),
+];
+
+/// DCLKX_Y_BYP of every pair of outputs.
+pub const DCLK_BYP: [FieldName; 7] = [
+    FieldName::from("DCLK0_1_BYP"),
+    FieldName::from("DCLK2_3_BYP"),
+    FieldName::from("DCLK4_5_BYP"),
+    FieldName::from("DCLK6_7_BYP"),
+    FieldName::from("DCLK8_9_BYP"),
+    FieldName::from("DCLK10_11_BYP"),
+    FieldName::from("DCLK12_13_BYP"),
+];
+
+/// SCLKX_Y_PD of every pair of outputs.
+pub const SCLK_PD: [FieldName; 7] = [
+    FieldName::from("SCLK0_1_PD"),
+    FieldName::from("SCLK2_3_PD"),
+    FieldName::from("SCLK4_5_PD"),
+    FieldName::from("SCLK6_7_PD"),
+    FieldName::from("SCLK8_9_PD"),
+    FieldName::from("SCLK10_11_PD"),
+    FieldName::from("SCLK12_13_PD"),
+];
+
+/// CLKoutN_SRC_MUX of every output.
+pub const CLKOUT_SRC_MUX: [FieldName; 14] = [
+    FieldName::from("CLKout0_SRC_MUX"),
+    FieldName::from("CLKout1_SRC_MUX"),
+    FieldName::from("CLKout2_SRC_MUX"),
+    FieldName::from("CLKout3_SRC_MUX"),
+    FieldName::from("CLKout4_SRC_MUX"),
+    FieldName::from("CLKout5_SRC_MUX"),
+    FieldName::from("CLKout6_SRC_MUX"),
+    FieldName::from("CLKout7_SRC_MUX"),
+    FieldName::from("CLKout8_SRC_MUX"),
+    FieldName::from("CLKout9_SRC_MUX"),
+    FieldName::from("CLKout10_SRC_MUX"),
+    FieldName::from("CLKout11_SRC_MUX"),
+    FieldName::from("CLKout12_SRC_MUX"),
+    FieldName::from("CLKout13_SRC_MUX"),
+];
+
+/// CLKoutN_FMT of every output.
+pub const CLKOUT_FMT: [FieldName; 14] = [
+    FieldName::from("CLKout0_FMT"),
+    FieldName::from("CLKout1_FMT"),
+    FieldName::from("CLKout2_FMT"),
+    FieldName::from("CLKout3_FMT"),
+    FieldName::from("CLKout4_FMT"),
+    FieldName::from("CLKout5_FMT"),
+    FieldName::from("CLKout6_FMT"),
+    FieldName::from("CLKout7_FMT"),
+    FieldName::from("CLKout8_FMT"),
+    FieldName::from("CLKout9_FMT"),
+    FieldName::from("CLKout10_FMT"),
+    FieldName::from("CLKout11_FMT"),
+    FieldName::from("CLKout12_FMT"),
+    FieldName::from("CLKout13_FMT"),
+];
+
+/// Register words the fields start out from: the 122.88 MHz profile of the
+/// embedded config with RESET cleared, registers it leaves out are zero.
+/// These are not the values after reset.
+const TEMPLATE: [u32; 127] = [
+    0x000010, 0x000200, 0x000306, 0x0004D1, 0x000563, 0x000650, 0x000C51, 0x000D04, 0x0100C0,
+    0x01010A, 0x010200, 0x010340, 0x010410, 0x010512, 0x010604, 0x010750, 0x010818, 0x01090A,
+    0x010A00, 0x010B40, 0x010C10, 0x010D12, 0x010E04, 0x010F05, 0x011008, 0x01110A, 0x011280,
+    0x011340, 0x011410, 0x011512, 0x011604, 0x011700, 0x011810, 0x01190A, 0x011A80, 0x011B40,
+    0x011C10, 0x011D12, 0x011E04, 0x011F00, 0x012018, 0x01210A, 0x012200, 0x012340, 0x012410,
+    0x012512, 0x012604, 0x012755, 0x012818, 0x01290A, 0x012A00, 0x012B40, 0x012C10, 0x012D12,
+    0x012E04, 0x012F55, 0x013000, 0x01310A, 0x013202, 0x013340, 0x013410, 0x013512, 0x013604,
+    0x013705, 0x013820, 0x013900, 0x013A0C, 0x013B00, 0x013C00, 0x013D08, 0x013E03, 0x013F00,
+    0x01400D, 0x014100, 0x014200, 0x014311, 0x014400, 0x014500, 0x01460E, 0x01470E, 0x014805,
+    0x014945, 0x014A05, 0x014B06, 0x014C00, 0x014D00, 0x014EC0, 0x014F7F, 0x015000, 0x015102,
+    0x015200, 0x015300, 0x015478, 0x015500, 0x015678, 0x015700, 0x015896, 0x015904, 0x015AB0,
+    0x015BD4, 0x015C20, 0x015D00, 0x015E1E, 0x015F3B, 0x016000, 0x016102, 0x01622D, 0x016300,
+    0x016400, 0x01650C, 0x016600, 0x016700, 0x01680C, 0x016958, 0x016A20, 0x016B00, 0x016C00,
+    0x016D00, 0x016E1B, 0x017310, 0x017700, 0x018200, 0x018300, 0x018400, 0x018500, 0x018800,
+    0x055500,
+];
+
+const FIELDS: &[Field] = &[
+    Field::from(
+        "RESET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x0, 7, 7)],
+    ),
+    Field::from(
+        "SPI_3WIRE_DIS",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x0, 4, 4)],
+    ),
+    Field::from(
+        "POWERDOWN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x2, 0, 0)],
+    ),
+    Field::from(
+        "ID_DEVICE_TYPE",
+        Access::ReadOnly,
+        0x6,
+        &[BitRange::from(0x3, 7, 0)],
+    ),
+    Field::from(
+        "ID_PROD",
+        Access::ReadOnly,
+        0xD163,
+        &[BitRange::from(0x4, 7, 0), BitRange::from(0x5, 7, 0)],
+    ),
+    Field::from(
+        "ID_MASKREV",
+        Access::ReadOnly,
+        0x50,
+        &[BitRange::from(0x6, 7, 0)],
+    ),
+    Field::from(
+        "ID_VNDR",
+        Access::ReadOnly,
+        0x5104,
+        &[BitRange::from(0xC, 7, 0), BitRange::from(0xD, 7, 0)],
+    ),
+    Field::from(
+        "DCLK0_1_DIV",
+        Access::ReadWrite,
+        0xC0,
+        &[BitRange::from(0x102, 1, 0), BitRange::from(0x100, 7, 0)],
+    ),
+    Field::from(
+        "DCLK0_1_DDLY",
+        Access::ReadWrite,
+        0xA,
+        &[BitRange::from(0x102, 3, 2), BitRange::from(0x101, 7, 0)],
+    ),
+    Field::from(
+        "CLKout0_1_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x102, 7, 7)],
+    ),
+    Field::from(
+        "CLKout0_1_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x102, 6, 6)],
+    ),
+    Field::from(
+        "CLKout0_1_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x102, 5, 5)],
+    ),
+    Field::from(
+        "DCLK0_1_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x102, 4, 4)],
+    ),
+    Field::from(
+        "CLKout0_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x103, 5, 5)],
+    ),
+    Field::from(
+        "DCLK0_1_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x103, 4, 4)],
+    ),
+    Field::from(
+        "DCLK0_1_BYP",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x103, 3, 3)],
+    ),
+    Field::from(
+        "DCLK0_1_DCC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x103, 2, 2)],
+    ),
+    Field::from(
+        "DCLK0_1_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x103, 1, 1)],
+    ),
+    Field::from(
+        "DCLK0_1_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x103, 0, 0)],
+    ),
+    Field::from(
+        "CLKout1_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x104, 5, 5)],
+    ),
+    Field::from(
+        "SCLK0_1_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x104, 4, 4)],
+    ),
+    Field::from(
+        "SCLK0_1_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x104, 3, 2)],
+    ),
+    Field::from(
+        "SCLK0_1_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x104, 1, 1)],
+    ),
+    Field::from(
+        "SCLK0_1_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x104, 0, 0)],
+    ),
+    Field::from(
+        "SCLK0_1_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x105, 5, 5)],
+    ),
+    Field::from(
+        "SCLK0_1_ADLY",
+        Access::ReadWrite,
+        0x12,
+        &[BitRange::from(0x105, 4, 0)],
+    ),
+    Field::from(
+        "SCLK0_1_DDLY",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(0x106, 3, 0)],
+    ),
+    Field::from(
+        "CLKout1_FMT",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x107, 7, 4)],
+    ),
+    Field::from(
+        "CLKout0_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x107, 3, 0)],
+    ),
+    Field::from(
+        "DCLK2_3_DIV",
+        Access::ReadWrite,
+        0x18,
+        &[BitRange::from(0x10A, 1, 0), BitRange::from(0x108, 7, 0)],
+    ),
+    Field::from(
+        "DCLK2_3_DDLY",
+        Access::ReadWrite,
+        0xA,
+        &[BitRange::from(0x10A, 3, 2), BitRange::from(0x109, 7, 0)],
+    ),
+    Field::from(
+        "CLKout2_3_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10A, 7, 7)],
+    ),
+    Field::from(
+        "CLKout2_3_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10A, 6, 6)],
+    ),
+    Field::from(
+        "CLKout2_3_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10A, 5, 5)],
+    ),
+    Field::from(
+        "DCLK2_3_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10A, 4, 4)],
+    ),
+    Field::from(
+        "CLKout2_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10B, 5, 5)],
+    ),
+    Field::from(
+        "DCLK2_3_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10B, 4, 4)],
+    ),
+    Field::from(
+        "DCLK2_3_BYP",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10B, 3, 3)],
+    ),
+    Field::from(
+        "DCLK2_3_DCC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10B, 2, 2)],
+    ),
+    Field::from(
+        "DCLK2_3_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10B, 1, 1)],
+    ),
+    Field::from(
+        "DCLK2_3_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10B, 0, 0)],
+    ),
+    Field::from(
+        "CLKout3_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10C, 5, 5)],
+    ),
+    Field::from(
+        "SCLK2_3_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x10C, 4, 4)],
+    ),
+    Field::from(
+        "SCLK2_3_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10C, 3, 2)],
+    ),
+    Field::from(
+        "SCLK2_3_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10C, 1, 1)],
+    ),
+    Field::from(
+        "SCLK2_3_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10C, 0, 0)],
+    ),
+    Field::from(
+        "SCLK2_3_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10D, 5, 5)],
+    ),
+    Field::from(
+        "SCLK2_3_ADLY",
+        Access::ReadWrite,
+        0x12,
+        &[BitRange::from(0x10D, 4, 0)],
+    ),
+    Field::from(
+        "SCLK2_3_DDLY",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(0x10E, 3, 0)],
+    ),
+    Field::from(
+        "CLKout3_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x10F, 7, 4)],
+    ),
+    Field::from(
+        "CLKout2_FMT",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x10F, 3, 0)],
+    ),
+    Field::from(
+        "DCLK4_5_DIV",
+        Access::ReadWrite,
+        0x8,
+        &[BitRange::from(0x112, 1, 0), BitRange::from(0x110, 7, 0)],
+    ),
+    Field::from(
+        "DCLK4_5_DDLY",
+        Access::ReadWrite,
+        0xA,
+        &[BitRange::from(0x112, 3, 2), BitRange::from(0x111, 7, 0)],
+    ),
+    Field::from(
+        "CLKout4_5_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x112, 7, 7)],
+    ),
+    Field::from(
+        "CLKout4_5_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x112, 6, 6)],
+    ),
+    Field::from(
+        "CLKout4_5_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x112, 5, 5)],
+    ),
+    Field::from(
+        "DCLK4_5_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x112, 4, 4)],
+    ),
+    Field::from(
+        "CLKout4_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x113, 5, 5)],
+    ),
+    Field::from(
+        "DCLK4_5_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x113, 4, 4)],
+    ),
+    Field::from(
+        "DCLK4_5_BYP",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x113, 3, 3)],
+    ),
+    Field::from(
+        "DCLK4_5_DCC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x113, 2, 2)],
+    ),
+    Field::from(
+        "DCLK4_5_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x113, 1, 1)],
+    ),
+    Field::from(
+        "DCLK4_5_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x113, 0, 0)],
+    ),
+    Field::from(
+        "CLKout5_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x114, 5, 5)],
+    ),
+    Field::from(
+        "SCLK4_5_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x114, 4, 4)],
+    ),
+    Field::from(
+        "SCLK4_5_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x114, 3, 2)],
+    ),
+    Field::from(
+        "SCLK4_5_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x114, 1, 1)],
+    ),
+    Field::from(
+        "SCLK4_5_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x114, 0, 0)],
+    ),
+    Field::from(
+        "SCLK4_5_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x115, 5, 5)],
+    ),
+    Field::from(
+        "SCLK4_5_ADLY",
+        Access::ReadWrite,
+        0x12,
+        &[BitRange::from(0x115, 4, 0)],
+    ),
+    Field::from(
+        "SCLK4_5_DDLY",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(0x116, 3, 0)],
+    ),
+    Field::from(
+        "CLKout5_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x117, 7, 4)],
+    ),
+    Field::from(
+        "CLKout4_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x117, 3, 0)],
+    ),
+    Field::from(
+        "DCLK6_7_DIV",
+        Access::ReadWrite,
+        0x10,
+        &[BitRange::from(0x11A, 1, 0), BitRange::from(0x118, 7, 0)],
+    ),
+    Field::from(
+        "DCLK6_7_DDLY",
+        Access::ReadWrite,
+        0xA,
+        &[BitRange::from(0x11A, 3, 2), BitRange::from(0x119, 7, 0)],
+    ),
+    Field::from(
+        "CLKout6_7_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x11A, 7, 7)],
+    ),
+    Field::from(
+        "CLKout6_7_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11A, 6, 6)],
+    ),
+    Field::from(
+        "CLKout6_7_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11A, 5, 5)],
+    ),
+    Field::from(
+        "DCLK6_7_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11A, 4, 4)],
+    ),
+    Field::from(
+        "CLKout6_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11B, 5, 5)],
+    ),
+    Field::from(
+        "DCLK6_7_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11B, 4, 4)],
+    ),
+    Field::from(
+        "DCLK6_7_BYP",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11B, 3, 3)],
+    ),
+    Field::from(
+        "DCLK6_7_DCC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11B, 2, 2)],
+    ),
+    Field::from(
+        "DCLK6_7_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11B, 1, 1)],
+    ),
+    Field::from(
+        "DCLK6_7_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11B, 0, 0)],
+    ),
+    Field::from(
+        "CLKout7_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11C, 5, 5)],
+    ),
+    Field::from(
+        "SCLK6_7_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x11C, 4, 4)],
+    ),
+    Field::from(
+        "SCLK6_7_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11C, 3, 2)],
+    ),
+    Field::from(
+        "SCLK6_7_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11C, 1, 1)],
+    ),
+    Field::from(
+        "SCLK6_7_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11C, 0, 0)],
+    ),
+    Field::from(
+        "SCLK6_7_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11D, 5, 5)],
+    ),
+    Field::from(
+        "SCLK6_7_ADLY",
+        Access::ReadWrite,
+        0x12,
+        &[BitRange::from(0x11D, 4, 0)],
+    ),
+    Field::from(
+        "SCLK6_7_DDLY",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(0x11E, 3, 0)],
+    ),
+    Field::from(
+        "CLKout7_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11F, 7, 4)],
+    ),
+    Field::from(
+        "CLKout6_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x11F, 3, 0)],
+    ),
+    Field::from(
+        "DCLK8_9_DIV",
+        Access::ReadWrite,
+        0x18,
+        &[BitRange::from(0x122, 1, 0), BitRange::from(0x120, 7, 0)],
+    ),
+    Field::from(
+        "DCLK8_9_DDLY",
+        Access::ReadWrite,
+        0xA,
+        &[BitRange::from(0x122, 3, 2), BitRange::from(0x121, 7, 0)],
+    ),
+    Field::from(
+        "CLKout8_9_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x122, 7, 7)],
+    ),
+    Field::from(
+        "CLKout8_9_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x122, 6, 6)],
+    ),
+    Field::from(
+        "CLKout8_9_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x122, 5, 5)],
+    ),
+    Field::from(
+        "DCLK8_9_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x122, 4, 4)],
+    ),
+    Field::from(
+        "CLKout8_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x123, 5, 5)],
+    ),
+    Field::from(
+        "DCLK8_9_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x123, 4, 4)],
+    ),
+    Field::from(
+        "DCLK8_9_BYP",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x123, 3, 3)],
+    ),
+    Field::from(
+        "DCLK8_9_DCC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x123, 2, 2)],
+    ),
+    Field::from(
+        "DCLK8_9_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x123, 1, 1)],
+    ),
+    Field::from(
+        "DCLK8_9_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x123, 0, 0)],
+    ),
+    Field::from(
+        "CLKout9_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x124, 5, 5)],
+    ),
+    Field::from(
+        "SCLK8_9_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x124, 4, 4)],
+    ),
+    Field::from(
+        "SCLK8_9_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x124, 3, 2)],
+    ),
+    Field::from(
+        "SCLK8_9_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x124, 1, 1)],
+    ),
+    Field::from(
+        "SCLK8_9_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x124, 0, 0)],
+    ),
+    Field::from(
+        "SCLK8_9_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x125, 5, 5)],
+    ),
+    Field::from(
+        "SCLK8_9_ADLY",
+        Access::ReadWrite,
+        0x12,
+        &[BitRange::from(0x125, 4, 0)],
+    ),
+    Field::from(
+        "SCLK8_9_DDLY",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(0x126, 3, 0)],
+    ),
+    Field::from(
+        "CLKout9_FMT",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x127, 7, 4)],
+    ),
+    Field::from(
+        "CLKout8_FMT",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x127, 3, 0)],
+    ),
+    Field::from(
+        "DCLK10_11_DIV",
+        Access::ReadWrite,
+        0x18,
+        &[BitRange::from(0x12A, 1, 0), BitRange::from(0x128, 7, 0)],
+    ),
+    Field::from(
+        "DCLK10_11_DDLY",
+        Access::ReadWrite,
+        0xA,
+        &[BitRange::from(0x12A, 3, 2), BitRange::from(0x129, 7, 0)],
+    ),
+    Field::from(
+        "CLKout10_11_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12A, 7, 7)],
+    ),
+    Field::from(
+        "CLKout10_11_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12A, 6, 6)],
+    ),
+    Field::from(
+        "CLKout10_11_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12A, 5, 5)],
+    ),
+    Field::from(
+        "DCLK10_11_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12A, 4, 4)],
+    ),
+    Field::from(
+        "CLKout10_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12B, 5, 5)],
+    ),
+    Field::from(
+        "DCLK10_11_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12B, 4, 4)],
+    ),
+    Field::from(
+        "DCLK10_11_BYP",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12B, 3, 3)],
+    ),
+    Field::from(
+        "DCLK10_11_DCC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12B, 2, 2)],
+    ),
+    Field::from(
+        "DCLK10_11_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12B, 1, 1)],
+    ),
+    Field::from(
+        "DCLK10_11_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12B, 0, 0)],
+    ),
+    Field::from(
+        "CLKout11_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12C, 5, 5)],
+    ),
+    Field::from(
+        "SCLK10_11_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x12C, 4, 4)],
+    ),
+    Field::from(
+        "SCLK10_11_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12C, 3, 2)],
+    ),
+    Field::from(
+        "SCLK10_11_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12C, 1, 1)],
+    ),
+    Field::from(
+        "SCLK10_11_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12C, 0, 0)],
+    ),
+    Field::from(
+        "SCLK10_11_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x12D, 5, 5)],
+    ),
+    Field::from(
+        "SCLK10_11_ADLY",
+        Access::ReadWrite,
+        0x12,
+        &[BitRange::from(0x12D, 4, 0)],
+    ),
+    Field::from(
+        "SCLK10_11_DDLY",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(0x12E, 3, 0)],
+    ),
+    Field::from(
+        "CLKout11_FMT",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x12F, 7, 4)],
+    ),
+    Field::from(
+        "CLKout10_FMT",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x12F, 3, 0)],
+    ),
+    Field::from(
+        "DCLK12_13_DIV",
+        Access::ReadWrite,
+        0x200,
+        &[BitRange::from(0x132, 1, 0), BitRange::from(0x130, 7, 0)],
+    ),
+    Field::from(
+        "DCLK12_13_DDLY",
+        Access::ReadWrite,
+        0xA,
+        &[BitRange::from(0x132, 3, 2), BitRange::from(0x131, 7, 0)],
+    ),
+    Field::from(
+        "CLKout12_13_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x132, 7, 7)],
+    ),
+    Field::from(
+        "CLKout12_13_ODL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x132, 6, 6)],
+    ),
+    Field::from(
+        "CLKout12_13_IDL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x132, 5, 5)],
+    ),
+    Field::from(
+        "DCLK12_13_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x132, 4, 4)],
+    ),
+    Field::from(
+        "CLKout12_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x133, 5, 5)],
+    ),
+    Field::from(
+        "DCLK12_13_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x133, 4, 4)],
+    ),
+    Field::from(
+        "DCLK12_13_BYP",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x133, 3, 3)],
+    ),
+    Field::from(
+        "DCLK12_13_DCC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x133, 2, 2)],
+    ),
+    Field::from(
+        "DCLK12_13_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x133, 1, 1)],
+    ),
+    Field::from(
+        "DCLK12_13_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x133, 0, 0)],
+    ),
+    Field::from(
+        "CLKout13_SRC_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x134, 5, 5)],
+    ),
+    Field::from(
+        "SCLK12_13_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x134, 4, 4)],
+    ),
+    Field::from(
+        "SCLK12_13_DIS_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x134, 3, 2)],
+    ),
+    Field::from(
+        "SCLK12_13_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x134, 1, 1)],
+    ),
+    Field::from(
+        "SCLK12_13_HS",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x134, 0, 0)],
+    ),
+    Field::from(
+        "SCLK12_13_ADLY_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x135, 5, 5)],
+    ),
+    Field::from(
+        "SCLK12_13_ADLY",
+        Access::ReadWrite,
+        0x12,
+        &[BitRange::from(0x135, 4, 0)],
+    ),
+    Field::from(
+        "SCLK12_13_DDLY",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(0x136, 3, 0)],
+    ),
+    Field::from(
+        "CLKout13_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x137, 7, 4)],
+    ),
+    Field::from(
+        "CLKout12_FMT",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x137, 3, 0)],
+    ),
+    Field::from(
+        "VCO_MUX",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x138, 6, 5)],
+    ),
+    Field::from(
+        "OSCout_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x138, 4, 4)],
+    ),
+    Field::from(
+        "OSCout_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x138, 3, 0)],
+    ),
+    Field::from(
+        "SYSREF_CLKin0_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x139, 4, 4)],
+    ),
+    Field::from(
+        "SYSREF_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x139, 1, 0)],
+    ),
+    Field::from(
+        "SYSREF_DIV",
+        Access::ReadWrite,
+        0xC00,
+        &[BitRange::from(0x13A, 4, 0), BitRange::from(0x13B, 7, 0)],
+    ),
+    Field::from(
+        "SYSREF_DDLY",
+        Access::ReadWrite,
+        0x8,
+        &[BitRange::from(0x13C, 4, 0), BitRange::from(0x13D, 7, 0)],
+    ),
+    Field::from(
+        "SYSREF_PULSE_CNT",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x13E, 1, 0)],
+    ),
+    Field::from(
+        "PLL2_RCLK_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x13F, 7, 7)],
+    ),
+    Field::from(
+        "PLL2_NCLK_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x13F, 5, 5)],
+    ),
+    Field::from(
+        "PLL1_NCLK_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x13F, 4, 3)],
+    ),
+    Field::from(
+        "FB_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x13F, 2, 1)],
+    ),
+    Field::from(
+        "FB_MUX_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x13F, 0, 0)],
+    ),
+    Field::from(
+        "PLL1_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 7, 7)],
+    ),
+    Field::from(
+        "VCO_LDO_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 6, 6)],
+    ),
+    Field::from(
+        "VCO_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 5, 5)],
+    ),
+    Field::from(
+        "OSCin_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 4, 4)],
+    ),
+    Field::from(
+        "SYSREF_GBL_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x140, 3, 3)],
+    ),
+    Field::from(
+        "SYSREF_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x140, 2, 2)],
+    ),
+    Field::from(
+        "SYSREF_DDLY_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x140, 1, 1)],
+    ),
+    Field::from(
+        "SYSREF_PLSR_PD",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x140, 0, 0)],
+    ),
+    Field::from(
+        "DDLYd_SYSREF_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 7, 7)],
+    ),
+    Field::from(
+        "DDLYd12_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 6, 6)],
+    ),
+    Field::from(
+        "DDLYd10_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 5, 5)],
+    ),
+    Field::from(
+        "DDLYd8_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 4, 4)],
+    ),
+    Field::from(
+        "DDLYd6_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 3, 3)],
+    ),
+    Field::from(
+        "DDLYd4_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 2, 2)],
+    ),
+    Field::from(
+        "DDLYd2_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 1, 1)],
+    ),
+    Field::from(
+        "DDLYd0_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x141, 0, 0)],
+    ),
+    Field::from(
+        "DDLYd_STEP_CNT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x142, 3, 0)],
+    ),
+    Field::from(
+        "SYSREF_CLR",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 7, 7)],
+    ),
+    Field::from(
+        "SYNC_1SHOT_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 6, 6)],
+    ),
+    Field::from(
+        "SYNC_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 5, 5)],
+    ),
+    Field::from(
+        "SYNC_EN",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x143, 4, 4)],
+    ),
+    Field::from(
+        "SYNC_PLL2_DLD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 3, 3)],
+    ),
+    Field::from(
+        "SYNC_PLL1_DLD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x143, 2, 2)],
+    ),
+    Field::from(
+        "SYNC_MODE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x143, 1, 0)],
+    ),
+    Field::from(
+        "SYNC_DISSYSREF",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 7, 7)],
+    ),
+    Field::from(
+        "SYNC_DIS12",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 6, 6)],
+    ),
+    Field::from(
+        "SYNC_DIS10",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 5, 5)],
+    ),
+    Field::from(
+        "SYNC_DIS8",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 4, 4)],
+    ),
+    Field::from(
+        "SYNC_DIS6",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 3, 3)],
+    ),
+    Field::from(
+        "SYNC_DIS4",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 2, 2)],
+    ),
+    Field::from(
+        "SYNC_DIS2",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 1, 1)],
+    ),
+    Field::from(
+        "SYNC_DIS0",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x144, 0, 0)],
+    ),
+    Field::from(
+        "CLKin2_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x146, 5, 5)],
+    ),
+    Field::from(
+        "CLKin1_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x146, 4, 4)],
+    ),
+    Field::from(
+        "CLKin0_EN",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x146, 3, 3)],
+    ),
+    Field::from(
+        "CLKin2_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x146, 2, 2)],
+    ),
+    Field::from(
+        "CLKin1_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x146, 1, 1)],
+    ),
+    Field::from(
+        "CLKin0_TYPE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x146, 0, 0)],
+    ),
+    Field::from(
+        "CLKin_SEL_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x147, 7, 7)],
+    ),
+    Field::from(
+        "CLKin_SEL_MODE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x147, 6, 4)],
+    ),
+    Field::from(
+        "CLKin1_OUT_MUX",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x147, 3, 2)],
+    ),
+    Field::from(
+        "CLKin0_OUT_MUX",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(0x147, 1, 0)],
+    ),
+    Field::from(
+        "CLKin_SEL0_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x148, 5, 3)],
+    ),
+    Field::from(
+        "CLKin_SEL0_TYPE",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x148, 2, 0)],
+    ),
+    Field::from(
+        "SDIO_RDBK_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x149, 6, 6)],
+    ),
+    Field::from(
+        "CLKin_SEL1_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x149, 5, 3)],
+    ),
+    Field::from(
+        "CLKin_SEL1_TYPE",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x149, 2, 0)],
+    ),
+    Field::from(
+        "RESET_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14A, 5, 3)],
+    ),
+    Field::from(
+        "RESET_TYPE",
+        Access::ReadWrite,
+        0x5,
+        &[BitRange::from(0x14A, 2, 0)],
+    ),
+    Field::from(
+        "LOS_TIMEOUT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14B, 7, 6)],
+    ),
+    Field::from(
+        "LOS_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14B, 5, 5)],
+    ),
+    Field::from(
+        "TRACK_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14B, 4, 4)],
+    ),
+    Field::from(
+        "HOLDOVER_FORCE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14B, 3, 3)],
+    ),
+    Field::from(
+        "MAN_DAC_EN",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x14B, 2, 2)],
+    ),
+    Field::from(
+        "MAN_DAC",
+        Access::ReadWrite,
+        0x200,
+        &[BitRange::from(0x14B, 1, 0), BitRange::from(0x14C, 7, 0)],
+    ),
+    Field::from(
+        "DAC_TRIP_LOW",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14D, 5, 0)],
+    ),
+    Field::from(
+        "DAC_CLK_MULT",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x14E, 7, 6)],
+    ),
+    Field::from(
+        "DAC_TRIP_HIGH",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x14E, 5, 0)],
+    ),
+    Field::from(
+        "DAC_CLK_CNTR",
+        Access::ReadWrite,
+        0x7F,
+        &[BitRange::from(0x14F, 7, 0)],
+    ),
+    Field::from(
+        "CLKin_OVERRIDE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 6, 6)],
+    ),
+    Field::from(
+        "HOLDOVER_PLL1_DET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 4, 4)],
+    ),
+    Field::from(
+        "HOLDOVER_LOS_DET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 3, 3)],
+    ),
+    Field::from(
+        "HOLDOVER_VTUNE_DET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 2, 2)],
+    ),
+    Field::from(
+        "HOLDOVER_HITLESS_SWITCH",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 1, 1)],
+    ),
+    Field::from(
+        "HOLDOVER_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x150, 0, 0)],
+    ),
+    Field::from(
+        "HOLDOVER_DLD_CNT",
+        Access::ReadWrite,
+        0x200,
+        &[BitRange::from(0x151, 5, 0), BitRange::from(0x152, 7, 0)],
+    ),
+    Field::from(
+        "CLKin0_R",
+        Access::ReadWrite,
+        0x78,
+        &[BitRange::from(0x153, 5, 0), BitRange::from(0x154, 7, 0)],
+    ),
+    Field::from(
+        "CLKin1_R",
+        Access::ReadWrite,
+        0x78,
+        &[BitRange::from(0x155, 5, 0), BitRange::from(0x156, 7, 0)],
+    ),
+    Field::from(
+        "CLKin2_R",
+        Access::ReadWrite,
+        0x96,
+        &[BitRange::from(0x157, 5, 0), BitRange::from(0x158, 7, 0)],
+    ),
+    Field::from(
+        "PLL1_N",
+        Access::ReadWrite,
+        0x4B0,
+        &[BitRange::from(0x159, 5, 0), BitRange::from(0x15A, 7, 0)],
+    ),
+    Field::from(
+        "PLL1_WND_SIZE",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x15B, 7, 6)],
+    ),
+    Field::from(
+        "PLL1_CP_TRI",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x15B, 5, 5)],
+    ),
+    Field::from(
+        "PLL1_CP_POL",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x15B, 4, 4)],
+    ),
+    Field::from(
+        "PLL1_CP_GAIN",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(0x15B, 3, 0)],
+    ),
+    Field::from(
+        "PLL1_DLD_CNT",
+        Access::ReadWrite,
+        0x2000,
+        &[BitRange::from(0x15C, 5, 0), BitRange::from(0x15D, 7, 0)],
+    ),
+    Field::from(
+        "PLL1_R_DLY",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x15E, 5, 3)],
+    ),
+    Field::from(
+        "PLL1_N_DLY",
+        Access::ReadWrite,
+        0x6,
+        &[BitRange::from(0x15E, 2, 0)],
+    ),
+    Field::from(
+        "PLL1_LD_MUX",
+        Access::ReadWrite,
+        0x7,
+        &[BitRange::from(0x15F, 7, 3)],
+    ),
+    Field::from(
+        "PLL1_LD_TYPE",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x15F, 2, 0)],
+    ),
+    Field::from(
+        "PLL2_R",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(0x160, 3, 0), BitRange::from(0x161, 7, 0)],
+    ),
+    Field::from(
+        "PLL2_P",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x162, 7, 5)],
+    ),
+    Field::from(
+        "OSCin_FREQ",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x162, 4, 2)],
+    ),
+    Field::from(
+        "PLL2_XTAL_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x162, 1, 1)],
+    ),
+    Field::from(
+        "PLL2_REF_2X_EN",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0x162, 0, 0)],
+    ),
+    Field::from(
+        "PLL2_N_CAL",
+        Access::ReadWrite,
+        0xC,
+        &[
+            BitRange::from(0x163, 1, 0),
+            BitRange::from(0x164, 7, 0),
+            BitRange::from(0x165, 7, 0),
+        ],
+    ),
+    Field::from(
+        "PLL2_N",
+        Access::ReadWrite,
+        0xC,
+        &[
+            BitRange::from(0x166, 1, 0),
+            BitRange::from(0x167, 7, 0),
+            BitRange::from(0x168, 7, 0),
+        ],
+    ),
+    Field::from(
+        "PLL2_WND_SIZE",
+        Access::ReadWrite,
+        0x2,
+        &[BitRange::from(0x169, 6, 5)],
+    ),
+    Field::from(
+        "PLL2_CP_GAIN",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x169, 4, 3)],
+    ),
+    Field::from(
+        "PLL2_CP_POL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x169, 2, 2)],
+    ),
+    Field::from(
+        "PLL2_CP_TRI",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x169, 1, 1)],
+    ),
+    Field::from(
+        "SYSREF_REQ_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16A, 6, 6)],
+    ),
+    Field::from(
+        "PLL2_DLD_CNT",
+        Access::ReadWrite,
+        0x2000,
+        &[BitRange::from(0x16A, 5, 0), BitRange::from(0x16B, 7, 0)],
+    ),
+    Field::from(
+        "PLL2_LF_R4",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16C, 5, 3)],
+    ),
+    Field::from(
+        "PLL2_LF_R3",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16C, 2, 0)],
+    ),
+    Field::from(
+        "PLL2_LF_C4",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16D, 7, 4)],
+    ),
+    Field::from(
+        "PLL2_LF_C3",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x16D, 3, 0)],
+    ),
+    Field::from(
+        "PLL2_LD_MUX",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x16E, 7, 3)],
+    ),
+    Field::from(
+        "PLL2_LD_TYPE",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(0x16E, 2, 0)],
+    ),
+    Field::from(
+        "PLL2_PRE_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x173, 6, 6)],
+    ),
+    Field::from(
+        "PLL2_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x173, 5, 5)],
+    ),
+    Field::from(
+        "PLL1R_SYNC_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x177, 5, 5)],
+    ),
+    Field::from(
+        "PLL1R_SYNC_SRC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x177, 4, 3)],
+    ),
+    Field::from(
+        "PLL2R_SYNC_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x177, 2, 2)],
+    ),
+    Field::from(
+        "RB_PLL1_LD_LOST",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x182, 2, 2)],
+    ),
+    Field::from(
+        "RB_PLL1_LD",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x182, 1, 1)],
+    ),
+    Field::from(
+        "CLR_PLL1_LD_LOST",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x182, 0, 0)],
+    ),
+    Field::from(
+        "RB_PLL2_LD_LOST",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x183, 2, 2)],
+    ),
+    Field::from(
+        "RB_PLL2_LD",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x183, 1, 1)],
+    ),
+    Field::from(
+        "CLR_PLL2_LD_LOST",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x183, 0, 0)],
+    ),
+    Field::from(
+        "RB_DAC_VALUE",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 7, 6), BitRange::from(0x185, 7, 0)],
+    ),
+    Field::from(
+        "RB_CLKin2_SEL",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 5, 5)],
+    ),
+    Field::from(
+        "RB_CLKin1_SEL",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 4, 4)],
+    ),
+    Field::from(
+        "RB_CLKin0_SEL",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 3, 3)],
+    ),
+    Field::from(
+        "RB_CLKin1_LOS",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 1, 1)],
+    ),
+    Field::from(
+        "RB_CLKin0_LOS",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x184, 0, 0)],
+    ),
+    Field::from(
+        "RB_HOLDOVER",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(0x188, 4, 4)],
+    ),
+    Field::from(
+        "SPI_LOCK",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0x555, 7, 0)],
+    ),
+];
+
+/// Registers 0x000 to 0x555 of the LMK04832 (TI SNAS688), the data byte of
+/// each word. Registers missing from a set start out from `TEMPLATE`.
+pub static REGISTER_MAP: RegisterMap =
+    RegisterMap::from_template(Chip::LMK04832, &TEMPLATE, FIELDS);
@@ -0,0 +1,593 @@
+use super::{Access, BitRange, Field, FieldName, RegisterMap};
+use crate::Chip;
+
+// fields the planner and `describe` use
+pub const FCAL_HPFD_ADJ: FieldName = FieldName::from("FCAL_HPFD_ADJ");
+pub const FCAL_LPFD_ADJ: FieldName = FieldName::from("FCAL_LPFD_ADJ");
+pub const CAL_CLK_DIV: FieldName = FieldName::from("CAL_CLK_DIV");
+pub const OSC_2X: FieldName = FieldName::from("OSC_2X");
+pub const MULT: FieldName = FieldName::from("MULT");
+pub const PLL_R_PRE: FieldName = FieldName::from("PLL_R_PRE");
+pub const PLL_R: FieldName = FieldName::from("PLL_R");
+pub const PLL_N: FieldName = FieldName::from("PLL_N");
+pub const PFD_DLY_SEL: FieldName = FieldName::from("PFD_DLY_SEL");
+pub const PLL_DEN: FieldName = FieldName::from("PLL_DEN");
+pub const PLL_NUM: FieldName = FieldName::from("PLL_NUM");
+pub const MASH_ORDER: FieldName = FieldName::from("MASH_ORDER");
+pub const CHDIV: FieldName = FieldName::from("CHDIV");
+pub const CHDIV_DIV2: FieldName = FieldName::from("CHDIV_DIV2");
+pub const OUTA_PD: FieldName = FieldName::from("OUTA_PD");
+pub const OUTA_MUX: FieldName = FieldName::from("OUTA_MUX");
+pub const OUTA_PWR: FieldName = FieldName::from("OUTA_PWR");
+pub const OUTB_PD: FieldName = FieldName::from("OUTB_PD");
+pub const OUTB_MUX: FieldName = FieldName::from("OUTB_MUX");
+pub const OUTB_PWR: FieldName = FieldName::from("OUTB_PWR");
+pub const SYSREF_EN: FieldName = FieldName::from("SYSREF_EN");
+pub const SYSREF_REPEAT: FieldName = FieldName::from("SYSREF_REPEAT");
+pub const SYSREF_PULSE: FieldName = FieldName::from("SYSREF_PULSE");
+pub const SYSREF_DIV: FieldName = FieldName::from("SYSREF_DIV");
+pub const POWERDOWN: FieldName = FieldName::from("POWERDOWN");
+
+/// Register words after reset, R0 to R112, as listed by TICS Pro.
+const RESET: [u32; 113] = [
+    0x002414, 0x010808, 0x020500, 0x030642, 0x040A43, 0x0500C8, 0x06C802, 0x0740B2, 0x082000,
+    0x090604, 0x0A10D8, 0x0B0018, 0x0C5001, 0x0D4000, 0x0E1E70, 0x0F064F, 0x100080, 0x11012C,
+    0x120064, 0x1327B7, 0x14E048, 0x150401, 0x160001, 0x17007C, 0x18071A, 0x190624, 0x1A0DB0,
+    0x1B0002, 0x1C0488, 0x1D318C, 0x1E318C, 0x1F43EC, 0x200393, 0x211E21, 0x220000, 0x230004,
+    0x240064, 0x250404, 0x260000, 0x2703E8, 0x280000, 0x290000, 0x2A0000, 0x2B0000, 0x2C1F23,
+    0x2DC0DF, 0x2E07FC, 0x2F0300, 0x300300, 0x314180, 0x320000, 0x330080, 0x340820, 0x350000,
+    0x360000, 0x370000, 0x380000, 0x390020, 0x3A9001, 0x3B0001, 0x3C0000, 0x3D00A8, 0x3E0322,
+    0x3F0000, 0x401388, 0x410000, 0x4201F4, 0x430000, 0x4403E8, 0x450000, 0x46C350, 0x470081,
+    0x480001, 0x49003F, 0x4A0000, 0x4B0800, 0x4C000C, 0x4D0000, 0x4E0003, 0x4F0026, 0x506666,
+    0x510000, 0x521E00, 0x530000, 0x540001, 0x55D300, 0x560000, 0x570000, 0x580000, 0x590000,
+    0x5A0000, 0x5B0000, 0x5C0000, 0x5D0000, 0x5E0000, 0x5F0000, 0x600000, 0x610888, 0x620200,
+    0x630000, 0x640000, 0x650011, 0x663F80, 0x670000, 0x680000, 0x690021, 0x6A0000, 0x6B0000,
+    0x6C0000, 0x6D0000, 0x6E0000, 0x6F0000, 0x700000,
+];
+
+/// Every field, ordered by register. Fields spread over several registers
+/// list their most significant part first, e.g. PLL_N is R34[2:0] followed
+/// by R36[15:0].
+const FIELDS: &[Field] = &[
+    Field::from(
+        "RAMP_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 15, 15)],
+    ),
+    Field::from(
+        "VCO_PHASE_SYNC",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 14, 14)],
+    ),
+    Field::from(
+        "OUT_MUTE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 9, 9)],
+    ),
+    Field::from(
+        "FCAL_HPFD_ADJ",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 8, 7)],
+    ),
+    Field::from(
+        "FCAL_LPFD_ADJ",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 6, 5)],
+    ),
+    Field::from(
+        "FCAL_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 3, 3)],
+    ),
+    Field::from(
+        "MUXOUT_LD_SEL",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(0, 2, 2)],
+    ),
+    Field::from("RESET", Access::ReadWrite, 0x0, &[BitRange::from(0, 1, 1)]),
+    Field::from(
+        "POWERDOWN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(0, 0, 0)],
+    ),
+    Field::from(
+        "CAL_CLK_DIV",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(1, 2, 0)],
+    ),
+    Field::from(
+        "ACAL_CMP_DLY",
+        Access::ReadWrite,
+        0xA,
+        &[BitRange::from(4, 15, 8)],
+    ),
+    Field::from(
+        "OUT_FORCE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(7, 14, 14)],
+    ),
+    Field::from(
+        "VCO_DACISET_FORCE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(8, 14, 14)],
+    ),
+    Field::from(
+        "VCO_CAPCTRL_FORCE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(8, 11, 11)],
+    ),
+    Field::from(
+        "OSC_2X",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(9, 12, 12)],
+    ),
+    Field::from("MULT", Access::ReadWrite, 0x1, &[BitRange::from(10, 11, 7)]),
+    Field::from(
+        "PLL_R",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(11, 11, 4)],
+    ),
+    Field::from(
+        "PLL_R_PRE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(12, 11, 0)],
+    ),
+    Field::from("CPG", Access::ReadWrite, 0x7, &[BitRange::from(14, 6, 4)]),
+    Field::from(
+        "VCO_DACISET",
+        Access::ReadWrite,
+        0x80,
+        &[BitRange::from(16, 8, 0)],
+    ),
+    Field::from(
+        "VCO_DACISET_STRT",
+        Access::ReadWrite,
+        0x12C,
+        &[BitRange::from(17, 8, 0)],
+    ),
+    Field::from(
+        "VCO_CAPCTRL",
+        Access::ReadWrite,
+        0xB7,
+        &[BitRange::from(19, 7, 0)],
+    ),
+    Field::from(
+        "VCO_SEL",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(20, 13, 11)],
+    ),
+    Field::from(
+        "VCO_SEL_FORCE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(20, 10, 10)],
+    ),
+    Field::from(
+        "CHDIV_DIV2",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(31, 14, 14)],
+    ),
+    Field::from(
+        "PLL_N",
+        Access::ReadWrite,
+        0x64,
+        &[BitRange::from(34, 2, 0), BitRange::from(36, 15, 0)],
+    ),
+    Field::from(
+        "MASH_SEED_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(37, 15, 15)],
+    ),
+    Field::from(
+        "PFD_DLY_SEL",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(37, 13, 8)],
+    ),
+    Field::from(
+        "PLL_DEN",
+        Access::ReadWrite,
+        0x3E8,
+        &[BitRange::from(38, 15, 0), BitRange::from(39, 15, 0)],
+    ),
+    Field::from(
+        "MASH_SEED",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(40, 15, 0), BitRange::from(41, 15, 0)],
+    ),
+    Field::from(
+        "PLL_NUM",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(42, 15, 0), BitRange::from(43, 15, 0)],
+    ),
+    Field::from(
+        "OUTA_PWR",
+        Access::ReadWrite,
+        0x1F,
+        &[BitRange::from(44, 13, 8)],
+    ),
+    Field::from(
+        "OUTB_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(44, 7, 7)],
+    ),
+    Field::from(
+        "OUTA_PD",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(44, 6, 6)],
+    ),
+    Field::from(
+        "MASH_RESET_N",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(44, 5, 5)],
+    ),
+    Field::from(
+        "MASH_ORDER",
+        Access::ReadWrite,
+        0x3,
+        &[BitRange::from(44, 2, 0)],
+    ),
+    Field::from(
+        "OUTA_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(45, 12, 11)],
+    ),
+    Field::from(
+        "OUT_ISET",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(45, 10, 9)],
+    ),
+    Field::from(
+        "OUTB_PWR",
+        Access::ReadWrite,
+        0x1F,
+        &[BitRange::from(45, 5, 0)],
+    ),
+    Field::from(
+        "OUTB_MUX",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(46, 1, 0)],
+    ),
+    Field::from(
+        "INPIN_IGNORE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(58, 15, 15)],
+    ),
+    Field::from(
+        "INPIN_HYST",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(58, 14, 14)],
+    ),
+    Field::from(
+        "INPIN_LVL",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(58, 13, 12)],
+    ),
+    Field::from(
+        "INPIN_FMT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(58, 11, 9)],
+    ),
+    Field::from(
+        "LD_TYPE",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(59, 0, 0)],
+    ),
+    Field::from(
+        "LD_DLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(60, 15, 0)],
+    ),
+    Field::from(
+        "ACAL_FAST",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(64, 9, 9)],
+    ),
+    Field::from(
+        "FCAL_FAST",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(64, 8, 8)],
+    ),
+    Field::from(
+        "MASH_RST_COUNT",
+        Access::ReadWrite,
+        0xC350,
+        &[BitRange::from(69, 15, 0), BitRange::from(70, 15, 0)],
+    ),
+    Field::from(
+        "SYSREF_DIV_PRE",
+        Access::ReadWrite,
+        0x4,
+        &[BitRange::from(71, 7, 5)],
+    ),
+    Field::from(
+        "SYSREF_PULSE",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(71, 4, 4)],
+    ),
+    Field::from(
+        "SYSREF_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(71, 3, 3)],
+    ),
+    Field::from(
+        "SYSREF_REPEAT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(71, 2, 2)],
+    ),
+    Field::from(
+        "SYSREF_DIV",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(72, 10, 0)],
+    ),
+    Field::from(
+        "JESD_DAC2_CTRL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(73, 11, 6)],
+    ),
+    Field::from(
+        "JESD_DAC1_CTRL",
+        Access::ReadWrite,
+        0x3F,
+        &[BitRange::from(73, 5, 0)],
+    ),
+    Field::from(
+        "SYSREF_PULSE_CNT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(74, 15, 12)],
+    ),
+    Field::from(
+        "JESD_DAC4_CTRL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(74, 11, 6)],
+    ),
+    Field::from(
+        "JESD_DAC3_CTRL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(74, 5, 0)],
+    ),
+    Field::from(
+        "CHDIV",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(75, 10, 6)],
+    ),
+    Field::from(
+        "QUICK_RECAL_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(78, 11, 11)],
+    ),
+    Field::from(
+        "VCO_CAPCTRL_STRT",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(78, 8, 1)],
+    ),
+    Field::from(
+        "RAMP_THRESH",
+        Access::ReadWrite,
+        0x266666,
+        &[
+            BitRange::from(78, 9, 9),
+            BitRange::from(79, 15, 0),
+            BitRange::from(80, 15, 0),
+        ],
+    ),
+    Field::from(
+        "RAMP_LIMIT_HIGH",
+        Access::ReadWrite,
+        0x1E000000,
+        &[
+            BitRange::from(81, 0, 0),
+            BitRange::from(82, 15, 0),
+            BitRange::from(83, 15, 0),
+        ],
+    ),
+    Field::from(
+        "RAMP_LIMIT_LOW",
+        Access::ReadWrite,
+        0x1D3000000,
+        &[
+            BitRange::from(84, 0, 0),
+            BitRange::from(85, 15, 0),
+            BitRange::from(86, 15, 0),
+        ],
+    ),
+    Field::from(
+        "RAMP_BURST_EN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(96, 15, 15)],
+    ),
+    Field::from(
+        "RAMP_BURST_COUNT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(96, 14, 2)],
+    ),
+    Field::from(
+        "RAMP0_RST",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(97, 15, 15)],
+    ),
+    Field::from(
+        "RAMP_TRIGA",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(97, 10, 7)],
+    ),
+    Field::from(
+        "RAMP_TRIGB",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(97, 6, 3)],
+    ),
+    Field::from(
+        "RAMP_BURST_TRIG",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(97, 1, 0)],
+    ),
+    Field::from(
+        "RAMP0_INC",
+        Access::ReadWrite,
+        0x800000,
+        &[BitRange::from(98, 15, 2), BitRange::from(99, 15, 0)],
+    ),
+    Field::from(
+        "RAMP0_DLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(98, 0, 0)],
+    ),
+    Field::from(
+        "RAMP0_LEN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(100, 15, 0)],
+    ),
+    Field::from(
+        "RAMP1_DLY",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(101, 6, 6)],
+    ),
+    Field::from(
+        "RAMP1_RST",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(101, 5, 5)],
+    ),
+    Field::from(
+        "RAMP0_NEXT",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(101, 4, 4)],
+    ),
+    Field::from(
+        "RAMP0_NEXT_TRIG",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(101, 1, 0)],
+    ),
+    Field::from(
+        "RAMP1_INC",
+        Access::ReadWrite,
+        0x3F800000,
+        &[BitRange::from(102, 13, 0), BitRange::from(103, 15, 0)],
+    ),
+    Field::from(
+        "RAMP1_LEN",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(104, 15, 0)],
+    ),
+    Field::from(
+        "RAMP_DLY_CNT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(105, 15, 6)],
+    ),
+    Field::from(
+        "RAMP_MANUAL",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(105, 5, 5)],
+    ),
+    Field::from(
+        "RAMP1_NEXT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(105, 4, 4)],
+    ),
+    Field::from(
+        "RAMP1_NEXT_TRIG",
+        Access::ReadWrite,
+        0x1,
+        &[BitRange::from(105, 1, 0)],
+    ),
+    Field::from(
+        "RAMP_TRIG_CAL",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(106, 4, 4)],
+    ),
+    Field::from(
+        "RAMP_SCALE_COUNT",
+        Access::ReadWrite,
+        0x0,
+        &[BitRange::from(106, 2, 0)],
+    ),
+    Field::from(
+        "rb_LD_VTUNE",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(110, 10, 9)],
+    ),
+    Field::from(
+        "rb_VCO_SEL",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(110, 7, 5)],
+    ),
+    Field::from(
+        "rb_VCO_CAPCTRL",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(111, 7, 0)],
+    ),
+    Field::from(
+        "rb_VCO_DACISET",
+        Access::ReadOnly,
+        0x0,
+        &[BitRange::from(112, 8, 0)],
+    ),
+];
+
+/// R0 to R112 of the LMX2594 (TI SNAS696). Bits that are not part of a
+/// field are fixed and keep their value from `RESET`.
+pub static REGISTER_MAP: RegisterMap = RegisterMap::from(Chip::LMX2594, &RESET, FIELDS);
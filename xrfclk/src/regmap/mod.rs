@@ -0,0 +1,714 @@
+pub mod lmk04208;
+pub mod lmk04828;
+pub mod lmk04832;
+pub mod lmx2594;
+
+use crate::config::{word_address, RegisterSet};
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::planner::set_field;
+use crate::Chip;
+use tracing::debug;
+
+/// How address, data and the R/W bit are laid out in the SPI word of a
+/// chip. Register words in this crate always have the address embedded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordFormat {
+    /// bit that turns the word into a read, `None` if the chip is read
+    /// through a readback register instead
+    pub read_bit: Option<u32>,
+    pub address_shift: u32,
+    pub address_bits: u32,
+    pub data_shift: u32,
+    pub data_bits: u32,
+}
+
+impl WordFormat {
+    /// 24 bits: R/W, 7-bit address, 16 data bits.
+    pub const LMX2594: Self = Self {
+        read_bit: Some(23),
+        address_shift: 16,
+        address_bits: 7,
+        data_shift: 0,
+        data_bits: 16,
+    };
+    /// 24 bits: R/W, W1:W0 and the 13-bit address make up a 15-bit address
+    /// field, followed by 8 data bits.
+    pub const LMK0482X: Self = Self {
+        read_bit: Some(23),
+        address_shift: 8,
+        address_bits: 15,
+        data_shift: 0,
+        data_bits: 8,
+    };
+    /// 32-bit uWire word: 27 data bits above a 5-bit address.
+    pub const LMK04208: Self = Self {
+        read_bit: None,
+        address_shift: 0,
+        address_bits: 5,
+        data_shift: 5,
+        data_bits: 27,
+    };
+
+    pub fn of(chip: Chip) -> Self {
+        match chip {
+            Chip::LMX2594 => Self::LMX2594,
+            Chip::LMK04828 | Chip::LMK04832 => Self::LMK0482X,
+            Chip::LMK04208 => Self::LMK04208,
+        }
+    }
+
+    /// Data bits in word bit positions.
+    pub fn data_mask(&self) -> u32 {
+        (((1u64 << self.data_bits) - 1) as u32) << self.data_shift
+    }
+
+    /// Address bits in word bit positions.
+    pub fn address_mask(&self) -> u32 {
+        ((1 << self.address_bits) - 1) << self.address_shift
+    }
+
+    /// The word writing `data` to `address`, `data` in word bit positions.
+    pub fn word(&self, address: u16, data: u32) -> u32 {
+        ((address as u32) << self.address_shift) & self.address_mask() | data & self.data_mask()
+    }
+
+    pub fn address(&self, word: u32) -> u16 {
+        ((word & self.address_mask()) >> self.address_shift) as u16
+    }
+
+    pub fn data(&self, word: u32) -> u32 {
+        word & self.data_mask()
+    }
+}
+
+/// Whether a field can be programmed or only read back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    ReadWrite,
+    ReadOnly,
+}
+
+/// Bits `msb..=lsb` of the register word at `address`. Bit positions count
+/// within the SPI word, like the register values of the config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitRange {
+    pub address: u16,
+    pub msb: u8,
+    pub lsb: u8,
+}
+
+impl BitRange {
+    pub const fn from(address: u16, msb: u8, lsb: u8) -> Self {
+        Self { address, msb, lsb }
+    }
+
+    pub fn width(&self) -> u32 {
+        (self.msb - self.lsb + 1) as u32
+    }
+
+    /// The bits of the range in word bit positions.
+    pub fn mask(&self) -> u32 {
+        (((1u64 << self.width()) - 1) as u32) << self.lsb
+    }
+
+    fn extract(&self, word: u32) -> u64 {
+        ((word >> self.lsb) as u64) & ((1 << self.width()) - 1)
+    }
+}
+
+/// A named field of a register map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field {
+    /// name as in the datasheet, e.g. `PLL_N`
+    pub name: &'static str,
+    pub access: Access,
+    /// value in the initial words of the map, see `RegisterMap`
+    pub initial: u64,
+    /// the parts of the field, most significant part first
+    pub ranges: &'static [BitRange],
+}
+
+impl Field {
+    pub const fn from(
+        name: &'static str,
+        access: Access,
+        initial: u64,
+        ranges: &'static [BitRange],
+    ) -> Self {
+        Self {
+            name,
+            access,
+            initial,
+            ranges,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.ranges.iter().map(BitRange::width).sum()
+    }
+
+    /// Largest value the field holds.
+    pub fn max(&self) -> u64 {
+        (1 << self.width()) - 1
+    }
+
+    /// The value of the field in `registers`, `None` if one of its
+    /// registers is missing.
+    pub fn read(&self, registers: &RegisterSet) -> Option<u64> {
+        self.ranges.iter().try_fold(0, |value, range| {
+            let word = registers.get(range.address)?;
+            Some((value << range.width()) | range.extract(word))
+        })
+    }
+}
+
+/// The name of a field, for code that knows which fields it works with.
+/// The chip modules name the fields the planners and `describe` use, e.g.
+/// `lmx2594::PLL_N`, so a misspelt name does not compile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldName(&'static str);
+
+impl FieldName {
+    pub const fn from(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+/// Every field of a chip along with the register words fields start out
+/// from when their register is missing. These initial words are the
+/// register values after reset where the datasheet lists them, otherwise
+/// a known good profile. The LMK maps start out from a profile: their
+/// reserved bits are not known and `validate` does not check them.
+#[derive(Debug)]
+pub struct RegisterMap {
+    chip: Chip,
+    /// full register words, address embedded
+    initial: &'static [u32],
+    /// whether `initial` holds the values after reset
+    reset_values: bool,
+    fields: &'static [Field],
+}
+
+impl RegisterMap {
+    /// A map whose initial words are the register values after reset.
+    pub const fn from(chip: Chip, reset: &'static [u32], fields: &'static [Field]) -> Self {
+        Self {
+            chip,
+            initial: reset,
+            reset_values: true,
+            fields,
+        }
+    }
+
+    /// A map that starts out from the words of a profile, the values after
+    /// reset are unknown.
+    pub const fn from_template(
+        chip: Chip,
+        template: &'static [u32],
+        fields: &'static [Field],
+    ) -> Self {
+        Self {
+            chip,
+            initial: template,
+            reset_values: false,
+            fields,
+        }
+    }
+
+    pub fn chip(&self) -> Chip {
+        self.chip
+    }
+
+    pub fn fields(&self) -> &'static [Field] {
+        self.fields
+    }
+
+    /// The field called `name`, case does not matter.
+    pub fn field(&self, name: &str) -> Option<&'static Field> {
+        self.fields
+            .iter()
+            .find(|field| field.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The fields that have bits in register `address`.
+    pub fn fields_of(&self, address: u16) -> impl Iterator<Item = &'static Field> + '_ {
+        self.fields
+            .iter()
+            .filter(move |field| field.ranges.iter().any(|range| range.address == address))
+    }
+
+    /// Whether the initial words are the register values after reset.
+    pub fn has_reset_values(&self) -> bool {
+        self.reset_values
+    }
+
+    pub fn initial_word(&self, address: u16) -> Option<u32> {
+        self.initial
+            .iter()
+            .copied()
+            .find(|word| word_address(self.chip, *word) == address)
+    }
+
+    pub fn format(&self) -> WordFormat {
+        WordFormat::of(self.chip)
+    }
+
+    /// Bits of register `address` that are not part of any field. With
+    /// reset values known they have to keep them.
+    pub fn fixed_bits(&self, address: u16) -> u32 {
+        let field_bits = self
+            .fields_of(address)
+            .flat_map(|field| field.ranges)
+            .filter(|range| range.address == address)
+            .fold(0, |bits, range| bits | range.mask());
+
+        self.format().data_mask() & !field_bits
+    }
+
+    /// Checks that every register of `registers` exists on the chip and,
+    /// if the map has the reset values, keeps the reserved bits at them.
+    /// Without reset values, as for the LMK maps, reserved bits may hold
+    /// anything.
+    pub fn validate(&self, registers: &RegisterSet) -> Result<(), XRFClkError> {
+        let invalid = || XRFClkError::from(XRFClkErrorKind::InvalidRegister).with_chip(self.chip);
+
+        if registers.chip() != self.chip {
+            return Err(invalid());
+        }
+
+        for (address, word) in registers.iter() {
+            let Some(initial) = self.initial_word(address) else {
+                debug!("{} has no register R{address}", self.chip);
+                return Err(invalid());
+            };
+            if !self.reset_values {
+                continue;
+            }
+            let fixed = self.fixed_bits(address);
+
+            if (word ^ initial) & fixed != 0 {
+                debug!(
+                    "R{address} = {word:#08X} of {} changes reserved bits {:#08X}",
+                    self.chip,
+                    (word ^ initial) & fixed
+                );
+                return Err(invalid());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Every field whose registers are all part of `registers`, with its
+    /// value.
+    pub fn decode(&self, registers: &RegisterSet) -> Vec<(&'static Field, u64)> {
+        self.fields
+            .iter()
+            .filter_map(|field| Some((field, field.read(registers)?)))
+            .collect()
+    }
+
+    /// Every register with its initial word.
+    pub fn initial_registers(&self) -> RegisterSet {
+        let mut registers = RegisterSet::new(self.chip);
+
+        for word in self.initial {
+            let _ = registers.insert(word_address(self.chip, *word), *word);
+        }
+
+        registers
+    }
+}
+
+/// The register map of `chip`.
+pub fn register_map(chip: Chip) -> &'static RegisterMap {
+    match chip {
+        Chip::LMX2594 => &lmx2594::REGISTER_MAP,
+        Chip::LMK04828 => &lmk04828::REGISTER_MAP,
+        Chip::LMK04832 => &lmk04832::REGISTER_MAP,
+        Chip::LMK04208 => &lmk04208::REGISTER_MAP,
+    }
+}
+
+fn lookup_field(
+    chip: Chip,
+    name: &str,
+) -> Result<(&'static RegisterMap, &'static Field), XRFClkError> {
+    let map = register_map(chip);
+    let field = map
+        .field(name)
+        .ok_or_else(|| XRFClkError::from(XRFClkErrorKind::InvalidRegister).with_chip(chip))?;
+
+    Ok((map, field))
+}
+
+impl RegisterSet {
+    /// The value of the field `name`, e.g. `PLL_N` or `OUTA_PWR`.
+    pub fn field(&self, name: &str) -> Result<u64, XRFClkError> {
+        let (_, field) = lookup_field(self.chip(), name)?;
+
+        field.read(self).ok_or_else(|| {
+            XRFClkError::from(XRFClkErrorKind::MissingRegister).with_chip(self.chip())
+        })
+    }
+
+    /// Sets the field `name` to `value`. Registers that are not part of
+    /// the set yet are added with their initial word first.
+    pub fn set_field(&mut self, name: &str, value: u64) -> Result<(), XRFClkError> {
+        let chip = self.chip();
+        let (map, field) = lookup_field(chip, name)?;
+
+        if field.access == Access::ReadOnly {
+            return Err(XRFClkError::from(XRFClkErrorKind::UnsupportedOperation).with_chip(chip));
+        }
+        if value > field.max() {
+            return Err(XRFClkError::from(XRFClkErrorKind::InvalidRegister).with_chip(chip));
+        }
+
+        let mut shift = field.width();
+        for range in field.ranges {
+            shift -= range.width();
+
+            if self.get(range.address).is_none() {
+                if let Some(word) = map.initial_word(range.address) {
+                    self.insert(range.address, word)?;
+                }
+            }
+
+            set_field(
+                self,
+                range.address,
+                range.msb as u32,
+                range.lsb as u32,
+                (value >> shift) as u32,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Like `field`, for one of the names of the chip modules.
+    pub fn read_field(&self, name: FieldName) -> Result<u64, XRFClkError> {
+        self.field(name.as_str())
+    }
+
+    /// Like `set_field`, for one of the names of the chip modules.
+    pub fn write_field(&mut self, name: FieldName, value: u64) -> Result<(), XRFClkError> {
+        self.set_field(name.as_str(), value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{load_config_from_file, Frequency};
+
+    #[test]
+    fn maps_are_consistent() {
+        let config = load_config_from_file().unwrap();
+
+        for chip in [
+            Chip::LMX2594,
+            Chip::LMK04828,
+            Chip::LMK04832,
+            Chip::LMK04208,
+        ] {
+            let map = register_map(chip);
+            let format = map.format();
+            let initial = map.initial_registers();
+
+            for field in map.fields() {
+                assert_eq!(
+                    initial.field(field.name).unwrap(),
+                    field.initial,
+                    "{chip} {}",
+                    field.name
+                );
+            }
+
+            // fields do not overlap
+            for (address, _) in initial.iter() {
+                let bits: u32 = map
+                    .fields_of(address)
+                    .flat_map(|field| field.ranges)
+                    .filter(|range| range.address == address)
+                    .map(BitRange::width)
+                    .sum();
+                assert_eq!(
+                    bits,
+                    format.data_bits - map.fixed_bits(address).count_ones(),
+                    "{chip} R{address}"
+                );
+            }
+
+            // every profile keeps the fixed bits
+            for profile in config.profiles(chip) {
+                assert!(
+                    map.validate(&profile.registers).is_ok(),
+                    "{chip} {}",
+                    profile.frequency
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn field_names_exist() {
+        let names: [(Chip, Vec<FieldName>); 4] = [
+            (
+                Chip::LMX2594,
+                vec![
+                    lmx2594::FCAL_HPFD_ADJ,
+                    lmx2594::FCAL_LPFD_ADJ,
+                    lmx2594::CAL_CLK_DIV,
+                    lmx2594::OSC_2X,
+                    lmx2594::MULT,
+                    lmx2594::PLL_R_PRE,
+                    lmx2594::PLL_R,
+                    lmx2594::PLL_N,
+                    lmx2594::PFD_DLY_SEL,
+                    lmx2594::PLL_DEN,
+                    lmx2594::PLL_NUM,
+                    lmx2594::MASH_ORDER,
+                    lmx2594::CHDIV,
+                    lmx2594::CHDIV_DIV2,
+                    lmx2594::OUTA_PD,
+                    lmx2594::OUTA_MUX,
+                    lmx2594::OUTA_PWR,
+                    lmx2594::OUTB_PD,
+                    lmx2594::OUTB_MUX,
+                    lmx2594::OUTB_PWR,
+                    lmx2594::SYSREF_EN,
+                    lmx2594::SYSREF_REPEAT,
+                    lmx2594::SYSREF_PULSE,
+                    lmx2594::SYSREF_DIV,
+                    lmx2594::POWERDOWN,
+                ],
+            ),
+            (
+                Chip::LMK04828,
+                vec![
+                    lmk04828::VCO_MUX,
+                    lmk04828::CLKIN0_R,
+                    lmk04828::PLL1_N,
+                    lmk04828::PLL1_PD,
+                    lmk04828::PLL1_NCLK_MUX,
+                    lmk04828::FB_MUX,
+                    lmk04828::FB_MUX_EN,
+                    lmk04828::PLL2_REF_2X_EN,
+                    lmk04828::PLL2_R,
+                    lmk04828::PLL2_P,
+                    lmk04828::PLL2_N,
+                    lmk04828::PLL2_N_CAL,
+                    lmk04828::PLL2_PD,
+                    lmk04828::SYSREF_DIV,
+                    lmk04828::SYSREF_PD,
+                    lmk04828::SYSREF_MUX,
+                ]
+                .into_iter()
+                .chain(lmk04828::CLKOUT_PD)
+                .chain(lmk04828::DCLKOUT_DIV)
+                .chain(lmk04828::DCLKOUT_MUX)
+                .chain(lmk04828::DCLKOUT_FMT)
+                .chain(lmk04828::SDCLKOUT_MUX)
+                .chain(lmk04828::SDCLKOUT_PD)
+                .chain(lmk04828::SDCLKOUT_FMT)
+                .collect(),
+            ),
+            (
+                Chip::LMK04832,
+                vec![
+                    lmk04832::VCO_MUX,
+                    lmk04832::CLKIN0_R,
+                    lmk04832::PLL1_N,
+                    lmk04832::PLL1_PD,
+                    lmk04832::PLL1_NCLK_MUX,
+                    lmk04832::FB_MUX,
+                    lmk04832::FB_MUX_EN,
+                    lmk04832::PLL2_REF_2X_EN,
+                    lmk04832::PLL2_R,
+                    lmk04832::PLL2_P,
+                    lmk04832::PLL2_N,
+                    lmk04832::PLL2_N_CAL,
+                    lmk04832::PLL2_PD,
+                    lmk04832::SYSREF_DIV,
+                    lmk04832::SYSREF_PD,
+                    lmk04832::SYSREF_MUX,
+                ]
+                .into_iter()
+                .chain(lmk04832::CLKOUT_PD)
+                .chain(lmk04832::DCLK_DIV)
+                .chain(lmk04832::DCLK_BYP)
+                .chain(lmk04832::SCLK_PD)
+                .chain(lmk04832::CLKOUT_SRC_MUX)
+                .chain(lmk04832::CLKOUT_FMT)
+                .collect(),
+            ),
+            (
+                Chip::LMK04208,
+                vec![
+                    lmk04208::MODE,
+                    lmk04208::PLL1_R,
+                    lmk04208::PLL1_N,
+                    lmk04208::EN_PLL2_REF_2X,
+                    lmk04208::PLL2_R,
+                    lmk04208::PLL2_P,
+                    lmk04208::PLL2_N,
+                    lmk04208::POWERDOWN,
+                ]
+                .into_iter()
+                .chain(lmk04208::CLKOUT_DIV)
+                .chain(lmk04208::CLKOUT_TYPE)
+                .chain(lmk04208::CLKOUT_PD)
+                .collect(),
+            ),
+        ];
+
+        for (chip, names) in names {
+            let mut registers = register_map(chip).initial_registers();
+
+            // every name exists and can be written
+            for name in names {
+                let written = registers
+                    .read_field(name)
+                    .and_then(|value| registers.write_field(name, value));
+                assert!(written.is_ok(), "{chip} {name:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn lmk_profiles_are_decoded() {
+        let config = load_config_from_file().unwrap();
+        let registers = |chip, frequency| {
+            config
+                .registers(chip, Frequency::from_10khz(frequency))
+                .unwrap()
+        };
+
+        let lmk04828 = registers(Chip::LMK04828, 50025);
+        assert_eq!(lmk04828.field("PLL2_N").unwrap(), 75);
+        assert_eq!(lmk04828.field("DCLKout0_DIV").unwrap(), 6);
+        assert_eq!(lmk04828.field("SYSREF_DIV").unwrap(), 300);
+
+        let lmk04832 = registers(Chip::LMK04832, 12288);
+        assert_eq!(lmk04832.field("DCLK0_1_DIV").unwrap(), 192);
+        assert_eq!(lmk04832.field("DCLK12_13_DIV").unwrap(), 512);
+        assert_eq!(lmk04832.field("PLL2_REF_2X_EN").unwrap(), 1);
+
+        let lmk04208 = registers(Chip::LMK04208, 12288);
+        assert_eq!(lmk04208.field("PLL2_N").unwrap(), 25);
+        assert_eq!(lmk04208.field("PLL2_R").unwrap(), 2);
+        assert_eq!(lmk04208.field("PLL2_DLD_CNT").unwrap(), 8192);
+
+        let decoded = register_map(Chip::LMK04208).decode(lmk04208);
+        assert_eq!(decoded.len(), lmk04208::REGISTER_MAP.fields().len());
+    }
+
+    #[test]
+    fn lmk_register_sets_are_edited_and_validated() {
+        let config = load_config_from_file().unwrap();
+        let mut registers = config
+            .registers(Chip::LMK04208, Frequency::from_10khz(12288))
+            .cloned()
+            .unwrap();
+
+        // the address bits of the uWire word stay untouched
+        registers.set_field("CLKout2_DIV", 0x7FF).unwrap();
+        assert_eq!(registers.get(2), Some(0x0014FFE2));
+
+        // without the reset values reserved bits are not checked
+        let map = register_map(Chip::LMK04208);
+        assert!(!map.has_reset_values());
+        registers.insert(9, 0x55555569).unwrap();
+        assert!(map.validate(&registers).is_ok());
+
+        let mut registers = RegisterSet::new(Chip::LMK04828);
+        registers.set_field("RESET", 1).unwrap();
+        assert_eq!(registers.get(0), Some(0x000090));
+        registers.set_field("SPI_LOCK", 0x53).unwrap();
+        assert_eq!(registers.get(0x1FFF), Some(0x1FFF53));
+        assert_eq!(
+            registers.set_field("RB_PLL2_LD", 1).unwrap_err().kind(),
+            XRFClkErrorKind::UnsupportedOperation
+        );
+
+        let map = register_map(Chip::LMK04828);
+        assert!(map.validate(&registers).is_ok());
+        registers.insert(0x1000, 0x100000).unwrap();
+        assert!(map.validate(&registers).is_err());
+    }
+
+    #[test]
+    fn word_formats_match_the_config_words() {
+        for chip in [
+            Chip::LMX2594,
+            Chip::LMK04828,
+            Chip::LMK04832,
+            Chip::LMK04208,
+        ] {
+            let format = WordFormat::of(chip);
+
+            for word in register_map(chip).initial {
+                let address = format.address(*word);
+                assert_eq!(address, word_address(chip, *word));
+                assert_eq!(format.word(address, format.data(*word)), *word);
+            }
+        }
+
+        assert_eq!(WordFormat::LMK0482X.word(0x1FFF, 0x53), 0x1FFF53);
+        assert_eq!(WordFormat::LMK04208.data_mask(), 0xFFFF_FFE0);
+    }
+
+    #[test]
+    fn fields_are_set_by_name() {
+        let config = load_config_from_file().unwrap();
+        let mut registers = config
+            .registers(Chip::LMX2594, Frequency::from_10khz(40960))
+            .cloned()
+            .unwrap();
+
+        registers.set_field("PLL_N", 0x12345).unwrap();
+        registers.set_field("outa_pwr", 31).unwrap();
+
+        assert_eq!(registers.field("PLL_N").unwrap(), 0x12345);
+        assert_eq!(registers.get(34).unwrap() & 0x7, 0x1);
+        assert_eq!(registers.get(36), Some(0x242345));
+        assert_eq!(registers.field("OUTA_PWR").unwrap(), 31);
+
+        let kind = |result: Result<(), XRFClkError>| result.unwrap_err().kind();
+        assert_eq!(
+            kind(registers.set_field("OUTA_PWR", 64)),
+            XRFClkErrorKind::InvalidRegister
+        );
+        assert_eq!(
+            kind(registers.set_field("rb_LD_VTUNE", 2)),
+            XRFClkErrorKind::UnsupportedOperation
+        );
+        assert_eq!(
+            kind(registers.set_field("NO_SUCH_FIELD", 0)),
+            XRFClkErrorKind::InvalidRegister
+        );
+
+        // the LMX2594 map knows the reset values, reserved bits are fixed
+        let map = register_map(Chip::LMX2594);
+        assert!(map.has_reset_values());
+        assert!(map.validate(&registers).is_ok());
+        let r0 = registers.get(0).unwrap();
+        registers.insert(0, r0 ^ (1 << 4)).unwrap();
+        assert!(map.validate(&registers).is_err());
+
+        // registers missing from the set start out at their reset value
+        let mut registers = RegisterSet::new(Chip::LMX2594);
+        registers.set_field("CHDIV", 3).unwrap();
+        assert_eq!(registers.get(75), Some(0x4B08C0));
+        assert_eq!(
+            registers.field("PLL_N").unwrap_err().kind(),
+            XRFClkErrorKind::MissingRegister
+        );
+    }
+}
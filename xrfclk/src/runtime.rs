@@ -0,0 +1,34 @@
+use crate::error::XRFClkError;
+
+/// Runs the blocking `operation` of an async fn. With the `tokio` feature it
+/// is moved onto the blocking pool of the current runtime so the executor
+/// keeps going during SPI and sysfs access; without a runtime, or without
+/// the feature, it runs in place.
+#[cfg(feature = "tokio")]
+pub(crate) async fn blocking<T, F>(operation: F) -> Result<T, XRFClkError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, XRFClkError> + Send + 'static,
+{
+    let Ok(handle) = tokio::runtime::Handle::try_current() else {
+        return operation();
+    };
+
+    match handle.spawn_blocking(operation).await {
+        Ok(result) => result,
+        Err(e) => match e.try_into_panic() {
+            Ok(payload) => std::panic::resume_unwind(payload),
+            Err(e) => {
+                Err(XRFClkError::from(crate::error::XRFClkErrorKind::UnknownError).with_source(e))
+            }
+        },
+    }
+}
+
+#[cfg(not(feature = "tokio"))]
+pub(crate) async fn blocking<T, F>(operation: F) -> Result<T, XRFClkError>
+where
+    F: FnOnce() -> Result<T, XRFClkError>,
+{
+    operation()
+}
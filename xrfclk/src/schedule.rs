@@ -0,0 +1,423 @@
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::identity::DeviceSetting;
+use crate::{runtime, Chip, LMKDevice, LMXDevice};
+use std::error::Error;
+use std::fmt;
+use std::thread;
+use tracing::{debug, warn};
+
+/// Outcome of programming a single device of a `Schedule`.
+#[derive(Debug, Clone)]
+pub struct ProgramReport {
+    pub chip: Chip,
+    /// where the device lives, e.g. its spidev node
+    pub device: String,
+    pub result: Result<(), XRFClkError>,
+}
+
+impl ProgramReport {
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+impl fmt::Display for ProgramReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.result {
+            Ok(()) => write!(f, "{} on {}: ok", self.chip, self.device),
+            Err(e) => write!(f, "{} on {}: {e}", self.chip, self.device),
+        }
+    }
+}
+
+/// Programs clock chips in the order their references require: the LMKs
+/// first, as they provide the reference of the LMXs, then the LMXs. The
+/// devices of a stage are independent of each other and are programmed
+/// concurrently, one thread per device.
+///
+/// A failing device does not stop the others of its stage. An LMX whose
+/// reference LMK fails is not touched and fails with `ReferenceFailed`.
+/// LMXs added with `with_lmx` depend on every LMK of the schedule, those
+/// added with `with_lmx_fed_by` only on the LMK that feeds them.
+#[derive(Clone, Default)]
+pub struct Schedule {
+    lmk: Vec<(LMKDevice, DeviceSetting)>,
+    /// every LMX with the LMK feeding it, `None` if that is not known
+    lmx: Vec<(LMXDevice, DeviceSetting, Option<LMKDevice>)>,
+}
+
+fn program_lmk(device: &LMKDevice, setting: &DeviceSetting) -> ProgramReport {
+    let result = match setting {
+        DeviceSetting::Frequency(frequency) => device.set_clks_blocking(*frequency),
+        DeviceSetting::Registers(registers) => device.write_registers_blocking(registers),
+    };
+
+    ProgramReport {
+        chip: device.chip(),
+        device: device.location(),
+        result,
+    }
+}
+
+fn program_lmx(device: &LMXDevice, setting: &DeviceSetting) -> ProgramReport {
+    let result = match setting {
+        DeviceSetting::Frequency(frequency) => device.set_clks_blocking(*frequency),
+        DeviceSetting::Registers(registers) => device.write_registers_blocking(registers),
+    };
+
+    ProgramReport {
+        chip: device.chip(),
+        device: device.location(),
+        result,
+    }
+}
+
+/// Runs `program` for every job on its own thread, the reports are in the
+/// order of `jobs`.
+fn program_concurrently<D: Sync>(
+    jobs: &[(D, DeviceSetting)],
+    program: fn(&D, &DeviceSetting) -> ProgramReport,
+) -> Vec<ProgramReport> {
+    thread::scope(|scope| {
+        let handles: Vec<_> = jobs
+            .iter()
+            .map(|(device, setting)| scope.spawn(move || program(device, setting)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+            })
+            .collect()
+    })
+}
+
+impl Schedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an LMK, devices are cloned and share their transport.
+    pub fn with_lmk(mut self, device: &LMKDevice, setting: DeviceSetting) -> Self {
+        self.lmk.push((device.clone(), setting));
+        self
+    }
+
+    /// Adds an LMX that is programmed once every LMK is, for a wiring that
+    /// is not known. It fails with `ReferenceFailed` if any LMK does.
+    pub fn with_lmx(mut self, device: &LMXDevice, setting: DeviceSetting) -> Self {
+        self.lmx.push((device.clone(), setting, None));
+        self
+    }
+
+    /// Adds an LMX that gets its reference from `reference`. It fails with
+    /// `ReferenceFailed` only if that LMK does, and is programmed as usual
+    /// if the LMK is not part of the schedule.
+    pub fn with_lmx_fed_by(
+        mut self,
+        device: &LMXDevice,
+        setting: DeviceSetting,
+        reference: &LMKDevice,
+    ) -> Self {
+        self.lmx
+            .push((device.clone(), setting, Some(reference.clone())));
+        self
+    }
+
+    /// The error of the LMK `reference` names, or of the first failed LMK
+    /// without a reference.
+    fn reference_error(
+        &self,
+        lmk_reports: &[ProgramReport],
+        reference: Option<&LMKDevice>,
+    ) -> Option<XRFClkError> {
+        self.lmk
+            .iter()
+            .zip(lmk_reports)
+            .filter(|((lmk_device, _), _)| {
+                reference.is_none_or(|reference| reference.is_same_device(lmk_device))
+            })
+            .find_map(|(_, report)| report.result.clone().err())
+    }
+
+    pub fn len(&self) -> usize {
+        self.lmk.len() + self.lmx.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Programs every device and returns one report per device, the LMKs
+    /// first, each stage in the order the devices were added.
+    pub fn run_blocking(&self) -> Vec<ProgramReport> {
+        let mut reports = program_concurrently(&self.lmk, program_lmk);
+
+        let reference_errors: Vec<Option<XRFClkError>> = self
+            .lmx
+            .iter()
+            .map(|(_, _, reference)| self.reference_error(&reports, reference.as_ref()))
+            .collect();
+        let ready: Vec<(LMXDevice, DeviceSetting)> = self
+            .lmx
+            .iter()
+            .zip(&reference_errors)
+            .filter(|(_, reference_error)| reference_error.is_none())
+            .map(|((device, setting, _), _)| (device.clone(), setting.clone()))
+            .collect();
+        let mut lmx_reports = program_concurrently(&ready, program_lmx).into_iter();
+
+        for ((device, _, _), reference_error) in self.lmx.iter().zip(reference_errors) {
+            reports.push(match reference_error {
+                // the reports of `ready` are in the order of `self.lmx`
+                None => lmx_reports.next().unwrap(),
+                Some(reference_error) => ProgramReport {
+                    chip: device.chip(),
+                    device: device.location(),
+                    result: Err(XRFClkError::from(XRFClkErrorKind::ReferenceFailed)
+                        .with_chip(device.chip())
+                        .with_source(reference_error)),
+                },
+            });
+        }
+
+        for report in &reports {
+            match report.result {
+                Ok(()) => debug!("{report}"),
+                Err(_) => warn!("{report}"),
+            }
+        }
+
+        reports
+    }
+
+    /// Like `run_blocking`, the schedule runs on the blocking pool with the
+    /// `tokio` feature.
+    pub async fn run(&self) -> Result<Vec<ProgramReport>, XRFClkError> {
+        let schedule = self.clone();
+        runtime::blocking(move || Ok(schedule.run_blocking())).await
+    }
+}
+
+/// The reports of every device that failed, carried as the source of the
+/// error `check_reports` returns. Its own source is the cause of the first
+/// failure, which the error would otherwise have carried.
+#[derive(Debug, Clone)]
+pub struct ProgramFailures {
+    pub reports: Vec<ProgramReport>,
+}
+
+impl ProgramFailures {
+    /// The failures behind an error of `check_reports`.
+    pub fn of(error: &XRFClkError) -> Option<&Self> {
+        error.source()?.downcast_ref()
+    }
+}
+
+impl fmt::Display for ProgramFailures {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} devices failed", self.reports.len())?;
+        for report in &self.reports {
+            write!(f, "; {report}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ProgramFailures {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.reports.first()?.result.as_ref().err()?.source()
+    }
+}
+
+/// Ok if every device of `reports` was programmed. Otherwise the error is
+/// the one of the first failed device, with every failure attached as
+/// `ProgramFailures`.
+pub fn check_reports(reports: &[ProgramReport]) -> Result<(), XRFClkError> {
+    let failures: Vec<ProgramReport> = reports
+        .iter()
+        .filter(|report| !report.is_ok())
+        .cloned()
+        .collect();
+
+    match failures.first().map(|report| report.result.clone()) {
+        Some(Err(first)) => Err(first.with_source(ProgramFailures { reports: failures })),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sequence::ProgrammingSequence;
+    use crate::transport::RecordingTransport;
+    use crate::{load_config_from_file, Frequency};
+    use std::sync::Arc;
+
+    fn words(config: &crate::Config, chip: Chip, frequency: u64) -> Vec<u32> {
+        let registers = config
+            .registers(chip, Frequency::from_10khz(frequency))
+            .unwrap();
+        ProgrammingSequence::new(registers)
+            .unwrap()
+            .words()
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn lmks_are_programmed_before_the_lmxs() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        // one transport for all devices records the order across threads
+        let transport = RecordingTransport::new();
+        let lmk = LMKDevice::from_transport(
+            Chip::LMK04828,
+            Box::new(transport.clone()),
+            3,
+            config.clone(),
+        );
+        let lmx: Vec<LMXDevice> = (0..2)
+            .map(|_| {
+                LMXDevice::from_transport(
+                    Chip::LMX2594,
+                    Box::new(transport.clone()),
+                    config.clone(),
+                )
+            })
+            .collect();
+
+        let reports = Schedule::new()
+            .with_lmk(&lmk, DeviceSetting::Frequency(50025))
+            .with_lmx(&lmx[0], DeviceSetting::Frequency(20480))
+            .with_lmx(&lmx[1], DeviceSetting::Frequency(40960))
+            .run()
+            .await
+            .unwrap();
+
+        assert_eq!(reports.len(), 3);
+        assert!(reports.iter().all(ProgramReport::is_ok));
+        assert_eq!(reports[0].chip, Chip::LMK04828);
+
+        let lmk_words = words(&config, Chip::LMK04828, 50025);
+        let mut expected = [
+            words(&config, Chip::LMX2594, 20480),
+            words(&config, Chip::LMX2594, 40960),
+        ]
+        .concat();
+        let written = transport.words();
+        let mut lmx_words = written[lmk_words.len()..].to_vec();
+
+        assert_eq!(written[..lmk_words.len()], lmk_words[..]);
+        expected.sort_unstable();
+        lmx_words.sort_unstable();
+        assert_eq!(lmx_words, expected);
+    }
+
+    #[test]
+    fn every_error_is_collected() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let lmk_transport = RecordingTransport::new();
+        let lmx_transport = RecordingTransport::new();
+        let lmks: Vec<LMKDevice> = (0..2)
+            .map(|_| {
+                LMKDevice::from_transport(
+                    Chip::LMK04828,
+                    Box::new(lmk_transport.clone()),
+                    3,
+                    config.clone(),
+                )
+            })
+            .collect();
+        let lmx = LMXDevice::from_transport(Chip::LMX2594, Box::new(lmx_transport.clone()), config);
+
+        let reports = Schedule::new()
+            .with_lmk(&lmks[0], DeviceSetting::Frequency(1))
+            .with_lmk(&lmks[1], DeviceSetting::Frequency(50025))
+            .with_lmx(&lmx, DeviceSetting::Frequency(40960))
+            .run_blocking();
+
+        let kinds: Vec<_> = reports
+            .iter()
+            .map(|report| report.result.as_ref().err().map(XRFClkError::kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                Some(XRFClkErrorKind::InvalidFrequency),
+                None,
+                Some(XRFClkErrorKind::ReferenceFailed)
+            ]
+        );
+        assert!(!lmk_transport.words().is_empty());
+        assert!(lmx_transport.words().is_empty());
+
+        let error = check_reports(&reports).unwrap_err();
+        assert_eq!(error.kind(), XRFClkErrorKind::InvalidFrequency);
+        let failures = ProgramFailures::of(&error).unwrap();
+        assert_eq!(failures.reports.len(), 2);
+        assert_eq!(failures.reports[1].chip, Chip::LMX2594);
+        assert!(check_reports(&reports[1..2]).is_ok());
+    }
+
+    #[test]
+    fn only_the_lmxs_of_a_failed_lmk_are_skipped() {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let lmks: Vec<LMKDevice> = (0..2)
+            .map(|_| {
+                LMKDevice::from_transport(
+                    Chip::LMK04828,
+                    Box::new(RecordingTransport::new()),
+                    3,
+                    config.clone(),
+                )
+            })
+            .collect();
+        let transports = [RecordingTransport::new(), RecordingTransport::new()];
+        let lmx: Vec<LMXDevice> = transports
+            .iter()
+            .map(|transport| {
+                LMXDevice::from_transport(
+                    Chip::LMX2594,
+                    Box::new(transport.clone()),
+                    config.clone(),
+                )
+            })
+            .collect();
+
+        let reports = Schedule::new()
+            .with_lmk(&lmks[0], DeviceSetting::Frequency(1))
+            .with_lmk(&lmks[1], DeviceSetting::Frequency(50025))
+            .with_lmx_fed_by(&lmx[0], DeviceSetting::Frequency(40960), &lmks[0])
+            .with_lmx_fed_by(&lmx[1], DeviceSetting::Frequency(40960), &lmks[1])
+            .run_blocking();
+
+        assert_eq!(
+            reports[2].result.as_ref().unwrap_err().kind(),
+            XRFClkErrorKind::ReferenceFailed
+        );
+        assert!(reports[3].is_ok());
+        assert!(transports[0].words().is_empty());
+        assert!(!transports[1].words().is_empty());
+    }
+
+    #[test]
+    fn the_cause_of_the_first_failure_is_kept() {
+        let cause = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        let reports = [ProgramReport {
+            chip: Chip::LMX2594,
+            device: "/dev/spidev1.2".to_string(),
+            result: Err(XRFClkError::from(XRFClkErrorKind::InvalidFilePath).with_source(cause)),
+        }];
+
+        let error = check_reports(&reports).unwrap_err();
+        assert!(ProgramFailures::of(&error).is_some());
+        let root = error.source().unwrap().source().unwrap();
+        assert_eq!(
+            root.downcast_ref::<std::io::Error>().unwrap().kind(),
+            std::io::ErrorKind::PermissionDenied
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::config::RegisterSet;
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::Chip;
+
+/// LMX2594 R0 with RESET = 1.
+const LMX2594_RESET: u32 = 0x000002;
+/// LMX2594 R0 with RESET = 0.
+const LMX2594_RELEASE_RESET: u32 = 0x000000;
+/// FCAL_EN bit inside the LMX2594 R0 word.
+const LMX2594_FCAL_EN: u32 = 1 << 3;
+
+/// LMK0482x R0 with RESET = 1 (used when the profile has no `R0 (INIT)` entry).
+const LMK0482X_RESET: u32 = 0x000080;
+/// LMK04208 R0 with RESET = 1 (used when the profile has no `R0 (INIT)` entry).
+const LMK04208_RESET: u32 = 0x0002_0000;
+
+/// A single SPI write of a programming sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// Software reset of the chip, either asserting or releasing it.
+    Reset(u32),
+    /// Register value taken from the configuration.
+    Register { address: u16, value: u32 },
+    /// Rewrite of a register that triggers the VCO calibration.
+    Calibrate(u32),
+}
+
+impl Step {
+    /// The raw word that is shifted out over SPI for this step.
+    pub fn word(&self) -> u32 {
+        match self {
+            Self::Reset(word) | Self::Calibrate(word) => *word,
+            Self::Register { value, .. } => *value,
+        }
+    }
+}
+
+/// Ordered list of writes that brings a chip into the state described by a
+/// register set, following the recommended initial programming sequence of
+/// the respective datasheet.
+#[derive(Debug, Clone)]
+pub struct ProgrammingSequence {
+    chip: Chip,
+    steps: Vec<Step>,
+}
+
+/// Splits register names like `R112` or `R0 (INIT)` into the register
+/// address and whether the entry is the initial reset write.
+pub fn parse_register_name(name: &str) -> Option<(u16, bool)> {
+    let name = name.trim();
+    let (name, init) = match name.strip_suffix("(INIT)") {
+        Some(stripped) => (stripped.trim_end(), true),
+        None => (name, false),
+    };
+
+    let address = name.strip_prefix('R')?.parse::<u16>().ok()?;
+    Some((address, init))
+}
+
+impl ProgrammingSequence {
+    pub fn new(register_values: &RegisterSet) -> Result<Self, XRFClkError> {
+        let chip = register_values.chip();
+        let init = register_values.init();
+        let registers: Vec<(u16, u32)> = register_values.iter().collect();
+
+        let steps = match chip {
+            Chip::LMX2594 => Self::lmx2594_steps(registers)?,
+            Chip::LMK04828 | Chip::LMK04832 => {
+                Self::lmk_steps(init.unwrap_or(LMK0482X_RESET), registers)
+            }
+            Chip::LMK04208 => Self::lmk_steps(init.unwrap_or(LMK04208_RESET), registers),
+        };
+
+        Ok(Self { chip, steps })
+    }
+
+    /// LMX2594: reset, program R112 down to R0 and finally write R0 once
+    /// more with FCAL_EN = 1 so that the VCO calibration runs from a stable
+    /// state.
+    fn lmx2594_steps(mut registers: Vec<(u16, u32)>) -> Result<Vec<Step>, XRFClkError> {
+        registers.sort_by_key(|(address, _)| std::cmp::Reverse(*address));
+
+        let r0 = registers
+            .iter()
+            .find(|(address, _)| *address == 0)
+            .map(|(_, value)| *value)
+            .ok_or_else(|| {
+                XRFClkError::from(XRFClkErrorKind::MissingRegister).with_chip(Chip::LMX2594)
+            })?;
+
+        let mut steps = Vec::with_capacity(registers.len() + 3);
+        steps.push(Step::Reset(LMX2594_RESET));
+        steps.push(Step::Reset(LMX2594_RELEASE_RESET));
+        steps.extend(
+            registers
+                .into_iter()
+                .map(|(address, value)| Step::Register { address, value }),
+        );
+        steps.push(Step::Calibrate(r0 | LMX2594_FCAL_EN));
+
+        Ok(steps)
+    }
+
+    /// LMK parts: reset first, then every register in ascending address
+    /// order. PLL2 calibration is started by the write to the PLL2_N
+    /// register, which therefore naturally comes after its dependencies.
+    fn lmk_steps(reset: u32, mut registers: Vec<(u16, u32)>) -> Vec<Step> {
+        registers.sort_by_key(|(address, _)| *address);
+
+        let mut steps = Vec::with_capacity(registers.len() + 1);
+        steps.push(Step::Reset(reset));
+        steps.extend(
+            registers
+                .into_iter()
+                .map(|(address, value)| Step::Register { address, value }),
+        );
+
+        steps
+    }
+
+    pub fn chip(&self) -> Chip {
+        self.chip
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// The words in the order they have to be shifted out.
+    pub fn words(&self) -> impl Iterator<Item = u32> + '_ {
+        self.steps.iter().map(Step::word)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{load_config_from_file, Frequency};
+
+    #[test]
+    fn parses_register_names() {
+        assert_eq!(parse_register_name("R112"), Some((112, false)));
+        assert_eq!(parse_register_name("R0 (INIT)"), Some((0, true)));
+        assert_eq!(parse_register_name("PLL_N"), None);
+    }
+
+    #[test]
+    fn lmx2594_is_programmed_descending() {
+        let config = load_config_from_file().unwrap();
+        let values = config
+            .registers(Chip::LMX2594, Frequency::from_10khz(10240))
+            .unwrap();
+        let sequence = ProgrammingSequence::new(values).unwrap();
+        let words: Vec<u32> = sequence.words().collect();
+        let r0 = values.get(0).unwrap();
+
+        assert_eq!(words.len(), values.len() + 3);
+        assert_eq!(&words[..3], &[0x000002, 0x000000, values.get(112).unwrap()]);
+        assert_eq!(words[words.len() - 2], r0);
+        assert_eq!(words[words.len() - 1], r0 | LMX2594_FCAL_EN);
+    }
+
+    #[test]
+    fn lmk_is_reset_first_and_ascending() {
+        let config = load_config_from_file().unwrap();
+        let values = config
+            .registers(Chip::LMK04828, Frequency::from_10khz(50025))
+            .unwrap();
+        let sequence = ProgrammingSequence::new(values).unwrap();
+
+        assert_eq!(sequence.steps()[0], Step::Reset(values.init().unwrap()));
+
+        let addresses: Vec<u16> = sequence
+            .steps()
+            .iter()
+            .filter_map(|step| match step {
+                Step::Register { address, .. } => Some(*address),
+                _ => None,
+            })
+            .collect();
+        assert!(addresses.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(addresses.len(), values.len());
+    }
+}
@@ -0,0 +1,560 @@
+use super::{check_width, set_readback_field, READ_BIT};
+use crate::config::RegisterSet;
+use crate::describe::{describe_registers, ClockDescription};
+use crate::error::XRFClkError;
+use crate::lock::{LmkLockState, LMK0482X_PLL1_LD_REGISTER, LMK0482X_PLL2_LD_REGISTER};
+use crate::planner::lmk048xx::DeviceLimits;
+use crate::regmap::{register_map, Access, RegisterMap, WordFormat};
+use crate::transport::SpiTransport;
+use crate::{Chip, Frequency};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, warn};
+
+/// RESET inside the R0 word, it clears itself.
+const R0_RESET: u32 = 1 << 7;
+
+/// Fields PLL2 is calibrated for, changing them afterwards leaves the VCO
+/// off its calibration.
+const PLL2_FIELDS: [&str; 9] = [
+    "VCO_LDO_PD",
+    "PLL2_PD",
+    "PLL2_PRE_PD",
+    "VCO_MUX",
+    "PLL2_REF_2X_EN",
+    "PLL2_R",
+    "PLL2_P",
+    "PLL2_N",
+    "OSCin_FREQ",
+];
+
+/// A write that breaks the programming sequence of the datasheet. The chip
+/// takes it without complaint, so `Lmk048xxSimulator` records it instead of
+/// failing the transfer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequenceViolation {
+    /// write to an address the chip does not have
+    UnknownRegister { word: u32 },
+    /// PLL2 was calibrated, i.e. PLL2_N written, while OSCin was powered
+    /// down
+    CalibrationWithoutReference,
+    /// a PLL2 setting changed after PLL2_N started the calibration
+    Pll2ChangedAfterCalibration { address: u16, field: &'static str },
+}
+
+impl fmt::Display for SequenceViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownRegister { word } => write!(f, "{word:#08X} writes an unknown register"),
+            Self::CalibrationWithoutReference => {
+                write!(f, "PLL2 calibrated while OSCin is powered down")
+            }
+            Self::Pll2ChangedAfterCalibration { address, field } => write!(
+                f,
+                "R{address} changes {field} after the PLL2 calibration, PLL2_N has to be written last"
+            ),
+        }
+    }
+}
+
+struct Device {
+    map: &'static RegisterMap,
+    limits: DeviceLimits,
+    /// frequency at OSCin
+    reference: Frequency,
+    /// frequency at CLKin0, PLL1 cannot lock without one
+    clkin: Option<Frequency>,
+    registers: RegisterSet,
+    /// whether the last PLL2 calibration locked, `None` if the PLL2
+    /// settings changed since
+    pll2_calibration: Option<bool>,
+    calibrations: usize,
+    sync_events: usize,
+    violations: Vec<SequenceViolation>,
+}
+
+impl Device {
+    fn field(&self, name: &str) -> u32 {
+        self.registers.field(name).unwrap_or_default() as u32
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.field(name) != 0
+    }
+
+    fn pll2_settings(&self) -> Vec<u32> {
+        PLL2_FIELDS.iter().map(|name| self.field(name)).collect()
+    }
+
+    /// The register whose write starts the PLL2 calibration, the one
+    /// holding the least significant bits of PLL2_N.
+    fn calibration_register(&self) -> u16 {
+        self.map
+            .field("PLL2_N")
+            .unwrap()
+            .ranges
+            .last()
+            .unwrap()
+            .address
+    }
+
+    fn powered_down(&self) -> bool {
+        self.flag("POWERDOWN") || self.flag("OSCin_PD")
+    }
+
+    fn pll1_locked(&self) -> bool {
+        if self.powered_down() || self.flag("PLL1_PD") {
+            return false;
+        }
+        let Some(clkin) = self.clkin else {
+            return false;
+        };
+
+        // in 0-delay mode the PLL1 feedback is a clock output of PLL2
+        if self.flag("PLL1_NCLK_MUX") && !self.pll2_locked() {
+            return false;
+        }
+
+        match describe_registers(&self.registers, self.reference) {
+            Ok(description) => {
+                let expected_hz = description.plls[0].input_hz;
+                (clkin.hz() as f64 - expected_hz).abs() <= expected_hz * 1e-9
+            }
+            Err(e) => {
+                debug!("simulated {} cannot lock PLL1: {e}", self.registers.chip());
+                false
+            }
+        }
+    }
+
+    fn pll2_locked(&self) -> bool {
+        !self.powered_down() && self.pll2_calibration == Some(true)
+    }
+
+    fn lock_state(&self) -> LmkLockState {
+        LmkLockState {
+            pll1: self.pll1_locked(),
+            pll2: self.pll2_locked(),
+        }
+    }
+
+    fn sync_asserted(&self) -> bool {
+        self.flag("SYNC_EN") && self.field("SYNC_MODE") != 0 && self.flag("SYNC_POL")
+    }
+
+    fn violation(&mut self, violation: SequenceViolation) {
+        warn!("simulated {}: {violation}", self.registers.chip());
+        self.violations.push(violation);
+    }
+
+    fn write(&mut self, word: u32) {
+        let format = WordFormat::LMK0482X;
+        let address = format.address(word);
+        let data = format.data(word);
+
+        if self.map.initial_word(address).is_none() {
+            return self.violation(SequenceViolation::UnknownRegister { word });
+        }
+        // the profiles write the ID registers too, the chip ignores that
+        let mut fields = self.map.fields_of(address).peekable();
+        if fields.peek().is_some() && fields.all(|field| field.access == Access::ReadOnly) {
+            debug!(
+                "simulated {} ignores the write of {word:#08X} to read-only R{address}",
+                self.registers.chip()
+            );
+            return;
+        }
+
+        if address == 0 && data & R0_RESET != 0 {
+            debug!("simulated {} is reset", self.registers.chip());
+            self.registers = self.map.initial_registers();
+            self.pll2_calibration = None;
+            let _ = self.registers.insert(0, format.word(0, data & !R0_RESET));
+            return;
+        }
+
+        let pll2_settings = self.pll2_settings();
+        let sync_asserted = self.sync_asserted();
+        let _ = self.registers.insert(address, format.word(address, data));
+
+        if address == self.calibration_register() {
+            return self.calibrate();
+        }
+
+        let changed = PLL2_FIELDS
+            .iter()
+            .zip(pll2_settings)
+            .find(|(name, value)| self.field(name) != *value);
+        if let (Some((field, _)), Some(_)) = (changed, self.pll2_calibration) {
+            self.pll2_calibration = None;
+            self.violation(SequenceViolation::Pll2ChangedAfterCalibration { address, field });
+        }
+
+        // releasing SYNC restarts the dividers it held in reset, aligned
+        // to each other
+        if sync_asserted && !self.sync_asserted() {
+            self.sync_events += 1;
+        }
+    }
+
+    /// Runs the PLL2 VCO calibration, PLL2 locks if the selected VCO and
+    /// the phase detector can reach the programmed frequency.
+    fn calibrate(&mut self) {
+        self.calibrations += 1;
+
+        if self.flag("OSCin_PD") {
+            self.pll2_calibration = Some(false);
+            return self.violation(SequenceViolation::CalibrationWithoutReference);
+        }
+
+        let locked = match describe_registers(&self.registers, self.reference) {
+            Ok(description) => {
+                let pll2 = &description.plls[1];
+                let vco_range = self.limits.vco_ranges.get(self.field("VCO_MUX") as usize);
+
+                pll2.enabled
+                    && !self.flag("PLL2_PRE_PD")
+                    && !self.flag("VCO_LDO_PD")
+                    && pll2.phase_detector_hz <= self.limits.pll2_pd_max_hz as f64
+                    && vco_range.is_some_and(|(min, max)| {
+                        (*min as f64..=*max as f64).contains(&description.vco_hz)
+                    })
+            }
+            Err(e) => {
+                debug!("simulated {} cannot calibrate: {e}", self.registers.chip());
+                false
+            }
+        };
+
+        debug!(
+            "simulated {} calibrated PLL2, locked: {locked}",
+            self.registers.chip()
+        );
+        self.pll2_calibration = Some(locked);
+    }
+
+    /// The bits clocked in while shifting out `word`.
+    fn transfer(&mut self, word: u32) -> u32 {
+        if word & READ_BIT == 0 {
+            self.write(word);
+            return 0;
+        }
+
+        let format = WordFormat::LMK0482X;
+        let address = format.address(word);
+        let state = self.lock_state();
+        match address {
+            LMK0482X_PLL1_LD_REGISTER => {
+                set_readback_field(&mut self.registers, "RB_PLL1_LD", state.pll1 as u32)
+            }
+            LMK0482X_PLL2_LD_REGISTER => {
+                set_readback_field(&mut self.registers, "RB_PLL2_LD", state.pll2 as u32)
+            }
+            _ => {}
+        }
+
+        self.registers
+            .get(address)
+            .map_or(0, |word| format.data(word))
+    }
+
+    /// Whether the dividers of output pair `pair` are held in reset by
+    /// SYNC.
+    fn held_by_sync(&self, pair: usize) -> bool {
+        self.sync_asserted() && !self.flag(&format!("SYNC_DIS{}", 2 * pair))
+    }
+
+    /// The SYSREF frequency while SYSREF runs continuously.
+    fn sysref_hz(&self, description: &ClockDescription) -> Option<f64> {
+        let sysref = description.sysref.as_ref()?;
+        let held = self.sync_asserted() && !self.flag("SYNC_DISSYSREF");
+
+        // the other SYSREF_MUX settings only produce pulses
+        (self.pll2_locked() && sysref.enabled && self.field("SYSREF_MUX") == 3 && !held)
+            .then_some(sysref.frequency_hz)
+            .flatten()
+    }
+}
+
+/// Behavioural model of an LMK04828 or LMK04832 behind the `SpiTransport`
+/// interface.
+///
+/// It keeps the register state written over SPI and resets it on RESET.
+/// The values after reset are not tabulated for the LMK parts, power-up and
+/// RESET load the initial words of the register map instead.
+/// The write of PLL2_N calibrates PLL2, which locks if the selected VCO and
+/// the phase detector can reach the programmed frequency. PLL1 locks if
+/// CLKin0 / CLKin0_R matches its feedback / PLL1_N, where the feedback is
+/// OSCin or in 0-delay mode a clock output. Toggling SYNC_POL holds the
+/// dividers without SYNC_DISx in reset. Writes the datasheet sequence does
+/// not allow are recorded as `SequenceViolation`s.
+///
+/// Clones share the same device, so one handle can be passed to an
+/// `LMKDevice` while the other inspects the outcome.
+#[derive(Clone)]
+pub struct Lmk048xxSimulator {
+    device: Arc<Mutex<Device>>,
+}
+
+impl Lmk048xxSimulator {
+    /// A chip fresh out of power-up, fed with `reference` at OSCin.
+    pub fn from(chip: Chip, reference: Frequency) -> Result<Self, XRFClkError> {
+        let limits = DeviceLimits::of(chip)?;
+        let map = register_map(chip);
+
+        Ok(Self {
+            device: Arc::new(Mutex::new(Device {
+                map,
+                limits,
+                reference,
+                clkin: None,
+                registers: map.initial_registers(),
+                pll2_calibration: None,
+                calibrations: 0,
+                sync_events: 0,
+                violations: Vec::new(),
+            })),
+        })
+    }
+
+    /// Feeds CLKin0 with `clkin`, the reference PLL1 locks the VCXO to.
+    pub fn with_clkin(self, clkin: Frequency) -> Self {
+        self.device.lock().unwrap().clkin = Some(clkin);
+        self
+    }
+
+    pub fn chip(&self) -> Chip {
+        self.device.lock().unwrap().registers.chip()
+    }
+
+    pub fn reference(&self) -> Frequency {
+        self.device.lock().unwrap().reference
+    }
+
+    /// The current content of every register.
+    pub fn registers(&self) -> RegisterSet {
+        self.device.lock().unwrap().registers.clone()
+    }
+
+    pub fn lock_state(&self) -> LmkLockState {
+        self.device.lock().unwrap().lock_state()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.lock_state().is_locked()
+    }
+
+    /// Number of PLL2 calibrations run so far.
+    pub fn calibrations(&self) -> usize {
+        self.device.lock().unwrap().calibrations
+    }
+
+    /// Number of times SYNC was released.
+    pub fn sync_events(&self) -> usize {
+        self.device.lock().unwrap().sync_events
+    }
+
+    /// Every write so far that broke the programming sequence, in order.
+    pub fn violations(&self) -> Vec<SequenceViolation> {
+        self.device.lock().unwrap().violations.clone()
+    }
+
+    /// The clock tree the current registers configure.
+    pub fn clock_description(&self) -> Result<ClockDescription, XRFClkError> {
+        let device = self.device.lock().unwrap();
+        describe_registers(&device.registers, device.reference)
+    }
+
+    /// Frequencies of the running outputs by name, e.g. `CLKout0`. Empty
+    /// while PLL2 is not locked, outputs held by SYNC and SYSREF outputs
+    /// without a continuous SYSREF are left out.
+    pub fn output_frequencies(&self) -> Result<Vec<(String, f64)>, XRFClkError> {
+        let device = self.device.lock().unwrap();
+        if !device.pll2_locked() {
+            return Ok(Vec::new());
+        }
+
+        let description = describe_registers(&device.registers, device.reference)?;
+        let sysref_hz = device.sysref_hz(&description);
+
+        Ok(description
+            .outputs
+            .into_iter()
+            .enumerate()
+            .filter(|(_, output)| output.enabled)
+            .filter_map(|(index, output)| {
+                let frequency_hz = match output.source {
+                    "SYSREF" => sysref_hz?,
+                    _ if device.held_by_sync(index / 2) => return None,
+                    _ => output.frequency_hz?,
+                };
+                Some((output.name, frequency_hz))
+            })
+            .collect())
+    }
+
+    /// The SYSREF frequency, `None` unless SYSREF runs continuously.
+    pub fn sysref_frequency(&self) -> Result<Option<f64>, XRFClkError> {
+        let device = self.device.lock().unwrap();
+        let description = describe_registers(&device.registers, device.reference)?;
+
+        Ok(device.sysref_hz(&description))
+    }
+}
+
+impl SpiTransport for Lmk048xxSimulator {
+    fn write_word(&mut self, word: u32, width: usize) -> Result<(), XRFClkError> {
+        let mut device = self.device.lock().unwrap();
+        check_width(device.registers.chip(), width)?;
+        device.transfer(word);
+        Ok(())
+    }
+
+    fn transfer_word(&mut self, word: u32, width: usize) -> Result<u32, XRFClkError> {
+        let mut device = self.device.lock().unwrap();
+        check_width(device.registers.chip(), width)?;
+        Ok(device.transfer(word))
+    }
+
+    fn flush(&mut self) -> Result<(), XRFClkError> {
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        format!("simulated {}", self.chip())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{load_config_from_file, LMKDevice};
+
+    const REFERENCE: Frequency = Frequency::from_hz(160_000_000);
+    /// CLKin0 profile 50025 locks PLL1 to, compared with SYSREF.
+    const CLKIN: Frequency = Frequency::from_hz(10_000_000);
+
+    fn programmed() -> Lmk048xxSimulator {
+        let config = Arc::new(load_config_from_file().unwrap());
+        let simulator = Lmk048xxSimulator::from(Chip::LMK04828, REFERENCE)
+            .unwrap()
+            .with_clkin(CLKIN);
+        let device =
+            LMKDevice::from_transport(Chip::LMK04828, Box::new(simulator.clone()), 3, config);
+
+        device.set_clks_blocking(50025).unwrap();
+        assert!(device.is_locked_blocking().unwrap());
+        simulator
+    }
+
+    /// Writes every register of the field `name`, in ascending order.
+    fn write_field(simulator: &mut Lmk048xxSimulator, name: &str, value: u64) {
+        let mut registers = simulator.registers();
+        registers.set_field(name, value).unwrap();
+
+        for range in register_map(Chip::LMK04828).field(name).unwrap().ranges {
+            let word = registers.get(range.address).unwrap();
+            simulator.write_word(word, 3).unwrap();
+        }
+    }
+
+    #[test]
+    fn programming_locks_both_plls() {
+        let simulator = programmed();
+
+        assert_eq!(simulator.calibrations(), 1);
+        assert!(simulator.violations().is_empty());
+        assert_eq!(simulator.sysref_frequency().unwrap(), Some(10e6));
+        let outputs = simulator.output_frequencies().unwrap();
+        assert!(outputs.contains(&("CLKout0".to_string(), 500e6)));
+        assert!(outputs.contains(&("CLKout1".to_string(), 10e6)));
+        assert!(!outputs.iter().any(|(name, _)| name == "CLKout2"));
+
+        // PLL1 needs a matching CLKin0
+        let unreferenced = Lmk048xxSimulator::from(Chip::LMK04828, REFERENCE).unwrap();
+        for word in simulator.registers().iter().map(|(_, word)| word) {
+            let mut transport = unreferenced.clone();
+            transport.write_word(word, 3).unwrap();
+        }
+        assert_eq!(
+            unreferenced.lock_state(),
+            LmkLockState {
+                pll1: false,
+                pll2: true
+            }
+        );
+    }
+
+    #[test]
+    fn pll2_changes_after_the_calibration_are_flagged() {
+        let mut simulator = programmed();
+
+        let r = simulator.registers().field("PLL2_R").unwrap();
+        write_field(&mut simulator, "PLL2_R", r * 2);
+        assert!(!simulator.lock_state().pll2);
+        assert!(simulator.output_frequencies().unwrap().is_empty());
+        assert_eq!(
+            simulator.violations(),
+            [SequenceViolation::Pll2ChangedAfterCalibration {
+                address: 0x161,
+                field: "PLL2_R"
+            }]
+        );
+
+        // the VCO cannot reach the doubled N
+        let n = simulator.registers().field("PLL2_N").unwrap();
+        write_field(&mut simulator, "PLL2_R", r);
+        write_field(&mut simulator, "PLL2_N", n * 2);
+        assert!(!simulator.lock_state().pll2);
+        write_field(&mut simulator, "PLL2_N", n);
+        assert!(simulator.lock_state().pll2);
+        assert_eq!(simulator.calibrations(), 3);
+
+        simulator.write_word(R0_RESET, 3).unwrap();
+        write_field(&mut simulator, "OSCin_PD", 1);
+        write_field(&mut simulator, "PLL2_N", n);
+        assert!(!simulator.lock_state().pll2);
+        assert_eq!(
+            simulator.violations().last(),
+            Some(&SequenceViolation::CalibrationWithoutReference)
+        );
+    }
+
+    #[test]
+    fn sync_holds_the_dividers() {
+        let mut simulator = programmed();
+        write_field(&mut simulator, "SYNC_DIS0", 0);
+        write_field(&mut simulator, "SYNC_DISSYSREF", 0);
+
+        write_field(&mut simulator, "SYNC_POL", 1);
+        let outputs = simulator.output_frequencies().unwrap();
+        assert!(!outputs.iter().any(|(name, _)| name == "CLKout0"));
+        assert!(outputs.contains(&("CLKout4".to_string(), 500e6)));
+        assert_eq!(simulator.sysref_frequency().unwrap(), None);
+
+        write_field(&mut simulator, "SYNC_POL", 0);
+        assert_eq!(simulator.sync_events(), 1);
+        assert!(simulator
+            .output_frequencies()
+            .unwrap()
+            .contains(&("CLKout0".to_string(), 500e6)));
+        assert!(simulator.violations().is_empty());
+    }
+
+    #[test]
+    fn only_the_lmk0482x_are_simulated() {
+        assert_eq!(
+            Lmk048xxSimulator::from(Chip::LMK04208, REFERENCE)
+                .err()
+                .map(|e| e.kind()),
+            Some(crate::error::XRFClkErrorKind::InvalidChipString)
+        );
+
+        let mut simulator = programmed();
+        simulator.write_word(0x7FFF00, 3).unwrap();
+        assert_eq!(
+            simulator.violations(),
+            [SequenceViolation::UnknownRegister { word: 0x7FFF00 }]
+        );
+    }
+}
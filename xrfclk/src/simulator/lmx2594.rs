@@ -0,0 +1,356 @@
+use super::{check_width, set_readback_field, READ_BIT};
+use crate::config::RegisterSet;
+use crate::describe::{describe_registers, ClockDescription};
+use crate::error::XRFClkError;
+use crate::lock::{Lmx2594LockState, LMX2594_LD_REGISTER};
+use crate::planner::lmx2594::{PD_MAX_INTEGER_HZ, PD_MIN_HZ, VCO_MAX_HZ, VCO_MIN_HZ};
+use crate::readback::{LMX2594_FCAL_EN, LMX2594_MUXOUT_LD_SEL};
+use crate::regmap::lmx2594::REGISTER_MAP;
+use crate::regmap::{Access, WordFormat};
+use crate::transport::SpiTransport;
+use crate::{Chip, Frequency};
+use std::sync::{Arc, Mutex};
+use tracing::debug;
+
+/// POWERDOWN and RESET inside the R0 word.
+const R0_POWERDOWN: u32 = 1 << 0;
+const R0_RESET: u32 = 1 << 1;
+
+/// Fields that move the VCO away from the frequency it was calibrated for.
+const PLL_FIELDS: [&str; 7] = [
+    "OSC_2X",
+    "MULT",
+    "PLL_R_PRE",
+    "PLL_R",
+    "PLL_N",
+    "PLL_NUM",
+    "PLL_DEN",
+];
+
+/// Highest frequency of each of the VCO cores VCO1 to VCO7.
+const VCO_CORE_MAX_HZ: [f64; 7] = [8.6e9, 9.8e9, 10.8e9, 12.0e9, 12.9e9, 13.9e9, 15.0e9];
+
+struct Device {
+    reference: Frequency,
+    registers: RegisterSet,
+    /// the lock detect state the last VCO calibration ended in, `None` if
+    /// the PLL settings changed since
+    calibration: Option<Lmx2594LockState>,
+    calibrations: usize,
+}
+
+fn ld_vtune(state: Lmx2594LockState) -> u32 {
+    match state {
+        Lmx2594LockState::UnlockedVtuneLow => 0,
+        Lmx2594LockState::Invalid => 1,
+        Lmx2594LockState::Locked => 2,
+        Lmx2594LockState::UnlockedVtuneHigh => 3,
+    }
+}
+
+impl Device {
+    fn r0(&self) -> u32 {
+        self.registers.get(0).unwrap_or_default()
+    }
+
+    fn pll_settings(&self) -> Vec<u64> {
+        PLL_FIELDS
+            .iter()
+            .map(|name| self.registers.field(name).unwrap_or_default())
+            .collect()
+    }
+
+    fn lock_state(&self) -> Lmx2594LockState {
+        if self.r0() & (R0_POWERDOWN | R0_RESET) != 0 {
+            return Lmx2594LockState::UnlockedVtuneLow;
+        }
+
+        self.calibration
+            .unwrap_or(Lmx2594LockState::UnlockedVtuneLow)
+    }
+
+    fn write(&mut self, word: u32) {
+        let format = WordFormat::LMX2594;
+        let address = format.address(word);
+        let data = format.data(word);
+
+        if REGISTER_MAP.initial_word(address).is_none() {
+            debug!("simulated LMX2594 ignores the write of {word:#08X} to unknown R{address}");
+            return;
+        }
+        let mut fields = REGISTER_MAP.fields_of(address).peekable();
+        if fields.peek().is_some() && fields.all(|field| field.access == Access::ReadOnly) {
+            debug!("simulated LMX2594 ignores the write of {word:#08X} to read-only R{address}");
+            return;
+        }
+
+        let mut stored = data;
+        if address == 0 && data & R0_RESET != 0 {
+            self.registers = REGISTER_MAP.initial_registers();
+            self.calibration = None;
+            // RESET clears itself once the reset is done
+            stored &= !R0_RESET;
+        }
+
+        let pll_settings = self.pll_settings();
+        let _ = self.registers.insert(address, format.word(address, stored));
+        if self.pll_settings() != pll_settings {
+            self.calibration = None;
+        }
+
+        if address == 0 && data & LMX2594_FCAL_EN != 0 && data & (R0_RESET | R0_POWERDOWN) == 0 {
+            self.calibrate();
+        }
+    }
+
+    /// Runs the VCO calibration and settles the PLL on the current
+    /// settings, if the VCO and the phase detector can reach them.
+    fn calibrate(&mut self) {
+        self.calibrations += 1;
+
+        let state = match describe_registers(&self.registers, self.reference) {
+            Ok(description) => {
+                let phase_detector_hz = description.plls[0].phase_detector_hz;
+
+                if !(PD_MIN_HZ as f64..=PD_MAX_INTEGER_HZ as f64).contains(&phase_detector_hz)
+                    || description.vco_hz < VCO_MIN_HZ as f64
+                {
+                    Lmx2594LockState::UnlockedVtuneLow
+                } else if description.vco_hz > VCO_MAX_HZ as f64 {
+                    Lmx2594LockState::UnlockedVtuneHigh
+                } else {
+                    let core = VCO_CORE_MAX_HZ
+                        .iter()
+                        .position(|max_hz| description.vco_hz <= *max_hz)
+                        .unwrap_or_default();
+                    set_readback_field(&mut self.registers, "rb_VCO_SEL", core as u32 + 1);
+                    Lmx2594LockState::Locked
+                }
+            }
+            Err(e) => {
+                debug!("simulated LMX2594 cannot calibrate: {e}");
+                Lmx2594LockState::Invalid
+            }
+        };
+
+        debug!("simulated LMX2594 calibrated the VCO: {state:?}");
+        self.calibration = Some(state);
+    }
+
+    /// The bits clocked in while shifting out `word`.
+    fn transfer(&mut self, word: u32) -> u32 {
+        if word & READ_BIT == 0 {
+            self.write(word);
+            return 0;
+        }
+
+        // MUXOUT only carries the register data with MUXOUT_LD_SEL = 0,
+        // otherwise it is the lock detect output
+        if self.r0() & LMX2594_MUXOUT_LD_SEL != 0 {
+            return match self.lock_state().is_locked() {
+                true => 0xFFFFFF,
+                false => 0,
+            };
+        }
+
+        let format = WordFormat::LMX2594;
+        let address = format.address(word);
+        if address == LMX2594_LD_REGISTER {
+            let state = self.lock_state();
+            set_readback_field(&mut self.registers, "rb_LD_VTUNE", ld_vtune(state));
+        }
+
+        self.registers
+            .get(address)
+            .map_or(0, |word| format.data(word))
+    }
+}
+
+/// Behavioural model of an LMX2594 behind the `SpiTransport` interface.
+///
+/// It keeps the register state written over SPI, resets it on RESET and
+/// calibrates the VCO on every R0 write with FCAL_EN set. The PLL locks if
+/// the calibration finds the VCO and phase detector in range, and loses lock
+/// once the dividers change until the next calibration. Register reads
+/// answer like the chip on MUXOUT, including rb_LD_VTUNE in R110.
+///
+/// Clones share the same device, so one handle can be passed to an
+/// `LMXDevice` while the other inspects the outcome.
+#[derive(Clone)]
+pub struct Lmx2594Simulator {
+    device: Arc<Mutex<Device>>,
+}
+
+impl Lmx2594Simulator {
+    /// A chip fresh out of power-up, fed with `reference` at OSCin.
+    pub fn from(reference: Frequency) -> Self {
+        Self {
+            device: Arc::new(Mutex::new(Device {
+                reference,
+                registers: REGISTER_MAP.initial_registers(),
+                calibration: None,
+                calibrations: 0,
+            })),
+        }
+    }
+
+    pub fn reference(&self) -> Frequency {
+        self.device.lock().unwrap().reference
+    }
+
+    /// The current content of every register.
+    pub fn registers(&self) -> RegisterSet {
+        self.device.lock().unwrap().registers.clone()
+    }
+
+    pub fn lock_state(&self) -> Lmx2594LockState {
+        self.device.lock().unwrap().lock_state()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.lock_state().is_locked()
+    }
+
+    /// Number of VCO calibrations run so far.
+    pub fn calibrations(&self) -> usize {
+        self.device.lock().unwrap().calibrations
+    }
+
+    /// The clock tree the current registers configure.
+    pub fn clock_description(&self) -> Result<ClockDescription, XRFClkError> {
+        let device = self.device.lock().unwrap();
+        describe_registers(&device.registers, device.reference)
+    }
+
+    /// Frequencies of the enabled outputs by name, e.g. `RFoutA`. Empty
+    /// while the PLL is not locked.
+    pub fn output_frequencies(&self) -> Result<Vec<(String, f64)>, XRFClkError> {
+        if !self.is_locked() {
+            return Ok(Vec::new());
+        }
+
+        Ok(self
+            .clock_description()?
+            .outputs
+            .into_iter()
+            .filter(|output| output.enabled)
+            .filter_map(|output| Some((output.name, output.frequency_hz?)))
+            .collect())
+    }
+}
+
+impl SpiTransport for Lmx2594Simulator {
+    fn write_word(&mut self, word: u32, width: usize) -> Result<(), XRFClkError> {
+        check_width(Chip::LMX2594, width)?;
+        self.device.lock().unwrap().transfer(word);
+        Ok(())
+    }
+
+    fn transfer_word(&mut self, word: u32, width: usize) -> Result<u32, XRFClkError> {
+        check_width(Chip::LMX2594, width)?;
+        Ok(self.device.lock().unwrap().transfer(word))
+    }
+
+    fn flush(&mut self) -> Result<(), XRFClkError> {
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        "simulated LMX2594".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::error::XRFClkErrorKind;
+    use crate::{load_config_from_file, LMXDevice};
+
+    const REFERENCE: Frequency = Frequency::from_hz(122_880_000);
+
+    fn device(simulator: &Lmx2594Simulator) -> LMXDevice {
+        let config = Arc::new(load_config_from_file().unwrap());
+        LMXDevice::from_transport(Chip::LMX2594, Box::new(simulator.clone()), config)
+    }
+
+    #[test]
+    fn programming_calibrates_and_locks() {
+        let simulator = Lmx2594Simulator::from(REFERENCE);
+        assert!(!simulator.is_locked());
+        assert!(simulator.output_frequencies().unwrap().is_empty());
+
+        let device = device(&simulator);
+        device.set_clks_blocking(73700).unwrap();
+
+        assert!(simulator.is_locked());
+        assert!(device.is_locked_blocking().unwrap());
+        let outputs = simulator.output_frequencies().unwrap();
+        assert_eq!(outputs[0].0, "RFoutA");
+        assert!((outputs[0].1 - 737.28e6).abs() < 1.0);
+
+        // reading back does not recalibrate or disturb the lock
+        let calibrations = simulator.calibrations();
+        let dump = device.read_all_registers_blocking().unwrap();
+        assert_eq!(dump.get(36), simulator.registers().get(36));
+        assert_eq!(simulator.calibrations(), calibrations);
+        assert!(simulator.is_locked());
+        assert_ne!(simulator.registers().field("rb_VCO_SEL").unwrap(), 0);
+    }
+
+    #[test]
+    fn divider_changes_need_a_calibration() {
+        let mut simulator = Lmx2594Simulator::from(REFERENCE);
+        device(&simulator).set_clks_blocking(73700).unwrap();
+
+        let mut registers = simulator.registers();
+        registers
+            .set_field("PLL_N", registers.field("PLL_N").unwrap() + 1)
+            .unwrap();
+        let r36 = registers.get(36).unwrap();
+        simulator.write_word(r36, 3).unwrap();
+        assert_eq!(simulator.lock_state(), Lmx2594LockState::UnlockedVtuneLow);
+
+        let r0 = simulator.registers().get(0).unwrap();
+        simulator.write_word(r0 | LMX2594_FCAL_EN, 3).unwrap();
+        assert!(simulator.is_locked());
+
+        simulator.write_word(R0_RESET, 3).unwrap();
+        assert!(!simulator.is_locked());
+        assert_eq!(simulator.registers(), {
+            let mut registers = REGISTER_MAP.initial_registers();
+            registers.insert(0, 0).unwrap();
+            registers
+        });
+    }
+
+    #[test]
+    fn reset_clears_itself() {
+        let mut simulator = Lmx2594Simulator::from(REFERENCE);
+        device(&simulator).set_clks_blocking(73700).unwrap();
+
+        let r0 = simulator.registers().get(0).unwrap() & !LMX2594_MUXOUT_LD_SEL;
+        simulator.write_word(r0 | R0_RESET, 3).unwrap();
+
+        let read = simulator.transfer_word(READ_BIT, 3).unwrap();
+        assert_eq!(read & R0_RESET, 0);
+        assert_eq!(read, r0 & 0xFFFF);
+    }
+
+    #[test]
+    fn out_of_range_vcos_do_not_lock() {
+        let mut simulator = Lmx2594Simulator::from(REFERENCE);
+        device(&simulator).set_clks_blocking(73700).unwrap();
+
+        let mut registers = simulator.registers();
+        registers.set_field("PLL_N", 200).unwrap();
+        simulator.write_word(registers.get(36).unwrap(), 3).unwrap();
+        let r0 = simulator.registers().get(0).unwrap();
+        simulator.write_word(r0 | LMX2594_FCAL_EN, 3).unwrap();
+
+        assert_eq!(simulator.lock_state(), Lmx2594LockState::UnlockedVtuneHigh);
+        assert_eq!(
+            simulator.write_word(r0, 4).unwrap_err().kind(),
+            XRFClkErrorKind::InvalidRegister
+        );
+    }
+}
@@ -0,0 +1,41 @@
+pub mod lmk048xx;
+pub mod lmx2594;
+
+pub use lmk048xx::{Lmk048xxSimulator, SequenceViolation};
+pub use lmx2594::Lmx2594Simulator;
+
+use crate::config::RegisterSet;
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::planner::set_field;
+use crate::regmap::register_map;
+use crate::Chip;
+use tracing::debug;
+
+/// R/W bit of the 24-bit SPI words of both chips.
+const READ_BIT: u32 = 1 << 23;
+
+/// Sets a read-only field of the register map, which
+/// `RegisterSet::set_field` refuses to touch.
+fn set_readback_field(registers: &mut RegisterSet, name: &str, value: u32) {
+    let field = register_map(registers.chip()).field(name).unwrap();
+
+    for range in field.ranges {
+        set_field(
+            registers,
+            range.address,
+            range.msb as u32,
+            range.lsb as u32,
+            value,
+        );
+    }
+}
+
+/// Both simulated chips take 3 byte words.
+fn check_width(chip: Chip, width: usize) -> Result<(), XRFClkError> {
+    if width != 3 {
+        debug!("the {chip} takes 3 byte words, not {width}");
+        return Err(XRFClkError::from(XRFClkErrorKind::InvalidRegister).with_chip(chip));
+    }
+
+    Ok(())
+}
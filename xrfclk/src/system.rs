@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+/// Directory that `sys` and `dev` are looked up in during discovery. The
+/// default is `/`, other roots allow running discovery against a fake tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemRoot {
+    root: PathBuf,
+}
+
+impl Default for SystemRoot {
+    fn default() -> Self {
+        Self::from(PathBuf::from("/"))
+    }
+}
+
+impl SystemRoot {
+    pub fn from(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.root
+    }
+
+    /// `/sys/bus/spi/devices`, one entry per SPI device like `spi1.2`.
+    pub fn spi_devices(&self) -> PathBuf {
+        self.root.join("sys/bus/spi/devices")
+    }
+
+    /// `/sys/bus/spi/drivers/<driver>`.
+    pub fn spi_driver(&self, driver: &str) -> PathBuf {
+        self.root.join("sys/bus/spi/drivers").join(driver)
+    }
+
+    /// `/dev`.
+    pub fn dev(&self) -> PathBuf {
+        self.root.join("dev")
+    }
+
+    /// The spidev node of the SPI device `device_name`, e.g. `spi1.2` is
+    /// `/dev/spidev1.2`.
+    pub fn device_path(&self, device_name: &str) -> PathBuf {
+        self.dev().join(device_name.replace("spi", "spidev"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn paths_are_below_the_root() {
+        let root = SystemRoot::from(PathBuf::from("/tmp/fake"));
+
+        assert_eq!(
+            root.spi_devices(),
+            PathBuf::from("/tmp/fake/sys/bus/spi/devices")
+        );
+        assert_eq!(
+            root.spi_driver("spidev").join("bind"),
+            PathBuf::from("/tmp/fake/sys/bus/spi/drivers/spidev/bind")
+        );
+        assert_eq!(
+            SystemRoot::default().device_path("spi1.2"),
+            PathBuf::from("/dev/spidev1.2")
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::config::{parse_hex_word, ClockProfile, Config, Frequency, RegisterSet};
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::sequence::parse_register_name;
+use crate::Chip;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use tracing::debug;
+
+fn invalid_config() -> XRFClkError {
+    XRFClkError::from(XRFClkErrorKind::InvalidConfig)
+}
+
+/// Parses the hex register export of TI TICS Pro ("Export Hex Register
+/// Values"). Every line holds the register name and its value as hex word,
+/// separated by tabs, e.g. `R0 (INIT)\t0x000090`. Columns in between are
+/// ignored, like `convert_to_json.py` does.
+pub fn parse_tics_pro_export(chip: Chip, contents: &str) -> Result<RegisterSet, XRFClkError> {
+    let mut registers = RegisterSet::new(chip);
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let (name, value) = match line.split_once('\t') {
+            Some((name, _)) => (name, line.rsplit('\t').next().unwrap_or_default()),
+            // exports that went through an editor may have lost their tabs
+            None => line.rsplit_once(' ').ok_or_else(invalid_config)?,
+        };
+
+        let name = name.trim();
+        if parse_register_name(name).is_none() {
+            debug!("invalid register name in TICS Pro export: {name}");
+            return Err(XRFClkError::from(XRFClkErrorKind::InvalidRegister));
+        }
+
+        let value = parse_hex_word(value).map_err(|e| invalid_config().with_source(e))?;
+        registers.insert_named(name, value)?;
+    }
+
+    if registers.is_empty() {
+        return Err(invalid_config());
+    }
+
+    Ok(registers)
+}
+
+/// Parses a TICS Pro setup file (`.tcs`). It is INI style and keeps the
+/// register words in its `[MODES]` section as numbered pairs like
+/// `NAME00=R0 (INIT)` and `VALUE00=144`, with decimal values. The other
+/// sections hold GUI state and are skipped.
+pub fn parse_tics_pro_setup(chip: Chip, contents: &str) -> Result<RegisterSet, XRFClkError> {
+    let mut names = BTreeMap::new();
+    let mut values = BTreeMap::new();
+    let mut in_modes = false;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+
+        if let Some(section) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            in_modes = section.trim().eq_ignore_ascii_case("MODES");
+            continue;
+        }
+        if !in_modes {
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').ok_or_else(invalid_config)?;
+        let (key, value) = (key.trim(), value.trim());
+        let (entries, index) = match (key.strip_prefix("NAME"), key.strip_prefix("VALUE")) {
+            (Some(index), _) => (&mut names, index),
+            (_, Some(index)) => (&mut values, index),
+            _ => continue,
+        };
+        let index = index.parse::<u32>().map_err(|e| {
+            debug!("invalid key in the MODES section of a TICS Pro setup: {key}");
+            invalid_config().with_source(e)
+        })?;
+        entries.insert(index, value);
+    }
+
+    let mut registers = RegisterSet::new(chip);
+    for (index, name) in names {
+        if parse_register_name(name).is_none() {
+            debug!("invalid register name in TICS Pro setup: {name}");
+            return Err(XRFClkError::from(XRFClkErrorKind::InvalidRegister));
+        }
+
+        let value = values.get(&index).ok_or_else(|| {
+            debug!("TICS Pro setup has no VALUE{index:02} for {name}");
+            invalid_config()
+        })?;
+        let value = value.parse::<u32>().map_err(|e| {
+            debug!("invalid value of {name} in TICS Pro setup: {value}");
+            invalid_config().with_source(e)
+        })?;
+        registers.insert_named(name, value)?;
+    }
+
+    if registers.is_empty() {
+        return Err(invalid_config());
+    }
+
+    Ok(registers)
+}
+
+/// Loads a TICS Pro file from disk, a `.tcs` setup file or otherwise the
+/// `.txt` hex register export.
+pub fn load_tics_pro_file(chip: Chip, path: &Path) -> Result<RegisterSet, XRFClkError> {
+    let contents = fs::read_to_string(path).map_err(|e| XRFClkError::io(e, path))?;
+
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("tcs") => parse_tics_pro_setup(chip, &contents),
+        _ => parse_tics_pro_export(chip, &contents),
+    }
+    .map_err(|e| e.with_path(path))
+}
+
+/// Adds the registers of a TICS Pro export to `config` under `chip` and
+/// `frequency`, replacing a profile that is already there.
+pub fn add_tics_pro_profile(
+    config: &mut Config,
+    chip: Chip,
+    frequency: Frequency,
+    path: &Path,
+) -> Result<(), XRFClkError> {
+    let mut profile = ClockProfile::from(frequency, load_tics_pro_file(chip, path)?);
+    profile.description = Some(format!("TICS Pro export {}", path.display()));
+    config.insert(profile);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_hex_register_exports() {
+        let export = "R0 (INIT)\t0x000090\r\nR0\t0x000010\nR256\t0x010006\n\n";
+        let registers = parse_tics_pro_export(Chip::LMK04828, export).unwrap();
+
+        assert_eq!(registers.len(), 2);
+        assert_eq!(registers.init(), Some(0x000090));
+        assert_eq!(registers.get(256), Some(0x010006));
+    }
+
+    #[test]
+    fn rejects_malformed_exports() {
+        assert!(parse_tics_pro_export(Chip::LMK04828, "").is_err());
+        assert!(parse_tics_pro_export(Chip::LMK04828, "PLL2_N\t0x01684B").is_err());
+        assert!(parse_tics_pro_export(Chip::LMK04828, "R0\tzz").is_err());
+        // R2 holding the word of R0
+        assert!(parse_tics_pro_export(Chip::LMK04828, "R2\t0x000010").is_err());
+    }
+
+    #[test]
+    fn parses_setup_files() {
+        let setup = "[SETUP]\r\nSIMULATE=1\r\n\r\n[MODES]\r\nNAME00=R0 (INIT)\r\n\
+                     VALUE00=144\r\nNAME01=R0\r\nVALUE01=16\r\nNAME02=R256\r\n\
+                     VALUE02=65542\r\n\r\n[FLEX]\r\nNAME00=PLL2_N\r\n";
+        let registers = parse_tics_pro_setup(Chip::LMK04828, setup).unwrap();
+
+        assert_eq!(registers.len(), 2);
+        assert_eq!(registers.init(), Some(0x000090));
+        assert_eq!(registers.get(256), Some(0x010006));
+
+        assert!(parse_tics_pro_setup(Chip::LMK04828, "[SETUP]\nSIMULATE=1").is_err());
+        assert!(parse_tics_pro_setup(Chip::LMK04828, "[MODES]\nNAME00=R0").is_err());
+        assert!(parse_tics_pro_setup(Chip::LMK04828, "[MODES]\nNAME00=R0\nVALUE00=0x10").is_err());
+    }
+}
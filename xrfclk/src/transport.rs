@@ -0,0 +1,199 @@
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Write;
+use std::os::unix::io::AsRawFd;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Byte level access to the SPI bus a clock chip is attached to.
+///
+/// Words are shifted out most significant byte first, `width` is the number
+/// of bytes of the word that go over the wire (3 for the LMX2594 and the
+/// LMK0482x, 4 for the LMK04208).
+pub trait SpiTransport: Send {
+    /// Shifts out the lowest `width` bytes of `word`.
+    fn write_word(&mut self, word: u32, width: usize) -> Result<(), XRFClkError>;
+
+    /// Shifts out the lowest `width` bytes of `word` and returns the bytes
+    /// that were clocked in during the same transfer.
+    fn transfer_word(&mut self, word: u32, width: usize) -> Result<u32, XRFClkError>;
+
+    fn flush(&mut self) -> Result<(), XRFClkError>;
+
+    /// Human readable location of the device, used in log messages.
+    fn describe(&self) -> String;
+}
+
+/// `struct spi_ioc_transfer` from `linux/spi/spidev.h`.
+#[repr(C)]
+#[derive(Default)]
+struct SpiIocTransfer {
+    tx_buf: u64,
+    rx_buf: u64,
+    len: u32,
+    speed_hz: u32,
+    delay_usecs: u16,
+    bits_per_word: u8,
+    cs_change: u8,
+    tx_nbits: u8,
+    rx_nbits: u8,
+    word_delay_usecs: u8,
+    pad: u8,
+}
+
+/// `SPI_IOC_MESSAGE(1)`: `_IOW('k', 0, char[sizeof(struct spi_ioc_transfer)])`.
+const SPI_IOC_MESSAGE_1: u32 =
+    (1 << 30) | ((std::mem::size_of::<SpiIocTransfer>() as u32) << 16) | ((b'k' as u32) << 8);
+
+fn word_bytes(word: u32, width: usize) -> Result<[u8; 4], XRFClkError> {
+    if width == 0 || width > 4 {
+        return Err(XRFClkError::from(XRFClkErrorKind::InvalidRegister));
+    }
+
+    Ok(word.to_be_bytes())
+}
+
+/// Transport that talks to a `/dev/spidevX.Y` character device.
+pub struct SpidevTransport {
+    path: PathBuf,
+    file: Option<fs::File>,
+}
+
+impl SpidevTransport {
+    /// The device node is opened lazily on the first access.
+    pub fn from(path: PathBuf) -> Self {
+        Self { path, file: None }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn file(&mut self) -> Result<&mut fs::File, XRFClkError> {
+        if self.file.is_none() {
+            self.file = Some(
+                fs::OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .create(false)
+                    .open(&self.path)
+                    .map_err(|e| XRFClkError::io(e, &self.path))?,
+            );
+        }
+
+        Ok(self.file.as_mut().unwrap())
+    }
+}
+
+impl SpiTransport for SpidevTransport {
+    fn write_word(&mut self, word: u32, width: usize) -> Result<(), XRFClkError> {
+        let bytes = word_bytes(word, width)?;
+        self.file()?
+            .write_all(&bytes[4 - width..])
+            .map_err(|e| XRFClkError::io(e, &self.path))
+    }
+
+    fn transfer_word(&mut self, word: u32, width: usize) -> Result<u32, XRFClkError> {
+        let bytes = word_bytes(word, width)?;
+        let file = self.file()?;
+
+        let tx = &bytes[4 - width..];
+        let mut received = [0u8; 4];
+        let rx = &mut received[4 - width..];
+
+        // a single full-duplex transfer, so chip select stays asserted while
+        // the chip shifts out the answer
+        let transfer = SpiIocTransfer {
+            tx_buf: tx.as_ptr() as u64,
+            rx_buf: rx.as_mut_ptr() as u64,
+            len: width as u32,
+            ..Default::default()
+        };
+
+        let result = unsafe { libc::ioctl(file.as_raw_fd(), SPI_IOC_MESSAGE_1 as _, &transfer) };
+        if result < 0 {
+            return Err(XRFClkError::io(std::io::Error::last_os_error(), &self.path));
+        }
+
+        Ok(u32::from_be_bytes(received))
+    }
+
+    fn flush(&mut self) -> Result<(), XRFClkError> {
+        self.file()?
+            .flush()
+            .map_err(|e| XRFClkError::io(e, &self.path))
+    }
+
+    fn describe(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+#[derive(Default)]
+struct Recording {
+    words: Vec<u32>,
+    responses: VecDeque<u32>,
+    flushes: usize,
+}
+
+/// In-memory transport that records every word instead of sending it.
+///
+/// Clones share the same recording, so one handle can be passed to a device
+/// while the other is kept around to inspect what was written.
+#[derive(Clone, Default)]
+pub struct RecordingTransport {
+    recording: Arc<Mutex<Recording>>,
+}
+
+impl RecordingTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All words written or transferred so far, in order.
+    pub fn words(&self) -> Vec<u32> {
+        self.recording.lock().unwrap().words.clone()
+    }
+
+    pub fn flushes(&self) -> usize {
+        self.recording.lock().unwrap().flushes
+    }
+
+    /// Queues a word that is returned by the next `transfer_word` call.
+    /// Transfers without a queued response read back zero.
+    pub fn push_response(&self, word: u32) {
+        self.recording.lock().unwrap().responses.push_back(word);
+    }
+
+    pub fn clear(&self) {
+        let mut recording = self.recording.lock().unwrap();
+        recording.words.clear();
+        recording.responses.clear();
+        recording.flushes = 0;
+    }
+}
+
+impl SpiTransport for RecordingTransport {
+    fn write_word(&mut self, word: u32, width: usize) -> Result<(), XRFClkError> {
+        word_bytes(word, width)?;
+        self.recording.lock().unwrap().words.push(word);
+        Ok(())
+    }
+
+    fn transfer_word(&mut self, word: u32, width: usize) -> Result<u32, XRFClkError> {
+        word_bytes(word, width)?;
+        let mut recording = self.recording.lock().unwrap();
+        recording.words.push(word);
+        Ok(recording.responses.pop_front().unwrap_or(0))
+    }
+
+    fn flush(&mut self) -> Result<(), XRFClkError> {
+        self.recording.lock().unwrap().flushes += 1;
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        "recording transport".to_string()
+    }
+}
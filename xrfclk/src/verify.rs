@@ -0,0 +1,165 @@
+use crate::config::RegisterSet;
+use crate::error::{XRFClkError, XRFClkErrorKind};
+use crate::readback::RegisterDump;
+use crate::Chip;
+use std::fmt;
+
+/// Bits of a register word that are expected to read back unchanged after
+/// programming. Read-only, self-clearing and readback-control fields are
+/// masked out, a mask of zero means the register is not compared at all.
+pub fn compare_mask(chip: Chip, address: u16) -> u32 {
+    match chip {
+        Chip::LMX2594 => match address {
+            // RESET and FCAL_EN clear themselves, MUXOUT_LD_SEL is switched
+            // to readback while reading
+            0 => 0xFFFF & !((1 << 1) | (1 << 2) | (1 << 3)),
+            // rb_* status registers
+            110..=112 => 0,
+            _ => 0xFFFF,
+        },
+        Chip::LMK04828 | Chip::LMK04832 => match address {
+            // RESET clears itself
+            0 => 0xFF & !(1 << 7),
+            // RB_PLLx_LD and RB_PLLx_LD_LOST are status bits
+            0x182 | 0x183 => 0xFF & !((1 << 1) | (1 << 2)),
+            // DAC and holdover readback, SPI lock
+            0x184..=0x188 | 0x1FFD..=0x1FFF => 0,
+            _ => 0xFF,
+        },
+        Chip::LMK04208 => match address {
+            // RESET clears itself
+            0 => !0x1F & !(1 << 17),
+            // R31 is rewritten to select the readback register
+            31 => 0,
+            _ => !0x1F,
+        },
+    }
+}
+
+/// A register whose read back value differs from the programmed one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisterMismatch {
+    pub address: u16,
+    pub expected: u32,
+    pub actual: u32,
+    /// bits that were taken into account for the comparison
+    pub mask: u32,
+}
+
+impl fmt::Display for RegisterMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "R{}: expected {:#08X} read {:#08X} (differing bits {:#08X})",
+            self.address,
+            self.expected,
+            self.actual,
+            (self.expected ^ self.actual) & self.mask
+        )
+    }
+}
+
+/// Result of comparing a read back register dump with a register set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyReport {
+    pub chip: Chip,
+    /// number of registers that were compared
+    pub checked: usize,
+    pub mismatches: Vec<RegisterMismatch>,
+}
+
+impl VerifyReport {
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Turns a report with mismatches into a `ReadbackMismatch` error.
+    pub fn into_result(self) -> Result<Self, XRFClkError> {
+        if self.is_ok() {
+            Ok(self)
+        } else {
+            Err(XRFClkError::from(XRFClkErrorKind::ReadbackMismatch).with_chip(self.chip))
+        }
+    }
+}
+
+/// Addresses of `register_values` that take part in a comparison, the
+/// `R0 (INIT)` write and registers that cannot be compared are left out.
+pub fn verifiable_registers(register_values: &RegisterSet) -> Vec<u16> {
+    register_values
+        .iter()
+        .map(|(address, _)| address)
+        .filter(|address| compare_mask(register_values.chip(), *address) != 0)
+        .collect()
+}
+
+/// Compares every verifiable register of `register_values` with `dump`.
+/// Registers missing from the dump count as mismatches with a read value of
+/// zero.
+pub fn verify(register_values: &RegisterSet, dump: &RegisterDump) -> VerifyReport {
+    let chip = register_values.chip();
+    let addresses = verifiable_registers(register_values);
+    let mut mismatches = Vec::new();
+
+    for address in &addresses {
+        // the addresses are taken from the register set
+        let expected = register_values.get(*address).unwrap_or_default();
+        let actual = dump.get(*address).unwrap_or(0);
+        let mask = compare_mask(chip, *address);
+
+        if (expected ^ actual) & mask != 0 {
+            mismatches.push(RegisterMismatch {
+                address: *address,
+                expected,
+                actual,
+                mask,
+            });
+        }
+    }
+
+    VerifyReport {
+        chip,
+        checked: addresses.len(),
+        mismatches,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{load_config_from_file, Frequency};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn self_clearing_bits_are_ignored() {
+        let config = load_config_from_file().unwrap();
+        let values = config
+            .registers(Chip::LMX2594, Frequency::from_10khz(10240))
+            .unwrap();
+
+        let mut registers: BTreeMap<u16, u32> = values.iter().collect();
+        // readback mode, FCAL_EN cleared and garbage in the status registers
+        registers.insert(0, values.get(0).unwrap() & !0b1100);
+        registers.insert(110, 0x6E0400);
+
+        let report = verify(values, &RegisterDump::from(Chip::LMX2594, registers));
+
+        assert!(report.is_ok());
+        assert_eq!(report.checked, 110);
+    }
+
+    #[test]
+    fn differing_registers_are_reported() {
+        let config = load_config_from_file().unwrap();
+        let values = config
+            .registers(Chip::LMK04828, Frequency::from_10khz(50025))
+            .unwrap();
+        let report = verify(values, &RegisterDump::from(Chip::LMK04828, BTreeMap::new()));
+
+        assert!(report
+            .mismatches
+            .iter()
+            .any(|mismatch| mismatch.address == 0x100 && mismatch.expected == 0x010006));
+        assert!(report.into_result().is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use std::fs;
+use std::os::unix::fs::symlink;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use xrfclk::SystemRoot;
+
+static NEXT_TREE: AtomicUsize = AtomicUsize::new(0);
+
+/// A fake `/sys` and `/dev` in a temporary directory, removed on drop.
+pub struct FakeSystem {
+    root: PathBuf,
+}
+
+impl FakeSystem {
+    pub fn new() -> Self {
+        let root = std::env::temp_dir().join(format!(
+            "xrfclk-{}-{}",
+            std::process::id(),
+            NEXT_TREE.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        fs::create_dir_all(root.join("sys/bus/spi/devices")).unwrap();
+        fs::create_dir_all(root.join("sys/bus/spi/drivers/spidev")).unwrap();
+        fs::create_dir_all(root.join("dev")).unwrap();
+
+        Self { root }
+    }
+
+    pub fn root(&self) -> SystemRoot {
+        SystemRoot::from(self.root.clone())
+    }
+
+    pub fn path(&self, relative: &str) -> PathBuf {
+        self.root.join(relative)
+    }
+
+    pub fn read(&self, relative: &str) -> String {
+        fs::read_to_string(self.path(relative)).unwrap()
+    }
+
+    /// Points the `driver` link of `spi_name` at `driver`.
+    pub fn rebind(&self, spi_name: &str, driver: &str) {
+        let driver_path = self.path("sys/bus/spi/drivers").join(driver);
+        let link = self
+            .path("sys/bus/spi/devices")
+            .join(spi_name)
+            .join("driver");
+
+        fs::create_dir_all(&driver_path).unwrap();
+        let _ = fs::remove_file(&link);
+        symlink(&driver_path, link).unwrap();
+    }
+
+    /// Adds the SPI device `spi_name` (e.g. `spi1.2`) with the given
+    /// compatible string and device tree node.
+    pub fn spi_device(&self, spi_name: &str, compatible: &str, node: &str) -> SpiDevice<'_> {
+        let node_path = self.path("sys/firmware/devicetree/base/spi").join(node);
+        fs::create_dir_all(&node_path).unwrap();
+        fs::write(node_path.join("compatible"), format!("{compatible}\0")).unwrap();
+
+        let device_path = self.path("sys/bus/spi/devices").join(spi_name);
+        fs::create_dir_all(&device_path).unwrap();
+        symlink(&node_path, device_path.join("of_node")).unwrap();
+
+        SpiDevice {
+            system: self,
+            spi_name: spi_name.to_string(),
+            device_path,
+            node_path,
+        }
+    }
+}
+
+impl Drop for FakeSystem {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+pub struct SpiDevice<'a> {
+    system: &'a FakeSystem,
+    spi_name: String,
+    device_path: PathBuf,
+    node_path: PathBuf,
+}
+
+impl SpiDevice<'_> {
+    /// The `num_bytes` property of the LMK nodes, a big endian u32.
+    pub fn num_bytes(self, num_bytes: u32) -> Self {
+        fs::write(self.node_path.join("num_bytes"), num_bytes.to_be_bytes()).unwrap();
+        self
+    }
+
+    /// Binds the device to `driver`, like the xrfclk kernel driver.
+    pub fn driver(self, driver: &str) -> Self {
+        let driver_path = self.system.path("sys/bus/spi/drivers").join(driver);
+        fs::create_dir_all(&driver_path).unwrap();
+        symlink(&driver_path, self.device_path.join("driver")).unwrap();
+        self
+    }
+
+    /// Creates the spidev node as a plain file that collects every write.
+    pub fn dev_node(self) -> Self {
+        fs::write(
+            self.system
+                .path("dev")
+                .join(self.spi_name.replace("spi", "spidev")),
+            [],
+        )
+        .unwrap();
+        self
+    }
+}
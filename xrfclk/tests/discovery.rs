@@ -0,0 +1,201 @@
+mod common;
+
+use common::FakeSystem;
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+use xrfclk::discovery::{
+    bind_spidev, bind_spidev_with_timeout, enumerate_clock_chips_in, restore_driver,
+};
+use xrfclk::error::XRFClkErrorKind;
+use xrfclk::{find_devices_in, load_config_from_file, open_devices, Chip, DeviceIdentity};
+
+#[tokio::test]
+async fn finds_the_clock_chips_of_a_fake_tree() {
+    let system = FakeSystem::new();
+    system
+        .spi_device("spi1.0", "ti,lmk04828", "lmk04828@0")
+        .num_bytes(3)
+        .dev_node();
+    system
+        .spi_device("spi1.1", "ti,lmx2594", "lmx2594@1")
+        .driver("xrfclk")
+        .dev_node();
+    system
+        .spi_device("spi1.2", "ti,lmx2594", "lmx2594@2")
+        .dev_node();
+    system.spi_device("spi2.0", "ti,ads8688", "adc@0");
+
+    let config = Arc::new(load_config_from_file().unwrap());
+    let (lmk_devices, lmx_devices) = find_devices_in(config, &system.root()).await.unwrap();
+
+    assert_eq!(lmk_devices.len(), 1);
+    assert_eq!(lmx_devices.len(), 2);
+    assert_eq!(lmk_devices[0].chip(), Chip::LMK04828);
+    assert!(lmk_devices[0]
+        .identities()
+        .contains(&DeviceIdentity::Node("lmk04828@0".to_string())));
+    assert!(lmk_devices[0]
+        .identities()
+        .contains(&"spi1.0".parse().unwrap()));
+
+    // every clock chip is moved over to spidev
+    assert_eq!(
+        system.read("sys/bus/spi/devices/spi1.0/driver_override"),
+        "spidev"
+    );
+    assert_eq!(system.read("sys/bus/spi/drivers/xrfclk/unbind"), "spi1.1");
+    assert!(!system
+        .path("sys/bus/spi/devices/spi2.0/driver_override")
+        .exists());
+
+    // the fake node collects the 3 byte words of the programming sequence
+    lmk_devices[0].set_clks(50025).await.unwrap();
+    let written = fs::read(system.path("dev/spidev1.0")).unwrap();
+    assert_eq!(written.len() % 3, 0);
+    assert_eq!(&written[..3], &[0x00, 0x00, 0x90]);
+}
+
+#[tokio::test]
+async fn enumeration_does_not_touch_the_drivers() {
+    let system = FakeSystem::new();
+    system
+        .spi_device("spi1.3", "ti,lmk04208", "lmk04208@3")
+        .num_bytes(4)
+        .driver("xrfclk");
+    system.spi_device("spi1.1", "ti,lmx2594", "lmx2594@1");
+
+    let descriptors = enumerate_clock_chips_in(&system.root()).await.unwrap();
+
+    assert_eq!(descriptors.len(), 2);
+    assert_eq!(descriptors[0].name, "spi1.1");
+    assert_eq!(descriptors[0].driver, None);
+    assert_eq!(descriptors[0].num_bytes, None);
+
+    let lmk = &descriptors[1];
+    assert_eq!((lmk.chip, lmk.bus, lmk.chip_select), (Chip::LMK04208, 1, 3));
+    assert_eq!(lmk.driver.as_deref(), Some("xrfclk"));
+    assert_eq!(lmk.num_bytes, Some(4));
+    assert!(!system
+        .path("sys/bus/spi/devices/spi1.3/driver_override")
+        .exists());
+}
+
+#[tokio::test]
+async fn the_original_driver_is_restored() {
+    let system = FakeSystem::new();
+    system
+        .spi_device("spi1.1", "ti,lmx2594", "lmx2594@1")
+        .driver("xrfclk")
+        .dev_node();
+
+    let descriptor = enumerate_clock_chips_in(&system.root())
+        .await
+        .unwrap()
+        .remove(0);
+
+    let device_path = bind_spidev(&descriptor).await.unwrap();
+    assert_eq!(device_path, system.path("dev/spidev1.1"));
+    assert_eq!(system.read("sys/bus/spi/drivers/xrfclk/unbind"), "spi1.1");
+    assert_eq!(system.read("sys/bus/spi/drivers/spidev/bind"), "spi1.1");
+
+    // what the kernel does on the bind above
+    system.rebind("spi1.1", "spidev");
+
+    restore_driver(&descriptor).await.unwrap();
+    assert_eq!(system.read("sys/bus/spi/drivers/spidev/unbind"), "spi1.1");
+    assert_eq!(
+        system.read("sys/bus/spi/devices/spi1.1/driver_override"),
+        "\n"
+    );
+    assert_eq!(system.read("sys/bus/spi/drivers/xrfclk/bind"), "spi1.1");
+}
+
+#[tokio::test]
+async fn only_the_opened_chips_are_rebound() {
+    let system = FakeSystem::new();
+    system
+        .spi_device("spi1.1", "ti,lmx2594", "lmx2594@1")
+        .driver("xrfclk")
+        .dev_node();
+    system
+        .spi_device("spi1.2", "ti,lmx2594", "lmx2594@2")
+        .driver("xrfclk")
+        .dev_node();
+
+    let descriptors: Vec<_> = enumerate_clock_chips_in(&system.root())
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|descriptor| descriptor.name == "spi1.2")
+        .collect();
+
+    let config = Arc::new(load_config_from_file().unwrap());
+    let (lmk_devices, lmx_devices) = open_devices(config, &descriptors).await.unwrap();
+    assert!(lmk_devices.is_empty());
+    assert_eq!(lmx_devices.len(), 1);
+    assert_eq!(system.read("sys/bus/spi/drivers/xrfclk/unbind"), "spi1.2");
+    assert!(!system
+        .path("sys/bus/spi/devices/spi1.1/driver_override")
+        .exists());
+
+    drop(lmx_devices);
+    system.rebind("spi1.2", "spidev");
+    restore_driver(&descriptors[0]).await.unwrap();
+    assert_eq!(system.read("sys/bus/spi/drivers/xrfclk/bind"), "spi1.2");
+}
+
+#[tokio::test]
+async fn binding_waits_for_the_device_node() {
+    let system = FakeSystem::new();
+    system.spi_device("spi1.2", "ti,lmx2594", "lmx2594@2");
+
+    let descriptor = enumerate_clock_chips_in(&system.root())
+        .await
+        .unwrap()
+        .remove(0);
+
+    let error = bind_spidev_with_timeout(&descriptor, Duration::ZERO)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), XRFClkErrorKind::DeviceTimeout);
+    assert_eq!(error.chip(), Some(Chip::LMX2594));
+    assert_eq!(error.path(), Some(system.path("dev/spidev1.2").as_path()));
+
+    // udev creating the node a little later
+    let node = system.path("dev/spidev1.2");
+    let udev = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        fs::write(node, []).unwrap();
+    });
+
+    let device_path = bind_spidev_with_timeout(&descriptor, Duration::from_secs(5))
+        .await
+        .unwrap();
+    assert_eq!(device_path, system.path("dev/spidev1.2"));
+    udev.join().unwrap();
+}
+
+#[tokio::test]
+async fn lmk_nodes_need_num_bytes() {
+    let system = FakeSystem::new();
+    system.spi_device("spi1.0", "ti,lmk04208", "lmk04208@0");
+
+    let config = Arc::new(load_config_from_file().unwrap());
+    let error = find_devices_in(config, &system.root()).await.err().unwrap();
+
+    assert_eq!(error.kind(), XRFClkErrorKind::InvalidFilePath);
+    assert_eq!(error.chip(), Some(Chip::LMK04208));
+    assert!(error.path().unwrap().ends_with("of_node/num_bytes"));
+}
+
+#[tokio::test]
+async fn missing_sysfs_is_reported_with_its_path() {
+    let system = FakeSystem::new();
+    fs::remove_dir_all(system.path("sys")).unwrap();
+
+    let config = Arc::new(load_config_from_file().unwrap());
+    let error = find_devices_in(config, &system.root()).await.err().unwrap();
+
+    assert_eq!(error.path(), Some(system.root().spi_devices().as_path()));
+}
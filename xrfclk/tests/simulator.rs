@@ -0,0 +1,94 @@
+use std::sync::Arc;
+use xrfclk::simulator::{Lmk048xxSimulator, Lmx2594Simulator};
+use xrfclk::{load_config_from_file, Chip, Frequency, LMKDevice, LMXDevice};
+
+/// OSCin of the LMX2594s on the supported boards.
+const LMX2594_REFERENCE: Frequency = Frequency::from_hz(122_880_000);
+
+#[test]
+fn every_lmx2594_profile_locks_at_its_frequency() {
+    let config = Arc::new(load_config_from_file().unwrap());
+    let profiles: Vec<_> = config.profiles(Chip::LMX2594).collect();
+    assert!(!profiles.is_empty());
+
+    for profile in profiles {
+        let simulator = Lmx2594Simulator::from(LMX2594_REFERENCE);
+        let device =
+            LMXDevice::from_transport(Chip::LMX2594, Box::new(simulator.clone()), config.clone());
+        let key = profile.frequency.as_10khz();
+
+        device.set_clks_blocking(key).unwrap();
+        assert!(device.is_locked_blocking().unwrap(), "profile {key}");
+
+        // the keys are nominal, e.g. 73700 for 737.28 MHz
+        let outputs = simulator.output_frequencies().unwrap();
+        let (_, rfout_a) = outputs
+            .iter()
+            .find(|(name, _)| name == "RFoutA")
+            .unwrap_or_else(|| panic!("profile {key} has RFoutA disabled"));
+        let nominal = profile.frequency.hz() as f64;
+        assert!(
+            (rfout_a - nominal).abs() / nominal < 1e-3,
+            "profile {key} outputs {rfout_a} Hz"
+        );
+    }
+}
+
+/// Profiles made for a CLKin0 other than the one of the board, PLL1 must
+/// not lock on the board's.
+const FOREIGN_CLKIN_PROFILES: [(Chip, u64); 1] = [(Chip::LMK04828, 50023)];
+
+#[test]
+fn every_lmk0482x_profile_locks_at_its_frequency() {
+    let config = Arc::new(load_config_from_file().unwrap());
+
+    // OSCin and CLKin0 of the boards the profiles are made for, the CLK104
+    // takes a 10 MHz reference
+    for (chip, reference, clkin) in [
+        (
+            Chip::LMK04828,
+            Frequency::from_hz(160_000_000),
+            Frequency::from_hz(10_000_000),
+        ),
+        (
+            Chip::LMK04832,
+            Frequency::from_hz(122_880_000),
+            Frequency::from_hz(12_288_000),
+        ),
+    ] {
+        for profile in config.profiles(chip) {
+            let key = profile.frequency.as_10khz();
+            let simulator = Lmk048xxSimulator::from(chip, reference)
+                .unwrap()
+                .with_clkin(clkin);
+            let device =
+                LMKDevice::from_transport(chip, Box::new(simulator.clone()), 3, config.clone());
+
+            device.set_clks_blocking(key).unwrap();
+            assert!(simulator.violations().is_empty(), "{chip} profile {key}");
+            assert!(simulator.lock_state().pll2, "{chip} profile {key}");
+
+            let locks = !FOREIGN_CLKIN_PROFILES.contains(&(chip, key));
+            assert_eq!(
+                simulator.lock_state().pll1,
+                locks,
+                "{chip} profile {key} on a {clkin} CLKin0"
+            );
+            assert_eq!(
+                device.is_locked_blocking().unwrap(),
+                locks,
+                "{chip} profile {key}"
+            );
+
+            let nominal = profile.frequency.hz() as f64;
+            let outputs = simulator.output_frequencies().unwrap();
+            assert!(
+                !locks
+                    || outputs
+                        .iter()
+                        .any(|(_, hz)| (hz - nominal).abs() / nominal < 1e-3),
+                "{chip} profile {key} outputs {outputs:?}"
+            );
+        }
+    }
+}